@@ -0,0 +1,441 @@
+use core::fmt;
+use std::{ops::{Index, IndexMut}, str::FromStr};
+
+use graph::{
+    base::{Graph, Kind},
+    shortest::dijkstra::{a_star, dijkstra},
+    traversal::search::Bfs
+};
+use structs::range::ops::Numeric;
+
+use crate::parse::ParseError;
+
+/// Cell position as `(row, col)`, rows going down from the first line
+pub type Pos = (usize, usize);
+
+/// Steps to the 4 orthogonal neighbours: up, right, down, left
+pub const DIRS4:[(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Steps to the 8 neighbours clockwise from up
+pub const DIRS8:[(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+/// Rectangular 2D map stored row by row
+///
+/// #Example
+/// ```
+/// use advent::grid::Grid;
+///
+/// let mut map:Grid<char> = "#..\n.#.\n...".parse().unwrap();
+///
+/// assert_eq!(map[(1, 1)], '#');
+/// assert_eq!(map.get(3, 0), None);
+/// assert_eq!(map.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+///
+/// map[(0, 2)] = '@';
+/// assert_eq!(map.rotate_cw().to_string(), "..#\n.#.\n..@\n");
+/// assert_eq!(map.transpose().to_string(), "#..\n.#.\n@..\n");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows:usize,
+    cols:usize,
+    cells:Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid with every cell set to `value`
+    pub fn new(rows:usize, cols:usize, value:T) -> Self
+        where T: Clone
+    {
+        Self { rows, cols, cells: vec![value; rows * cols] }
+    }
+
+    /// Grid from its rows, panics if they are not all the same length
+    pub fn from_rows(rows:Vec<Vec<T>>) -> Self {
+        let cols:usize = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols), "rows must all have {} cells", cols);
+
+        Self { rows: rows.len(), cols, cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// Read one row per non-empty line, turning every char into a cell with `cell`.
+    /// Fails on lines of different lengths or when `cell` rejects a char
+    ///
+    /// #Example
+    /// ```
+    /// use advent::grid::Grid;
+    ///
+    /// let heights:Grid<u32> = Grid::parse("123\n456\n", |c| c.to_digit(10)).unwrap();
+    /// assert_eq!(heights[(1, 2)], 6);
+    ///
+    /// let err = Grid::parse("12\n4x\n", |c| c.to_digit(10)).unwrap_err();
+    /// assert_eq!(err.to_string(), "line 2, column 2: unexpected 'x'");
+    /// ```
+    pub fn parse<F>(text:&str, cell:F) -> Result<Self, ParseError>
+        where F: Fn(char) -> Option<T>
+    {
+        let mut grid:Self = Self { rows: 0, cols: 0, cells: Vec::new() };
+
+        for (i, line) in text.lines().enumerate() {
+            if line.is_empty() { continue; }
+
+            let len:usize = line.chars().count();
+            if grid.rows == 0 {
+                grid.cols = len;
+            } else if len != grid.cols {
+                let column:usize = len.min(grid.cols) + 1;
+                return Err(ParseError::new(i + 1, column, format!("expected {} cells, found {}", grid.cols, len)));
+            }
+
+            for (j, c) in line.chars().enumerate() {
+                let value:T = cell(c).ok_or_else(|| ParseError::new(i + 1, j + 1, format!("unexpected '{}'", c)))?;
+                grid.cells.push(value);
+            }
+            grid.rows += 1;
+        }
+
+        Ok(grid)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Amount of cells
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, pos:Pos) -> bool {
+        pos.0 < self.rows && pos.1 < self.cols
+    }
+
+    /// Cell at `row` and `col`, None when out of bounds
+    pub fn get(&self, row:usize, col:usize) -> Option<&T> {
+        if !self.contains((row, col)) { return None; }
+        Some(&self.cells[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, row:usize, col:usize) -> Option<&mut T> {
+        if !self.contains((row, col)) { return None; }
+        Some(&mut self.cells[row * self.cols + col])
+    }
+
+    /// Position one step of `dir` away from `pos`, None when it leaves the grid
+    pub fn step(&self, pos:Pos, dir:(isize, isize)) -> Option<Pos> {
+        let row:usize = pos.0.checked_add_signed(dir.0)?;
+        let col:usize = pos.1.checked_add_signed(dir.1)?;
+        self.contains((row, col)).then_some((row, col))
+    }
+
+    /// Orthogonal neighbours inside the grid: up, right, down, left
+    pub fn neighbors4(&self, pos:Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, clockwise from up
+    pub fn neighbors8(&self, pos:Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Every position with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| ((i / self.cols, i % self.cols), cell))
+    }
+
+    /// Position of the first cell, row by row, matching `pred`
+    pub fn find<F>(&self, pred:F) -> Option<Pos>
+        where F: Fn(&T) -> bool
+    {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    /// Same shape with every cell turned into another
+    pub fn map<U, F>(&self, f:F) -> Grid<U>
+        where F: Fn(&T) -> U
+    {
+        Grid { rows: self.rows, cols: self.cols, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // grid of `rows` x `cols` filled from the cells at `from(row, col)`
+    fn rebuild<F>(&self, rows:usize, cols:usize, from:F) -> Self
+        where T: Clone, F: Fn(usize, usize) -> Pos
+    {
+        let mut cells:Vec<T> = Vec::with_capacity(self.cells.len());
+        for row in 0..rows {
+            for col in 0..cols {
+                cells.push(self[from(row, col)].clone());
+            }
+        }
+        Self { rows, cols, cells }
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Self
+        where T: Clone
+    {
+        self.rebuild(self.cols, self.rows, |row, col| (col, row))
+    }
+
+    /// Rotate a quarter turn clockwise
+    pub fn rotate_cw(&self) -> Self
+        where T: Clone
+    {
+        self.rebuild(self.cols, self.rows, |row, col| (self.rows - 1 - col, row))
+    }
+
+    /// Rotate a quarter turn counterclockwise
+    pub fn rotate_ccw(&self) -> Self
+        where T: Clone
+    {
+        self.rebuild(self.cols, self.rows, |row, col| (col, self.cols - 1 - row))
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Self
+        where T: Clone
+    {
+        self.rebuild(self.rows, self.cols, |row, col| (row, self.cols - 1 - col))
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Self
+        where T: Clone
+    {
+        self.rebuild(self.rows, self.cols, |row, col| (self.rows - 1 - row, col))
+    }
+
+    /// Positions of the orthogonally connected cells equal to the one at `start`, `start` first
+    ///
+    /// #Example
+    /// ```
+    /// use advent::grid::Grid;
+    ///
+    /// let mut garden:Grid<char> = "AAB\nABB\nAAA".parse().unwrap();
+    /// assert_eq!(garden.region((0, 2)), vec![(0, 2), (1, 2), (1, 1)]);
+    ///
+    /// assert_eq!(garden.flood_fill((0, 0), '.'), 6);
+    /// assert_eq!(garden.to_string(), "..B\n.BB\n...\n");
+    /// ```
+    pub fn region(&self, start:Pos) -> Vec<Pos>
+        where T: PartialEq
+    {
+        let value:&T = &self[start];
+        let mut seen:Vec<bool> = vec![false; self.cells.len()];
+        let mut res:Vec<Pos> = vec![start];
+        seen[start.0 * self.cols + start.1] = true;
+
+        let mut i:usize = 0;
+        while i < res.len() {
+            for next in self.neighbors4(res[i]) {
+                let idx:usize = next.0 * self.cols + next.1;
+                if !seen[idx] && self.cells[idx] == *value {
+                    seen[idx] = true;
+                    res.push(next);
+                }
+            }
+            i += 1;
+        }
+
+        res
+    }
+
+    /// Set the region of `start` to `value` like a paint bucket, returns the amount of cells changed
+    pub fn flood_fill(&mut self, start:Pos, value:T) -> usize
+        where T: PartialEq + Clone
+    {
+        let region:Vec<Pos> = self.region(start);
+        for &pos in region.iter() {
+            self[pos] = value.clone();
+        }
+        region.len()
+    }
+
+    /// The grid as a graph whose nodes are the cells, `row * cols + col`. Stepping into a cell
+    /// costs what `cost` gives for it, None makes it a wall
+    pub fn graph<C, F>(&self, diagonal:bool, cost:F) -> GridGraph<C>
+        where F: Fn(&T) -> Option<C>
+    {
+        GridGraph { rows: self.rows, cols: self.cols, diagonal, cost: self.cells.iter().map(cost).collect() }
+    }
+
+    /// Fewest orthogonal steps from `start` to every cell, through the cells `open` lets in
+    ///
+    /// #Example
+    /// ```
+    /// use advent::grid::Grid;
+    ///
+    /// let maze:Grid<char> = "S.#\n#.#\n...".parse().unwrap();
+    /// let steps:Grid<Option<usize>> = maze.bfs((0, 0), |c| *c != '#');
+    ///
+    /// assert_eq!(steps[(2, 0)], Some(4));
+    /// assert_eq!(steps[(0, 2)], None);
+    /// ```
+    pub fn bfs<F>(&self, start:Pos, open:F) -> Grid<Option<usize>>
+        where F: Fn(&T) -> bool
+    {
+        let graph:GridGraph<()> = self.graph(false, |cell| open(cell).then_some(()));
+        let mut bfs = Bfs::new(&graph, graph.node(start));
+        bfs.by_ref().for_each(drop);
+
+        Grid { rows: self.rows, cols: self.cols, cells: (0..self.cells.len()).map(|node| bfs.depth(node)).collect() }
+    }
+
+    /// Cheapest cost from `start` to every cell, stepping into a cell costing what `cost` gives
+    /// for it, None for walls. Runs Dijkstra on `graph()`
+    pub fn distances<C, F>(&self, start:Pos, diagonal:bool, cost:F) -> Grid<Option<C>>
+        where C: Numeric, F: Fn(&T) -> Option<C>
+    {
+        let graph:GridGraph<C> = self.graph(diagonal, cost);
+        let paths = dijkstra(&graph, graph.node(start));
+
+        Grid { rows: self.rows, cols: self.cols, cells: (0..self.cells.len()).map(|node| paths.distance(node)).collect() }
+    }
+
+    /// Cheapest path from `start` to `goal` with its cost, stepping into a cell costing what `cost`
+    /// gives for it, None for walls. Runs A* on `graph()`, guided by the distance left times the
+    /// cheapest cell cost
+    ///
+    /// #Example
+    /// ```
+    /// use advent::grid::Grid;
+    ///
+    /// let risk:Grid<u32> = Grid::parse("116\n138\n213", |c| c.to_digit(10)).unwrap();
+    /// let (total, path) = risk.shortest_path((0, 0), (2, 2), false, |r| Some(*r)).unwrap();
+    ///
+    /// assert_eq!(total, 7);
+    /// assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    /// ```
+    pub fn shortest_path<C, F>(&self, start:Pos, goal:Pos, diagonal:bool, cost:F) -> Option<(C, Vec<Pos>)>
+        where C: Numeric, F: Fn(&T) -> Option<C>
+    {
+        let graph:GridGraph<C> = self.graph(diagonal, cost);
+        let cheapest:C = graph.cost.iter()
+            .flatten()
+            .fold(None, |min:Option<C>, &c| if min.is_some_and(|m| m <= c) { min } else { Some(c) })
+            .unwrap_or(C::ZERO);
+
+        let heuristic = |node:usize| {
+            let (row, col) = graph.pos(node);
+            let (dr, dc) = (row.abs_diff(goal.0), col.abs_diff(goal.1));
+            let steps:usize = if diagonal { dr.max(dc) } else { dr + dc };
+            C::from_usize(steps) * cheapest
+        };
+
+        let (total, path) = a_star(&graph, graph.node(start), graph.node(goal), heuristic)?;
+        Some((total, path.into_iter().map(|node| graph.pos(node)).collect()))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos:Pos) -> &T {
+        self.get(pos.0, pos.1).unwrap_or_else(|| panic!("{:?} is out of a {}x{} grid", pos, self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos:Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos.0, pos.1).unwrap_or_else(|| panic!("{:?} is out of a {}x{} grid", pos, rows, cols))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(text:&str) -> Result<Self, ParseError> {
+        Self::parse(text, Some)
+    }
+}
+
+impl<T> fmt::Display for Grid<T>
+    where T: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.cols.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Grid` seen as a directed graph, built by `Grid::graph`. Every cell is a node linked to
+/// its neighbours that are not walls, weighted by the cost of stepping into them
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridGraph<C> {
+    rows:usize,
+    cols:usize,
+    diagonal:bool,
+    cost:Vec<Option<C>>,
+}
+
+impl<C> GridGraph<C> {
+    /// Node of the cell at `pos`
+    pub fn node(&self, pos:Pos) -> usize {
+        assert!(pos.0 < self.rows && pos.1 < self.cols, "{:?} is out of a {}x{} grid", pos, self.rows, self.cols);
+        pos.0 * self.cols + pos.1
+    }
+
+    /// Cell of a node
+    pub fn pos(&self, node:usize) -> Pos {
+        (node / self.cols, node % self.cols)
+    }
+
+    fn steps(&self, node:usize) -> impl Iterator<Item = usize> + '_ {
+        let dirs:&[(isize, isize)] = if self.diagonal { &DIRS8 } else { &DIRS4 };
+        let (row, col) = self.pos(node);
+
+        dirs.iter().filter_map(move |&(dr, dc)| {
+            let r:usize = row.checked_add_signed(dr)?;
+            let c:usize = col.checked_add_signed(dc)?;
+            (r < self.rows && c < self.cols).then_some(r * self.cols + c)
+        })
+    }
+}
+
+impl<C> Graph for GridGraph<C> {
+    type Weight = C;
+
+    fn kind(&self) -> Kind {
+        Kind::Directed
+    }
+
+    fn node_bound(&self) -> usize {
+        self.cost.len()
+    }
+
+    fn node_count(&self) -> usize {
+        self.cost.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        (0..self.cost.len()).map(|node| self.out_degree(node)).sum()
+    }
+
+    fn contains_node(&self, node:usize) -> bool {
+        node < self.cost.len()
+    }
+
+    fn neighbors(&self, node:usize) -> impl Iterator<Item = (usize, &C)> {
+        let steps:Option<_> = self.contains_node(node).then(|| self.steps(node));
+        steps.into_iter().flatten().filter_map(|next| Some((next, self.cost[next].as_ref()?)))
+    }
+
+    fn edge_weight(&self, from:usize, to:usize) -> Option<&C> {
+        self.neighbors(from).find(|(next, _)| *next == to).map(|(_, w)| w)
+    }
+
+    fn in_degree(&self, node:usize) -> usize {
+        if !self.contains_node(node) || self.cost[node].is_none() { return 0; }
+        self.steps(node).count()
+    }
+}
@@ -0,0 +1,9 @@
+pub mod grid;
+pub mod solution;
+pub mod registry;
+pub mod runner;
+pub mod parse;
+
+pub mod y2022 {
+    pub mod day01;
+}
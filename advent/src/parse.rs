@@ -0,0 +1,320 @@
+use core::fmt;
+use std::{any::type_name, error::Error, str::FromStr};
+
+use crate::grid::Grid;
+
+/// Puzzle input that did not match, with the place it was found, lines and columns counting from 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line:usize,
+    pub column:usize,
+    pub message:String,
+}
+
+impl ParseError {
+    pub fn new(line:usize, column:usize, message:impl Into<String>) -> Self {
+        Self { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Every integer in `text`, a `-` right before the digits makes it negative unless it follows
+/// another digit, so ranges like `3-7` stay positive. Numbers too large for `i64` are skipped
+///
+/// #Example
+/// ```
+/// use advent::parse::ints;
+///
+/// assert_eq!(ints("target area: x=20..30, y=-10..-5"), vec![20, 30, -10, -5]);
+/// assert_eq!(ints("1-3 a: abcde"), vec![1, 3]);
+/// ```
+pub fn ints(text:&str) -> Vec<i64> {
+    let bytes:&[u8] = text.as_bytes();
+    let mut res:Vec<i64> = Vec::new();
+    let mut i:usize = 0;
+
+    while i < bytes.len() {
+        let sign:bool = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if !sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start:usize = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        if let Ok(n) = text[start..i].parse() {
+            res.push(n);
+        }
+    }
+
+    res
+}
+
+/// Every run of digits in `text`, ignoring signs
+pub fn uints(text:&str) -> Vec<u64> {
+    text.split(|c:char| !c.is_ascii_digit())
+        .filter_map(|digits| digits.parse().ok())
+        .collect()
+}
+
+/// Piece of a text between blank lines, with the line of the text it starts on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// Line of the first line of the block, from 1
+    pub line:usize,
+    pub text:&'a str,
+}
+
+impl Block<'_> {
+    /// Parse every non-empty line of the block with `parser`, errors pointing at the line of the
+    /// whole text they come from
+    pub fn parse_lines<T, P>(&self, parser:&P) -> Result<Vec<T>, ParseError>
+        where P: Parser<T>
+    {
+        parse_lines(self.text, parser).map_err(|err| ParseError { line: err.line + self.line - 1, ..err })
+    }
+}
+
+/// Pieces of `text` separated by blank lines, without the line breaks around them
+///
+/// #Example
+/// ```
+/// use advent::parse::{blocks, number, Block};
+///
+/// let found:Vec<Block> = blocks("1\n2\n\n3\r\n  \r\n\n4\nx\n");
+/// assert_eq!(found.iter().map(|b| b.text).collect::<Vec<_>>(), vec!["1\n2", "3", "4\nx"]);
+/// assert_eq!(found.iter().map(|b| b.line).collect::<Vec<_>>(), vec![1, 4, 7]);
+///
+/// let err = found[2].parse_lines(&number::<u32>()).unwrap_err();
+/// assert_eq!((err.line, err.column), (8, 1));
+/// ```
+pub fn blocks(text:&str) -> Vec<Block<'_>> {
+    let mut res:Vec<Block> = Vec::new();
+    // byte range and first line of the block being read
+    let mut block:Option<(usize, usize, usize)> = None;
+    let mut offset:usize = 0;
+
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        let content:&str = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some((start, end, line)) = block.take() {
+                res.push(Block { line, text: &text[start..end] });
+            }
+        } else {
+            let (start, first) = block.map_or((offset, i + 1), |b| (b.0, b.2));
+            block = Some((start, offset + content.len(), first));
+        }
+        offset += line.len();
+    }
+
+    if let Some((start, end, line)) = block {
+        res.push(Block { line, text: &text[start..end] });
+    }
+    res
+}
+
+/// Grid of the chars in `text`, one row per line
+pub fn char_grid(text:&str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(text, Some)
+}
+
+/// Grid of single digits, one row per line
+pub fn digit_grid(text:&str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(text, |c| c.to_digit(10))
+}
+
+/// Parse every non-empty line with `parser`, errors pointing at the line they come from
+pub fn parse_lines<T, P>(text:&str, parser:&P) -> Result<Vec<T>, ParseError>
+    where P: Parser<T>
+{
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parser.parse(line).map_err(|err| ParseError { line: i + 1, ..err }))
+        .collect()
+}
+
+/// What a parser wanted to find at a byte offset of the text
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub pos:usize,
+    pub expected:String,
+}
+
+impl Failure {
+    pub fn new(pos:usize, expected:impl Into<String>) -> Self {
+        Self { pos, expected: expected.into() }
+    }
+
+    /// Line and column of the failure in `text`
+    pub fn into_error(self, text:&str) -> ParseError {
+        let before:&str = &text[..self.pos];
+        let line:usize = before.matches('\n').count() + 1;
+        let column:usize = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+
+        ParseError::new(line, column, format!("expected {}", self.expected))
+    }
+}
+
+/// Reads a value from `text` starting at the byte offset `pos`, giving back the offset after it.
+/// Any `Fn(&str, usize) -> Result<(T, usize), Failure>` is a parser
+///
+/// #Example
+/// ```
+/// use advent::parse::{alt, number, pair, preceded, separated, tag, terminated, Parser};
+///
+/// let colour = alt(alt(tag("red"), tag("green")), tag("blue"));
+/// let draw = pair(terminated(number::<u32>(), tag(" ")), colour);
+/// let game = pair(
+///     preceded(tag("Game "), number::<u32>()),
+///     preceded(tag(": "), separated(separated(draw, tag(", ")), tag("; ")))
+/// );
+///
+/// let (id, rounds) = game.parse("Game 12: 3 blue, 4 red; 1 green").unwrap();
+/// assert_eq!(id, 12);
+/// assert_eq!(rounds, vec![vec![(3, "blue"), (4, "red")], vec![(1, "green")]]);
+///
+/// let err = game.parse("Game 12: 3 blue, 4 pink").unwrap_err();
+/// assert_eq!(err.to_string(), "line 1, column 20: expected 'red' or 'green' or 'blue'");
+/// ```
+pub trait Parser<T> {
+    fn parse_at(&self, text:&str, pos:usize) -> Result<(T, usize), Failure>;
+
+    /// Parse the whole of `text`
+    fn parse(&self, text:&str) -> Result<T, ParseError> {
+        match self.parse_at(text, 0) {
+            Ok((value, end)) if end == text.len() => Ok(value),
+            Ok((_, end)) => Err(Failure::new(end, "the end of the input").into_error(text)),
+            Err(failure) => Err(failure.into_error(text))
+        }
+    }
+
+    /// Turn the value read into another
+    fn map<U, F>(self, f:F) -> impl Parser<U>
+        where Self: Sized, F: Fn(T) -> U
+    {
+        move |text:&str, pos:usize| self.parse_at(text, pos).map(|(value, end)| (f(value), end))
+    }
+}
+
+impl<T, F> Parser<T> for F
+    where F: Fn(&str, usize) -> Result<(T, usize), Failure>
+{
+    fn parse_at(&self, text:&str, pos:usize) -> Result<(T, usize), Failure> {
+        self(text, pos)
+    }
+}
+
+/// Exactly `expected`
+pub fn tag(expected:&'static str) -> impl Parser<&'static str> {
+    move |text:&str, pos:usize| {
+        if text[pos..].starts_with(expected) {
+            Ok((expected, pos + expected.len()))
+        } else {
+            Err(Failure::new(pos, format!("'{}'", expected)))
+        }
+    }
+}
+
+/// Digits with an optional sign, read as any type that parses from them
+pub fn number<T>() -> impl Parser<T>
+    where T: FromStr
+{
+    move |text:&str, pos:usize| {
+        let rest:&str = &text[pos..];
+        let sign:usize = usize::from(rest.starts_with(['-', '+']));
+        let digits:usize = rest[sign..].bytes().take_while(u8::is_ascii_digit).count();
+
+        let expected = || Failure::new(pos, format!("a number ({})", type_name::<T>()));
+        if digits == 0 { return Err(expected()); }
+
+        let end:usize = pos + sign + digits;
+        let value:T = text[pos..end].parse().map_err(|_| expected())?;
+        Ok((value, end))
+    }
+}
+
+/// Zero or more `item` with a `sep` between every two of them. Once a separator is read,
+/// the item after it must be there, and a first item failing past its start is an error
+pub fn separated<T, S, P, Q>(item:P, sep:Q) -> impl Parser<Vec<T>>
+    where P: Parser<T>, Q: Parser<S>
+{
+    move |text:&str, pos:usize| {
+        let mut res:Vec<T> = Vec::new();
+        let (first, mut pos) = match item.parse_at(text, pos) {
+            Ok(first) => first,
+            Err(failure) if failure.pos > pos => return Err(failure),
+            Err(_) => return Ok((res, pos))
+        };
+        res.push(first);
+
+        while let Ok((_, after)) = sep.parse_at(text, pos) {
+            let (value, end) = item.parse_at(text, after)?;
+            res.push(value);
+            pos = end;
+        }
+        Ok((res, pos))
+    }
+}
+
+/// `first`, or `second` when it fails. When both fail the error that got further wins
+pub fn alt<T, P, Q>(first:P, second:Q) -> impl Parser<T>
+    where P: Parser<T>, Q: Parser<T>
+{
+    move |text:&str, pos:usize| {
+        let a:Failure = match first.parse_at(text, pos) {
+            Ok(res) => return Ok(res),
+            Err(a) => a
+        };
+        let b:Failure = match second.parse_at(text, pos) {
+            Ok(res) => return Ok(res),
+            Err(b) => b
+        };
+
+        Err(match a.pos.cmp(&b.pos) {
+            std::cmp::Ordering::Greater => a,
+            std::cmp::Ordering::Less => b,
+            std::cmp::Ordering::Equal => Failure::new(a.pos, format!("{} or {}", a.expected, b.expected))
+        })
+    }
+}
+
+/// `first` then `second`, keeping both
+pub fn pair<A, B, P, Q>(first:P, second:Q) -> impl Parser<(A, B)>
+    where P: Parser<A>, Q: Parser<B>
+{
+    move |text:&str, pos:usize| {
+        let (a, pos) = first.parse_at(text, pos)?;
+        let (b, pos) = second.parse_at(text, pos)?;
+        Ok(((a, b), pos))
+    }
+}
+
+/// `prefix` then `item`, keeping the item
+pub fn preceded<A, B, P, Q>(prefix:P, item:Q) -> impl Parser<B>
+    where P: Parser<A>, Q: Parser<B>
+{
+    pair(prefix, item).map(|(_, b)| b)
+}
+
+/// `item` then `suffix`, keeping the item
+pub fn terminated<A, B, P, Q>(item:P, suffix:Q) -> impl Parser<A>
+    where P: Parser<A>, Q: Parser<B>
+{
+    pair(item, suffix).map(|(a, _)| a)
+}
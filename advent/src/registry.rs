@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use crate::{solution::{Puzzle, Solution}, y2022};
+
+/// Solutions by year and day
+#[derive(Default)]
+pub struct Registry {
+    days:BTreeMap<(u16, u8), Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every solution in this crate
+    pub fn builtin() -> Self {
+        let mut registry:Self = Self::new();
+        registry.register(2022, 1, y2022::day01::Day01);
+        registry
+    }
+
+    /// Add the solution of a day, replacing the one it had
+    ///
+    /// #Example
+    /// ```
+    /// use advent::registry::Registry;
+    ///
+    /// let registry:Registry = Registry::builtin();
+    /// assert!(registry.get(2022, 1).is_some());
+    /// assert!(registry.get(2022, 26).is_none());
+    /// assert_eq!(registry.days().next(), Some((2022, 1)));
+    /// ```
+    pub fn register<S>(&mut self, year:u16, day:u8, solution:S)
+        where S: Solution + 'static
+    {
+        assert!((1..=25).contains(&day), "days go from 1 to 25, not {}", day);
+        self.days.insert((year, day), Box::new(solution));
+    }
+
+    pub fn get(&self, year:u16, day:u8) -> Option<&dyn Puzzle> {
+        self.days.get(&(year, day)).map(|puzzle| puzzle.as_ref())
+    }
+
+    /// Registered `(year, day)` pairs in order
+    pub fn days(&self) -> impl Iterator<Item = (u16, u8)> + '_ {
+        self.days.keys().copied()
+    }
+}
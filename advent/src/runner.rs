@@ -0,0 +1,164 @@
+use core::fmt;
+use std::{env, error::Error, fs, io, path::{Path, PathBuf}, time::{Duration, Instant}};
+
+use crate::{registry::Registry, solution::Puzzle};
+
+/// Environment variable pointing at the inputs directory
+pub const INPUTS_VAR:&str = "ADVENT_INPUTS";
+
+/// Where inputs live unless told otherwise: `$ADVENT_INPUTS`, else `./inputs` when it exists,
+/// else in debug builds the `inputs` of the advent crate they were built from
+pub fn inputs_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(INPUTS_VAR) {
+        return PathBuf::from(dir);
+    }
+
+    let local:PathBuf = PathBuf::from("inputs");
+    if local.is_dir() {
+        return local;
+    }
+
+    #[cfg(debug_assertions)]
+    {
+        let crate_dir:PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+        if crate_dir.is_dir() {
+            return crate_dir;
+        }
+    }
+
+    local
+}
+
+/// Input of a day: `<dir>/<year>/dayDD.txt`
+pub fn input_path(dir:&Path, year:u16, day:u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+/// Known answers of a day, next to its input: `<dir>/<year>/dayDD.answers`
+pub fn answers_path(dir:&Path, year:u16, day:u8) -> PathBuf {
+    input_path(dir, year, day).with_extension("answers")
+}
+
+/// Expected answers from a file holding part 1 on its first line and part 2 on the second,
+/// a blank line for an unknown answer. A missing file knows no answers
+pub fn read_answers(path:&Path) -> io::Result<[Option<String>; 2]> {
+    let text:String = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok([None, None]),
+        Err(err) => return Err(err)
+    };
+
+    let mut lines = text.lines().map(|line| Some(line.trim()).filter(|l| !l.is_empty()).map(str::to_string));
+    Ok([lines.next().flatten(), lines.next().flatten()])
+}
+
+/// Answer of one part with the time it took
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRun {
+    pub part:u8,
+    pub answer:String,
+    pub expected:Option<String>,
+    pub time:Duration,
+}
+
+impl PartRun {
+    /// Whether the answer matches the expected one, the only place answers are compared
+    pub fn verdict(&self) -> Verdict<'_> {
+        match &self.expected {
+            Some(expected) if *expected == self.answer => Verdict::Ok,
+            Some(expected) => Verdict::Wrong { expected },
+            None => Verdict::Unverified
+        }
+    }
+}
+
+/// How an answer compares to the expected one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict<'a> {
+    Ok,
+    Wrong { expected:&'a str },
+    Unverified,
+}
+
+impl fmt::Display for Verdict<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Ok => write!(f, "ok"),
+            Verdict::Wrong { expected } => write!(f, "WRONG, expected {}", expected),
+            Verdict::Unverified => write!(f, "unverified")
+        }
+    }
+}
+
+/// Everything a run of one day produced
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub year:u16,
+    pub day:u8,
+    pub parse_time:Duration,
+    pub parts:Vec<PartRun>,
+}
+
+impl Report {
+    /// Whether some answer differs from the expected one
+    pub fn has_wrong(&self) -> bool {
+        self.parts.iter().any(|run| matches!(run.verdict(), Verdict::Wrong { .. }))
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} day {:02}", self.year, self.day)?;
+        writeln!(f, "  parse   ({:?})", self.parse_time)?;
+
+        for run in self.parts.iter() {
+            writeln!(f, "  part {}  {}  ({:?})  {}", run.part, run.answer, run.time, run.verdict())?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse `text` once, then solve the given parts timing every step
+///
+/// #Example
+/// ```
+/// use advent::{runner::{run, Verdict}, y2022::day01::Day01};
+///
+/// let report = run(&Day01, 2022, 1, "1\n2\n\n5\n\n3\n", &[1, 2], &[Some("5".to_string()), Some("9".to_string())]).unwrap();
+///
+/// assert_eq!(report.parts[0].answer, "5");
+/// assert_eq!(report.parts[1].verdict(), Verdict::Wrong { expected: "9" });
+/// assert_eq!(report.parts[1].verdict().to_string(), "WRONG, expected 9");
+/// assert!(report.has_wrong());
+/// ```
+pub fn run(puzzle:&dyn Puzzle, year:u16, day:u8, text:&str, parts:&[u8], expected:&[Option<String>; 2]) -> Result<Report, Box<dyn Error>> {
+    if let Some(part) = parts.iter().find(|p| !(1..=2).contains(*p)) {
+        return Err(format!("puzzles have parts 1 and 2, not {}", part).into());
+    }
+
+    let now:Instant = Instant::now();
+    let input = puzzle.parse_input(text)?;
+    let parse_time:Duration = now.elapsed();
+
+    let mut runs:Vec<PartRun> = Vec::with_capacity(parts.len());
+    for &part in parts {
+        let now:Instant = Instant::now();
+        let answer:String = puzzle.solve(input.as_ref(), part);
+        runs.push(PartRun { part, answer, expected: expected[part as usize - 1].clone(), time: now.elapsed() });
+    }
+
+    Ok(Report { year, day, parse_time, parts: runs })
+}
+
+/// Run a registered day on the input at `input`, checking the answers stored in `answers`
+pub fn run_day(registry:&Registry, year:u16, day:u8, parts:&[u8], input:&Path, answers:&Path) -> Result<Report, Box<dyn Error>> {
+    let puzzle:&dyn Puzzle = registry.get(year, day)
+        .ok_or_else(|| format!("no solution registered for {} day {}", year, day))?;
+
+    let text:String = fs::read_to_string(input)
+        .map_err(|err| format!("could not read {}: {}", input.display(), err))?;
+    let expected:[Option<String>; 2] = read_answers(answers)
+        .map_err(|err| format!("could not read {}: {}", answers.display(), err))?;
+
+    run(puzzle, year, day, &text, parts, &expected)
+}
@@ -0,0 +1,67 @@
+use std::{any::Any, error::Error, fmt::Display};
+
+/// One Advent of Code day: the input is parsed once and shared by both parts
+///
+/// #Example
+/// ```
+/// use std::error::Error;
+/// use advent::solution::{Puzzle, Solution};
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     type Input = Vec<i64>;
+///
+///     fn parse(&self, text:&str) -> Result<Vec<i64>, Box<dyn Error>> {
+///         Ok(text.split_whitespace().map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part1(&self, input:&Vec<i64>) -> impl std::fmt::Display {
+///         input.iter().sum::<i64>()
+///     }
+///
+///     fn part2(&self, input:&Vec<i64>) -> impl std::fmt::Display {
+///         input.iter().product::<i64>()
+///     }
+/// }
+///
+/// let input = Sum.parse_input("2 3 4").unwrap();
+/// assert_eq!(Sum.solve(input.as_ref(), 1), "9");
+/// assert_eq!(Sum.solve(input.as_ref(), 2), "24");
+/// ```
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, text:&str) -> Result<Self::Input, Box<dyn Error>>;
+
+    fn part1(&self, input:&Self::Input) -> impl Display;
+
+    fn part2(&self, input:&Self::Input) -> impl Display;
+}
+
+/// Object safe side of every `Solution`, letting the registry keep days with different inputs
+/// together. Answers come back as text
+pub trait Puzzle {
+    fn parse_input(&self, text:&str) -> Result<Box<dyn Any>, Box<dyn Error>>;
+
+    /// Answer of part 1 or 2 for an input from `parse_input`
+    fn solve(&self, input:&dyn Any, part:u8) -> String;
+}
+
+impl<S> Puzzle for S 
+    where S: Solution, S::Input: 'static
+{
+    fn parse_input(&self, text:&str) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        Ok(Box::new(self.parse(text)?))
+    }
+
+    fn solve(&self, input:&dyn Any, part:u8) -> String {
+        let input:&S::Input = input.downcast_ref().expect("input parsed by another solution");
+
+        match part {
+            1 => self.part1(input).to_string(),
+            2 => self.part2(input).to_string(),
+            _ => panic!("puzzles have parts 1 and 2, not {}", part)
+        }
+    }
+}
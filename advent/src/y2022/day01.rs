@@ -0,0 +1,31 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{parse::{blocks, number}, solution::Solution};
+
+/// Calorie Counting: blank lines separate the snacks of every elf,
+/// part 1 wants the largest load and part 2 the three largest together
+pub struct Day01;
+
+impl Solution for Day01 {
+    // total of every elf, largest first
+    type Input = Vec<u64>;
+
+    fn parse(&self, text:&str) -> Result<Vec<u64>, Box<dyn Error>> {
+        let mut totals:Vec<u64> = Vec::new();
+
+        for block in blocks(text) {
+            totals.push(block.parse_lines(&number::<u64>())?.into_iter().sum());
+        }
+
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(totals)
+    }
+
+    fn part1(&self, input:&Vec<u64>) -> impl Display {
+        input.first().copied().unwrap_or(0)
+    }
+
+    fn part2(&self, input:&Vec<u64>) -> impl Display {
+        input.iter().take(3).sum::<u64>()
+    }
+}
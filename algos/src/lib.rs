@@ -0,0 +1,29 @@
+pub mod partition;
+
+pub mod sort {
+    pub mod insertion;
+    pub mod merge;
+    pub mod quick;
+    pub mod heap;
+    pub mod radix;
+    pub mod counting;
+    pub mod tim;
+    pub mod harness;
+}
+
+pub mod select {
+    pub mod quick;
+    pub mod intro;
+    pub mod median_of_medians;
+    pub mod floyd_rivest;
+    pub mod top_k;
+}
+
+pub mod strings {
+    pub mod kmp;
+    pub mod z;
+    pub mod rabin_karp;
+    pub mod aho_corasick;
+    pub mod suffix_array;
+    pub mod manacher;
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use crate::{select::median_of_medians::select, sort::insertion::insert_from};
+
+/// Three-way partition of `v` around the value at `pivot`. Returns `(lt, gt)` with everything
+/// in `v[..lt]` smaller than the pivot, `v[lt..gt]` equal to it and `v[gt..]` bigger.
+/// Panics if `v` is empty
+///
+/// #Example
+/// ```
+/// use algos::partition::partition3_by;
+///
+/// let mut v:Vec<u32> = vec![5, 1, 9, 5, 3, 7, 5];
+/// let (lt, gt) = partition3_by(&mut v, 0, &mut u32::cmp);
+///
+/// assert_eq!((lt, gt), (2, 5));
+/// assert!(v[..lt].iter().all(|x| *x < 5));
+/// assert_eq!(&v[lt..gt], &[5, 5, 5]);
+/// assert!(v[gt..].iter().all(|x| *x > 5));
+/// ```
+pub fn partition3_by<T, F>(v:&mut [T], pivot:usize, compare:&mut F) -> (usize, usize)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    v.swap(0, pivot);
+    let (first, rest) = v.split_first_mut().expect("cannot partition an empty slice");
+
+    // rest[..lt] smaller, rest[lt..i] equal, rest[i..gt] unseen, rest[gt..] bigger
+    let (mut lt, mut i, mut gt) = (0, 0, rest.len());
+    while i < gt {
+        match compare(&rest[i], first) {
+            Ordering::Less => {
+                rest.swap(lt, i);
+                lt += 1;
+                i += 1;
+            },
+            Ordering::Greater => {
+                gt -= 1;
+                rest.swap(i, gt);
+            },
+            Ordering::Equal => i += 1
+        }
+    }
+
+    // the last smaller value goes to the front, the pivot right before its equals
+    v.swap(0, lt);
+    (lt, gt + 1)
+}
+
+/// Index of the middle one of `v[a]`, `v[b]` and `v[c]`
+pub fn median_of_three<T, F>(v:&[T], a:usize, b:usize, c:usize, compare:&mut F) -> usize
+    where F: FnMut(&T, &T) -> Ordering
+{
+    let less = |compare:&mut F, x:usize, y:usize| compare(&v[x], &v[y]) == Ordering::Less;
+
+    if less(compare, a, b) {
+        if less(compare, b, c) { b } else if less(compare, a, c) { c } else { a }
+    } else if less(compare, a, c) { a } else if less(compare, b, c) { c } else { b }
+}
+
+/// Cheap pivot guess: median of the first, middle and last values, or of three such medians
+/// (Tukey's ninther) on longer slices
+pub fn pivot_by<T, F>(v:&[T], compare:&mut F) -> usize
+    where F: FnMut(&T, &T) -> Ordering
+{
+    let len:usize = v.len();
+    let (first, mid, last) = (0, len / 2, len.saturating_sub(1));
+    if len < 128 {
+        return median_of_three(v, first, mid, last, compare);
+    }
+
+    let step:usize = len / 8;
+    let a:usize = median_of_three(v, first, first + step, first + 2 * step, compare);
+    let b:usize = median_of_three(v, mid - step, mid, mid + step, compare);
+    let c:usize = median_of_three(v, last - 2 * step, last - step, last, compare);
+    median_of_three(v, a, b, c, compare)
+}
+
+/// Pivot with at least ~30% of the values on each side, found in O(n) by taking the median
+/// of every group of five and selecting the median of those. Reorders `v`.
+/// Panics if `v` is empty
+pub fn median_of_medians_by<T, F>(v:&mut [T], compare:&mut F) -> usize
+    where F: FnMut(&T, &T) -> Ordering
+{
+    assert!(!v.is_empty(), "no pivot in an empty slice");
+    if v.len() <= 5 {
+        insert_from(v, 1, compare);
+        return v.len() / 2;
+    }
+
+    let groups:usize = v.len() / 5;
+    for g in 0..groups {
+        insert_from(&mut v[5 * g..5 * g + 5], 1, compare);
+        v.swap(g, 5 * g + 2);
+    }
+
+    let mid:usize = groups / 2;
+    select(&mut v[..groups], mid, compare);
+    mid
+}
@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+
+use crate::{partition::partition3_by, sort::insertion::insert_from};
+
+// below this the sampling step costs more than it saves
+const SAMPLE_ABOVE:usize = 600;
+// slices this short are insertion sorted
+const INSERTION:usize = 16;
+
+/// Floyd-Rivest selection: on long slices it first recursively selects within a small sample
+/// window around where position `k` should fall, which puts a pivot very close to the answer at
+/// `k`, so each partition throws away nearly everything. About n + min(k, n - k) comparisons on
+/// average, the fewest of the selection algorithms here.
+/// Same guarantees on the slice as quickselect. Panics if `k` is out of range
+///
+/// #Example
+/// ```
+/// use algos::select::floyd_rivest::{floyd_rivest_select, floyd_rivest_select_by};
+///
+/// let mut v:Vec<u32> = (0..10_000).map(|i| (i * 7_919) % 10_007).collect();
+/// let mut sorted:Vec<u32> = v.clone();
+/// sorted.sort();
+/// assert_eq!(*floyd_rivest_select(&mut v, 2_500), sorted[2_500]);
+///
+/// let mut names:Vec<&str> = vec!["eve", "Bob", "alice", "Dan"];
+/// let first:&&str = floyd_rivest_select_by(&mut names, 0, |a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+/// assert_eq!(*first, "alice");
+/// ```
+pub fn floyd_rivest_select<T>(v:&mut [T], k:usize) -> &T
+    where T: Ord
+{
+    floyd_rivest_select_by(v, k, T::cmp)
+}
+
+pub fn floyd_rivest_select_by<T, F>(v:&mut [T], k:usize, mut compare:F) -> &T
+    where F: FnMut(&T, &T) -> Ordering
+{
+    assert!(k < v.len(), "position {} out of range for {} values", k, v.len());
+
+    select(v, k, &mut compare);
+    &v[k]
+}
+
+pub fn floyd_rivest_select_by_key<T, K, F>(v:&mut [T], k:usize, mut key:F) -> &T
+    where K: Ord, F: FnMut(&T) -> K
+{
+    floyd_rivest_select_by(v, k, |a, b| key(a).cmp(&key(b)))
+}
+
+fn select<T, F>(mut v:&mut [T], mut k:usize, compare:&mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    while v.len() > INSERTION {
+        let len:usize = v.len();
+        if len > SAMPLE_ABOVE {
+            // window sized so the k-th value lands inside it with high probability
+            let n:f64 = len as f64;
+            let i:f64 = (k + 1) as f64;
+            let z:f64 = n.ln();
+            let s:f64 = 0.5 * (2.0 * z / 3.0).exp();
+            let sd:f64 = 0.5 * (z * s * (n - s) / n).sqrt() * (i - n / 2.0).signum();
+
+            let lo:usize = ((k as f64 - i * s / n + sd).max(0.0) as usize).min(k);
+            let hi:usize = ((k as f64 + (n - i) * s / n + sd) as usize).clamp(k, len - 1);
+            select(&mut v[lo..=hi], k - lo, compare);
+        }
+
+        let (lt, gt) = partition3_by(v, k, compare);
+        if k < lt {
+            v = &mut std::mem::take(&mut v)[..lt];
+        } else if k >= gt {
+            v = &mut std::mem::take(&mut v)[gt..];
+            k -= gt;
+        } else {
+            return;
+        }
+    }
+
+    insert_from(v, 1, compare);
+}
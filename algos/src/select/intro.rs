@@ -0,0 +1,39 @@
+use std::cmp::Ordering;
+
+use crate::select::quick::select;
+
+/// Musser's introselect: quickselect on guessed pivots until it has partitioned 2·log₂(n)
+/// times, then median-of-medians pivots, so it runs in O(n) on average and O(n log n) at worst.
+/// Same guarantees on the slice as quickselect. Panics if `k` is out of range
+///
+/// #Example
+/// ```
+/// use algos::select::intro::{introselect, introselect_by};
+///
+/// let mut v:Vec<i32> = (0..1_000).map(|i| (i * 7_919) % 1_000 - 500).collect();
+/// assert_eq!(*introselect(&mut v, 500), 0);
+///
+/// let mut floats:Vec<f64> = vec![0.5, -3.0, 2.25, 8.0, -1.5];
+/// assert_eq!(*introselect_by(&mut floats, 1, |a, b| b.total_cmp(a)), 2.25);
+/// ```
+pub fn introselect<T>(v:&mut [T], k:usize) -> &T
+    where T: Ord
+{
+    introselect_by(v, k, T::cmp)
+}
+
+pub fn introselect_by<T, F>(v:&mut [T], k:usize, mut compare:F) -> &T
+    where F: FnMut(&T, &T) -> Ordering
+{
+    assert!(k < v.len(), "position {} out of range for {} values", k, v.len());
+
+    let limit:u32 = 2 * (usize::BITS - v.len().leading_zeros());
+    select(v, k, Some(limit), &mut compare);
+    &v[k]
+}
+
+pub fn introselect_by_key<T, K, F>(v:&mut [T], k:usize, mut key:F) -> &T
+    where K: Ord, F: FnMut(&T) -> K
+{
+    introselect_by(v, k, |a, b| key(a).cmp(&key(b)))
+}
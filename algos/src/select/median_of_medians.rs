@@ -0,0 +1,60 @@
+use std::cmp::Ordering;
+
+use crate::{partition::{median_of_medians_by, partition3_by}, sort::insertion::insert_from};
+
+/// Blum-Floyd-Pratt-Rivest-Tarjan selection: every partition uses the median of the medians of
+/// groups of five as pivot, which leaves at least ~30% of the values on each side, so it is
+/// O(n) even at worst. Slower than quickselect on average because of the pivot search.
+/// Same guarantees on the slice as quickselect. Panics if `k` is out of range
+///
+/// #Example
+/// ```
+/// use algos::select::median_of_medians::{median_of_medians_select, median_of_medians_select_by_key};
+///
+/// let mut v:Vec<u64> = (1..=101).rev().collect();
+/// assert_eq!(*median_of_medians_select(&mut v, 50), 51);
+///
+/// let mut people:Vec<(&str, u32)> = vec![("ann", 41), ("bob", 19), ("cid", 33), ("dan", 27)];
+/// assert_eq!(median_of_medians_select_by_key(&mut people, 3, |p| p.1).0, "ann");
+/// ```
+pub fn median_of_medians_select<T>(v:&mut [T], k:usize) -> &T
+    where T: Ord
+{
+    median_of_medians_select_by(v, k, T::cmp)
+}
+
+pub fn median_of_medians_select_by<T, F>(v:&mut [T], k:usize, mut compare:F) -> &T
+    where F: FnMut(&T, &T) -> Ordering
+{
+    assert!(k < v.len(), "position {} out of range for {} values", k, v.len());
+
+    select(v, k, &mut compare);
+    &v[k]
+}
+
+pub fn median_of_medians_select_by_key<T, K, F>(v:&mut [T], k:usize, mut key:F) -> &T
+    where K: Ord, F: FnMut(&T) -> K
+{
+    median_of_medians_select_by(v, k, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Selection loop, also used by the median-of-medians pivot search on the group medians
+pub(crate) fn select<T, F>(mut v:&mut [T], mut k:usize, compare:&mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    while v.len() > 5 {
+        let pivot:usize = median_of_medians_by(v, compare);
+        let (lt, gt) = partition3_by(v, pivot, compare);
+
+        if k < lt {
+            v = &mut std::mem::take(&mut v)[..lt];
+        } else if k >= gt {
+            v = &mut std::mem::take(&mut v)[gt..];
+            k -= gt;
+        } else {
+            return;
+        }
+    }
+
+    insert_from(v, 1, compare);
+}
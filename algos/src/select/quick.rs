@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+
+use crate::{partition::{median_of_medians_by, partition3_by, pivot_by}, sort::insertion::insert_from};
+
+// slices this short are insertion sorted
+const INSERTION:usize = 16;
+
+/// Hoare's quickselect: partition around a median of three (or ninther) guess and keep going
+/// on the side holding position `k` only. O(n) on average, O(n²) on inputs built against the
+/// pivot guess, see introselect for a bounded worst case.
+/// Leaves the value that sorting would put at `k` there, with nothing bigger before it and
+/// nothing smaller after it. Panics if `k` is out of range
+///
+/// #Example
+/// ```
+/// use algos::select::quick::{quickselect, quickselect_by_key};
+///
+/// let mut v:Vec<u32> = vec![9, 1, 8, 2, 7, 3, 6, 4, 5];
+/// assert_eq!(*quickselect(&mut v, 4), 5);
+/// assert!(v[..4].iter().all(|x| *x <= 5) && v[5..].iter().all(|x| *x >= 5));
+///
+/// let mut words:Vec<&str> = vec!["kiwi", "fig", "banana", "apple"];
+/// assert_eq!(*quickselect_by_key(&mut words, 0, |w| w.len()), "fig");
+/// ```
+pub fn quickselect<T>(v:&mut [T], k:usize) -> &T
+    where T: Ord
+{
+    quickselect_by(v, k, T::cmp)
+}
+
+pub fn quickselect_by<T, F>(v:&mut [T], k:usize, mut compare:F) -> &T
+    where F: FnMut(&T, &T) -> Ordering
+{
+    assert!(k < v.len(), "position {} out of range for {} values", k, v.len());
+
+    select(v, k, None, &mut compare);
+    &v[k]
+}
+
+pub fn quickselect_by_key<T, K, F>(v:&mut [T], k:usize, mut key:F) -> &T
+    where K: Ord, F: FnMut(&T) -> K
+{
+    quickselect_by(v, k, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Quickselect loop shared with introselect: `limit` is how many partitions may use a guessed
+/// pivot before switching to median-of-medians, `None` never switches
+pub(crate) fn select<T, F>(mut v:&mut [T], mut k:usize, mut limit:Option<u32>, compare:&mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    while v.len() > INSERTION {
+        let pivot:usize = match limit {
+            Some(0) => median_of_medians_by(v, compare),
+            _ => pivot_by(v, compare)
+        };
+        limit = limit.map(|l| l.saturating_sub(1));
+
+        let (lt, gt) = partition3_by(v, pivot, compare);
+        if k < lt {
+            v = &mut std::mem::take(&mut v)[..lt];
+        } else if k >= gt {
+            v = &mut std::mem::take(&mut v)[gt..];
+            k -= gt;
+        } else {
+            return;
+        }
+    }
+
+    insert_from(v, 1, compare);
+}
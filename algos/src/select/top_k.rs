@@ -0,0 +1,148 @@
+use std::fmt::Debug;
+
+use structs::{heap::FindSmallest as HeapFindSmallest, list::list::FindSmallest as ListFindSmallest};
+use trees::ost::FindSmallest as OstFindSmallest;
+
+use crate::select::{floyd_rivest::floyd_rivest_select, intro::introselect, median_of_medians::median_of_medians_select,
+    quick::quickselect};
+
+/// A way of finding the `k` smallest values, so the approaches can be swapped and compared
+///
+/// #Example
+/// ```
+/// use algos::select::top_k::selectors;
+///
+/// let batch:Vec<u32> = (0..1_000).map(|i| (i * 7_919) % 1_009).collect();
+/// let mut expected:Vec<u32> = batch.clone();
+/// expected.sort();
+///
+/// for selector in selectors::<u32>() {
+///     let mut smallest:Vec<u32> = selector.select_k(&batch, 25);
+///     smallest.sort();
+///     assert_eq!(smallest, expected[..25], "{}", selector.name());
+/// }
+/// ```
+pub trait SelectK<T> {
+    fn name(&self) -> &'static str;
+
+    /// The `k` smallest values of `v` in no particular order, all of them when `k >= v.len()`
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T>;
+}
+
+pub struct QuickSelect;
+pub struct IntroSelect;
+pub struct MedianOfMedians;
+pub struct FloydRivest;
+/// Bounded max-heap of `structs::heap`
+pub struct HeapSelect;
+/// Sorted linked list of `structs::list`
+pub struct ListSelect;
+/// Bounded order-statistic tree of `trees::ost`
+pub struct OstSelect;
+
+/// Every approach, in-place selections first
+pub fn selectors<T>() -> Vec<Box<dyn SelectK<T>>>
+    where T: Ord + Clone + Debug
+{
+    vec![
+        Box::new(QuickSelect),
+        Box::new(IntroSelect),
+        Box::new(MedianOfMedians),
+        Box::new(FloydRivest),
+        Box::new(HeapSelect),
+        Box::new(ListSelect),
+        Box::new(OstSelect),
+    ]
+}
+
+// copy `v`, put the k-th smallest at k - 1 with the smaller ones before it and cut the rest
+fn smallest_with<T, S>(v:&[T], k:usize, select:S) -> Vec<T>
+    where T: Clone, S: FnOnce(&mut [T], usize)
+{
+    if k == 0 { return Vec::new(); }
+
+    let mut res:Vec<T> = v.to_vec();
+    if k < res.len() {
+        select(&mut res, k - 1);
+        res.truncate(k);
+    }
+    res
+}
+
+impl<T> SelectK<T> for QuickSelect
+    where T: Ord + Clone
+{
+    fn name(&self) -> &'static str { "quickselect" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        smallest_with(v, k, |v, k| { quickselect(v, k); })
+    }
+}
+
+impl<T> SelectK<T> for IntroSelect
+    where T: Ord + Clone
+{
+    fn name(&self) -> &'static str { "introselect" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        smallest_with(v, k, |v, k| { introselect(v, k); })
+    }
+}
+
+impl<T> SelectK<T> for MedianOfMedians
+    where T: Ord + Clone
+{
+    fn name(&self) -> &'static str { "median of medians" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        smallest_with(v, k, |v, k| { median_of_medians_select(v, k); })
+    }
+}
+
+impl<T> SelectK<T> for FloydRivest
+    where T: Ord + Clone
+{
+    fn name(&self) -> &'static str { "floyd-rivest" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        smallest_with(v, k, |v, k| { floyd_rivest_select(v, k); })
+    }
+}
+
+impl<T> SelectK<T> for HeapSelect
+    where T: PartialOrd + Clone + Debug
+{
+    fn name(&self) -> &'static str { "heap" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        // find_smallest only handles 0 < k < len
+        if k == 0 { return Vec::new(); }
+        if k >= v.len() { return v.to_vec(); }
+
+        HeapFindSmallest::find_smallest(v, k)
+    }
+}
+
+impl<T> SelectK<T> for ListSelect
+    where T: PartialOrd + Clone + Debug
+{
+    fn name(&self) -> &'static str { "list" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        // find_smallest only handles 0 < k < len
+        if k == 0 { return Vec::new(); }
+        if k >= v.len() { return v.to_vec(); }
+
+        ListFindSmallest::find_smallest(v, k).to_vec()
+    }
+}
+
+impl<T> SelectK<T> for OstSelect
+    where T: Ord + Clone
+{
+    fn name(&self) -> &'static str { "order statistic tree" }
+
+    fn select_k(&self, v:&[T], k:usize) -> Vec<T> {
+        OstFindSmallest::find_smallest(v, k)
+    }
+}
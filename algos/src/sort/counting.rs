@@ -0,0 +1,72 @@
+use crate::sort::radix::{radix_sort_by_key, RadixKey};
+
+/// Widest distance between the smallest and the biggest key that is counted directly for `n`
+/// values, a few counters per value. Wider ranges are radix sorted instead
+pub fn max_span(n:usize) -> u64 {
+    (n as u64).saturating_mul(4).max(1 << 16)
+}
+
+/// Stable counting sort, O(n + k) time and memory where k is the distance between the smallest
+/// and the biggest key, so only worth it for keys in a narrow range. Ranges wider than
+/// `max_span` fall back to `radix_sort_by_key`, so memory stays O(n).
+/// There is no `_by` version, ordering comes from the keys and not from comparisons
+///
+/// #Example
+/// ```
+/// use algos::sort::counting::{counting_sort, counting_sort_by_key};
+///
+/// let mut dice:Vec<u8> = vec![6, 1, 4, 1, 6, 3, 2];
+/// counting_sort(&mut dice);
+/// assert_eq!(dice, vec![1, 1, 2, 3, 4, 6, 6]);
+///
+/// let mut grades:Vec<(&str, char)> = vec![("zoe", 'B'), ("al", 'A'), ("max", 'B'), ("ida", 'A')];
+/// counting_sort_by_key(&mut grades, |g| g.1);
+/// assert_eq!(grades, vec![("al", 'A'), ("ida", 'A'), ("zoe", 'B'), ("max", 'B')]);
+///
+/// // too wide to count, radix sorted without allocating a counter per key
+/// let mut sparse:Vec<u64> = vec![0, 1 << 40, 3];
+/// counting_sort(&mut sparse);
+/// assert_eq!(sparse, vec![0, 3, 1 << 40]);
+/// ```
+pub fn counting_sort<T>(v:&mut [T])
+    where T: RadixKey
+{
+    counting_sort_by_key(v, |x| *x);
+}
+
+pub fn counting_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where T: Clone, K: RadixKey, F: FnMut(&T) -> K
+{
+    let keys:Vec<u64> = v.iter().map(|x| key(x).radix_key()).collect();
+    let (Some(&min), Some(&max)) = (keys.iter().min(), keys.iter().max()) else {
+        return;
+    };
+
+    if max - min >= max_span(v.len()) {
+        return radix_sort_by_key(v, key);
+    }
+    let span:usize = (max - min) as usize + 1;
+
+    // starts[k] is where the first value with key min + k goes
+    let mut starts:Vec<usize> = vec![0; span];
+    for &k in keys.iter() {
+        starts[(k - min) as usize] += 1;
+    }
+
+    let mut total:usize = 0;
+    for start in starts.iter_mut() {
+        let count:usize = *start;
+        *start = total;
+        total += count;
+    }
+
+    let mut order:Vec<usize> = vec![0; v.len()];
+    for (i, &k) in keys.iter().enumerate() {
+        let slot:&mut usize = &mut starts[(k - min) as usize];
+        order[*slot] = i;
+        *slot += 1;
+    }
+
+    let sorted:Vec<T> = order.iter().map(|&i| v[i].clone()).collect();
+    v.clone_from_slice(&sorted);
+}
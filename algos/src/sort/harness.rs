@@ -0,0 +1,123 @@
+use core::fmt;
+use std::{error::Error, time::{Duration, Instant}};
+
+use crate::sort::{counting::{counting_sort_by_key, max_span}, heap::heap_sort_by_key, insertion::insertion_sort_by_key,
+    merge::merge_sort_by_key, quick::quick_sort_by_key, radix::{radix_sort_by_key, RadixKey}, tim::tim_sort_by_key};
+
+/// A sort of the crate, run on `(key, original position)` pairs ordered by key only
+pub struct Sorter<K> {
+    pub name:&'static str,
+    pub stable:bool,
+    /// O(n²) sorts, too slow for big batches
+    pub quadratic:bool,
+    /// Sorts keys in a narrow range only, wider ones are handed to another sort
+    pub range_limited:bool,
+    pub sort:fn(&mut [(K, usize)]),
+}
+
+impl<K> Sorter<K>
+    where K: RadixKey
+{
+    /// Whether the sort works on `batch` itself rather than falling back to another sort
+    pub fn handles(&self, batch:&[K]) -> bool {
+        let keys = batch.iter().map(|k| k.radix_key());
+        let span:u64 = keys.clone().max().unwrap_or(0) - keys.min().unwrap_or(0);
+        !self.range_limited || span < max_span(batch.len())
+    }
+}
+
+impl<K> Clone for Sorter<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for Sorter<K> {}
+
+/// Every sort of the crate
+pub fn sorters<K>() -> Vec<Sorter<K>>
+    where K: Ord + RadixKey
+{
+    vec![
+        Sorter { name: "insertion", stable: true, quadratic: true, range_limited: false, sort: |v| insertion_sort_by_key(v, |p| p.0) },
+        Sorter { name: "merge", stable: true, quadratic: false, range_limited: false, sort: |v| merge_sort_by_key(v, |p| p.0) },
+        Sorter { name: "quick", stable: false, quadratic: false, range_limited: false, sort: |v| quick_sort_by_key(v, |p| p.0) },
+        Sorter { name: "heap", stable: false, quadratic: false, range_limited: false, sort: |v| heap_sort_by_key(v, |p| p.0) },
+        Sorter { name: "radix", stable: true, quadratic: false, range_limited: false, sort: |v| radix_sort_by_key(v, |p| p.0) },
+        Sorter { name: "counting", stable: true, quadratic: false, range_limited: true, sort: |v| counting_sort_by_key(v, |p| p.0) },
+        Sorter { name: "tim", stable: true, quadratic: false, range_limited: false, sort: |v| tim_sort_by_key(v, |p| p.0) },
+    ]
+}
+
+/// Sort that gave a wrong result, with the first position where it differs from the expected one
+#[derive(Debug)]
+pub struct SortError {
+    pub name:&'static str,
+    pub index:usize,
+    pub message:String,
+}
+
+impl fmt::Display for SortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sort, index {}: {}", self.name, self.index, self.message)
+    }
+}
+
+impl Error for SortError {}
+
+/// Run `sorter` on `batch` and compare it with the standard library's stable sort: the keys must
+/// come out in order, every value must still be there and, for stable sorts, equal keys must keep
+/// their original order. Returns how long the sort took
+///
+/// #Example
+/// ```
+/// use algos::sort::harness::{check, sorters, Sorter};
+///
+/// let batch:Vec<u32> = (0..2_000).map(|i| (i * 7_919) % 97).collect();
+/// for sorter in sorters::<u32>() {
+///     assert!(check(&sorter, &batch).is_ok(), "{} sort", sorter.name);
+/// }
+///
+/// // counting sort hands a range this wide to radix sort
+/// let counting:Sorter<u64> = sorters().into_iter().find(|s| s.name == "counting").unwrap();
+/// assert!(!counting.handles(&[0, 1 << 40, 3]));
+/// assert!(check(&counting, &[0, 1 << 40, 3]).is_ok());
+///
+/// // keeps the pairs in order but breaks ties the wrong way
+/// let reversed:Sorter<u32> = Sorter {
+///     name: "reversed", stable: true, quadratic: false, range_limited: false,
+///     sort: |v| v.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+/// };
+/// let err = check(&reversed, &[3, 1, 3]).unwrap_err();
+/// assert_eq!(err.to_string(), "reversed sort, index 1: expected (3, 0), got (3, 2)");
+/// ```
+pub fn check<K>(sorter:&Sorter<K>, batch:&[K]) -> Result<Duration, SortError>
+    where K: Ord + Copy + fmt::Debug
+{
+    let mut pairs:Vec<(K, usize)> = batch.iter().copied().zip(0..).collect();
+    let mut expected:Vec<(K, usize)> = pairs.clone();
+    expected.sort_by_key(|p| p.0);
+
+    let now:Instant = Instant::now();
+    (sorter.sort)(&mut pairs);
+    let elapsed:Duration = now.elapsed();
+
+    let error = |index:usize, message:String| SortError { name: sorter.name, index, message };
+
+    if pairs.len() != expected.len() {
+        return Err(error(pairs.len().min(expected.len()), format!("{} values instead of {}", pairs.len(), expected.len())));
+    }
+    if let Some(i) = (0..pairs.len()).find(|&i| pairs[i].0 != expected[i].0) {
+        return Err(error(i, format!("expected key {:?}, got {:?}", expected[i].0, pairs[i].0)));
+    }
+
+    // unstable sorts may put equal keys in any order, but all positions must be there
+    if !sorter.stable {
+        pairs.sort_by_key(|p| p.1);
+        expected.sort_by_key(|p| p.1);
+    }
+    match (0..pairs.len()).find(|&i| pairs[i] != expected[i]) {
+        Some(i) => Err(error(i, format!("expected {:?}, got {:?}", expected[i], pairs[i]))),
+        None => Ok(elapsed)
+    }
+}
@@ -0,0 +1,74 @@
+use std::{cell::RefCell, cmp::Ordering};
+
+use structs::heap::{Heapify, MaxHeap};
+
+// value carrying the comparison it is ordered by, so MaxHeap can take any order
+struct Ordered<'a, T, F> {
+    val:T,
+    compare:&'a RefCell<F>,
+}
+
+impl<T, F> Clone for Ordered<'_, T, F>
+    where T: Clone
+{
+    fn clone(&self) -> Self {
+        Self { val: self.val.clone(), compare: self.compare }
+    }
+}
+
+impl<T, F> PartialEq for Ordered<'_, T, F>
+    where F: FnMut(&T, &T) -> Ordering
+{
+    fn eq(&self, other:&Self) -> bool {
+        (self.compare.borrow_mut())(&self.val, &other.val) == Ordering::Equal
+    }
+}
+
+impl<T, F> PartialOrd for Ordered<'_, T, F>
+    where F: FnMut(&T, &T) -> Ordering
+{
+    fn partial_cmp(&self, other:&Self) -> Option<Ordering> {
+        Some((self.compare.borrow_mut())(&self.val, &other.val))
+    }
+}
+
+/// Unstable heapsort on `structs::heap::MaxHeap`: heapify a copy of the values, then extract
+/// the maximum into the slice from the back. O(n log n) in every case
+///
+/// #Example
+/// ```
+/// use algos::sort::heap::{heap_sort, heap_sort_by_key};
+///
+/// let mut v:Vec<i64> = vec![3, -8, 12, 0, 3, 5];
+/// heap_sort(&mut v);
+/// assert_eq!(v, vec![-8, 0, 3, 3, 5, 12]);
+///
+/// let mut points:Vec<(i32, i32)> = vec![(3, 4), (0, 1), (-2, 2)];
+/// heap_sort_by_key(&mut points, |(x, y)| x * x + y * y);
+/// assert_eq!(points, vec![(0, 1), (-2, 2), (3, 4)]);
+/// ```
+pub fn heap_sort<T>(v:&mut [T])
+    where T: Ord + Clone
+{
+    heap_sort_by(v, T::cmp);
+}
+
+pub fn heap_sort_by<T, F>(v:&mut [T], compare:F)
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+{
+    let compare:RefCell<F> = RefCell::new(compare);
+    let mut heap:MaxHeap<Ordered<T, F>> = v.iter()
+        .map(|val| Ordered { val: val.clone(), compare: &compare })
+        .collect::<Vec<Ordered<T, F>>>()
+        .heapify();
+
+    for slot in v.iter_mut().rev() {
+        *slot = heap.extract().expect("heap holds every value").val;
+    }
+}
+
+pub fn heap_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where T: Clone, K: Ord, F: FnMut(&T) -> K
+{
+    heap_sort_by(v, |a, b| key(a).cmp(&key(b)));
+}
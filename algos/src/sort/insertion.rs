@@ -0,0 +1,59 @@
+use std::cmp::Ordering;
+
+/// Stable in-place sort moving every value left past the bigger ones before it.
+/// O(n²) comparisons, but the fastest option on short or nearly sorted slices
+///
+/// #Example
+/// ```
+/// use algos::sort::insertion::{insertion_sort, insertion_sort_by_key};
+///
+/// let mut v:Vec<i32> = vec![4, -2, 7, 0, -2];
+/// insertion_sort(&mut v);
+/// assert_eq!(v, vec![-2, -2, 0, 4, 7]);
+///
+/// let mut words:Vec<&str> = vec!["pear", "fig", "apple", "kiwi"];
+/// insertion_sort_by_key(&mut words, |w| w.len());
+/// assert_eq!(words, vec!["fig", "pear", "kiwi", "apple"]);
+/// ```
+pub fn insertion_sort<T>(v:&mut [T])
+    where T: Ord
+{
+    insertion_sort_by(v, T::cmp);
+}
+
+pub fn insertion_sort_by<T, F>(v:&mut [T], mut compare:F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    insert_from(v, 1, &mut compare);
+}
+
+pub fn insertion_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where K: Ord, F: FnMut(&T) -> K
+{
+    insertion_sort_by(v, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Insert `v[sorted..]` one by one into the already sorted `v[..sorted]`
+pub(crate) fn insert_from<T, F>(v:&mut [T], sorted:usize, compare:&mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    for i in sorted.max(1)..v.len() {
+        let mut j:usize = i;
+        while j > 0 && compare(&v[j], &v[j - 1]) == Ordering::Less {
+            v.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// Same as `insert_from` but finds every spot with a binary search, so only the moves stay
+/// quadratic. Equal values go after the ones already there, keeping it stable
+pub(crate) fn binary_insert_from<T, F>(v:&mut [T], sorted:usize, compare:&mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    for i in sorted.max(1)..v.len() {
+        let (before, rest) = v.split_at(i);
+        let pos:usize = before.partition_point(|x| compare(x, &rest[0]) != Ordering::Greater);
+        v[pos..=i].rotate_right(1);
+    }
+}
@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+
+use crate::sort::insertion::insert_from;
+
+// slices this short are insertion sorted
+const INSERTION:usize = 20;
+
+/// Stable top-down merge sort, O(n log n) with a buffer of n / 2 values.
+/// Halves already in order are not merged, so sorted input takes O(n)
+///
+/// #Example
+/// ```
+/// use algos::sort::merge::{merge_sort, merge_sort_by};
+///
+/// let mut v:Vec<u64> = (0..100).map(|i| (i * 37) % 101).collect();
+/// merge_sort(&mut v);
+/// assert!(v.windows(2).all(|w| w[0] <= w[1]));
+///
+/// let mut people:Vec<(&str, u32)> = vec![("ann", 31), ("bob", 25), ("cid", 31), ("dan", 25)];
+/// merge_sort_by(&mut people, |a, b| b.1.cmp(&a.1));
+/// assert_eq!(people, vec![("ann", 31), ("cid", 31), ("bob", 25), ("dan", 25)]);
+/// ```
+pub fn merge_sort<T>(v:&mut [T])
+    where T: Ord + Clone
+{
+    merge_sort_by(v, T::cmp);
+}
+
+pub fn merge_sort_by<T, F>(v:&mut [T], mut compare:F)
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+{
+    let mut buf:Vec<T> = Vec::with_capacity(v.len() / 2);
+    sort(v, &mut buf, &mut compare);
+}
+
+pub fn merge_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where T: Clone, K: Ord, F: FnMut(&T) -> K
+{
+    merge_sort_by(v, |a, b| key(a).cmp(&key(b)));
+}
+
+fn sort<T, F>(v:&mut [T], buf:&mut Vec<T>, compare:&mut F)
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+{
+    if v.len() <= INSERTION {
+        insert_from(v, 1, compare);
+        return;
+    }
+
+    let mid:usize = v.len() / 2;
+    sort(&mut v[..mid], buf, compare);
+    sort(&mut v[mid..], buf, compare);
+    merge(v, mid, buf, compare);
+}
+
+/// Merge the sorted runs `v[..mid]` and `v[mid..]`, copying only the left one into `buf`.
+/// Ties keep the left value first
+pub(crate) fn merge<T, F>(v:&mut [T], mid:usize, buf:&mut Vec<T>, compare:&mut F)
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+{
+    if mid == 0 || mid == v.len() || compare(&v[mid], &v[mid - 1]) != Ordering::Less {
+        return;
+    }
+
+    buf.clear();
+    buf.extend_from_slice(&v[..mid]);
+
+    // v[k..j] holds stale values that are already in buf or written out
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < buf.len() && j < v.len() {
+        if compare(&v[j], &buf[i]) == Ordering::Less {
+            v.swap(k, j);
+            j += 1;
+        } else {
+            v[k] = buf[i].clone();
+            i += 1;
+        }
+        k += 1;
+    }
+
+    for val in buf.drain(i..) {
+        v[k] = val;
+        k += 1;
+    }
+}
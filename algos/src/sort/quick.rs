@@ -0,0 +1,62 @@
+use std::cmp::Ordering;
+
+use crate::{partition::{median_of_medians_by, partition3_by, pivot_by}, sort::insertion::insert_from};
+
+// slices this short are insertion sorted
+const INSERTION:usize = 16;
+
+/// Unstable in-place quicksort picking pivots the way introselect does: a median of three
+/// (or ninther) guess first, switching to median-of-medians once the recursion gets deeper than
+/// 2·log₂(n), so the worst case stays O(n log n). The three-way partition takes runs of equal
+/// values out in one pass
+///
+/// #Example
+/// ```
+/// use algos::sort::quick::{quick_sort, quick_sort_by, quick_sort_by_key};
+///
+/// let mut v:Vec<u32> = (0..1_000).map(|i| (i * 7_919) % 1_009).collect();
+/// quick_sort(&mut v);
+/// assert!(v.windows(2).all(|w| w[0] <= w[1]));
+///
+/// let mut floats:Vec<f64> = vec![2.5, -1.0, 0.0, 9.75];
+/// quick_sort_by(&mut floats, |a, b| b.total_cmp(a));
+/// assert_eq!(floats, vec![9.75, 2.5, 0.0, -1.0]);
+///
+/// let mut words:Vec<String> = vec!["Banana".into(), "apple".into(), "cherry".into()];
+/// quick_sort_by_key(&mut words, |w| w.to_lowercase());
+/// assert_eq!(words, vec!["apple", "Banana", "cherry"]);
+/// ```
+pub fn quick_sort<T>(v:&mut [T])
+    where T: Ord
+{
+    quick_sort_by(v, T::cmp);
+}
+
+pub fn quick_sort_by<T, F>(v:&mut [T], mut compare:F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    let limit:u32 = 2 * (usize::BITS - v.len().leading_zeros());
+    sort(v, limit, &mut compare);
+}
+
+pub fn quick_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where K: Ord, F: FnMut(&T) -> K
+{
+    quick_sort_by(v, |a, b| key(a).cmp(&key(b)));
+}
+
+fn sort<T, F>(v:&mut [T], limit:u32, compare:&mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    if v.len() <= INSERTION {
+        insert_from(v, 1, compare);
+        return;
+    }
+
+    let pivot:usize = if limit == 0 { median_of_medians_by(v, compare) } else { pivot_by(v, compare) };
+    let (lt, gt) = partition3_by(v, pivot, compare);
+    let limit:u32 = limit.saturating_sub(1);
+
+    sort(&mut v[..lt], limit, compare);
+    sort(&mut v[gt..], limit, compare);
+}
@@ -0,0 +1,77 @@
+/// Keys the non-comparison sorts can work with: mapped to a `u64` that orders the same way
+pub trait RadixKey: Copy {
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! impl_radix_key {
+    (unsigned $($t:ty),*) => {$(
+        impl RadixKey for $t {
+            fn radix_key(self) -> u64 { self as u64 }
+        }
+    )*};
+    (signed $($t:ty),*) => {$(
+        impl RadixKey for $t {
+            // flipping the sign bit puts the negative numbers first
+            fn radix_key(self) -> u64 { (self as i64 as u64) ^ (1 << 63) }
+        }
+    )*};
+}
+
+impl_radix_key!(unsigned u8, u16, u32, u64, usize, char, bool);
+impl_radix_key!(signed i8, i16, i32, i64, isize);
+
+/// Stable LSD radix sort over the bytes of the keys, O(n) per byte with 8 passes at most.
+/// Passes where every key has the same byte are skipped, so small keys cost fewer passes.
+/// There is no `_by` version, ordering comes from the keys and not from comparisons
+///
+/// #Example
+/// ```
+/// use algos::sort::radix::{radix_sort, radix_sort_by_key};
+///
+/// let mut v:Vec<i32> = vec![170, -45, 75, -90, 802, 24, 2, 66];
+/// radix_sort(&mut v);
+/// assert_eq!(v, vec![-90, -45, 2, 24, 66, 75, 170, 802]);
+///
+/// let mut tasks:Vec<(&str, u8)> = vec![("mail", 2), ("build", 1), ("lunch", 2), ("deploy", 1)];
+/// radix_sort_by_key(&mut tasks, |t| t.1);
+/// assert_eq!(tasks, vec![("build", 1), ("deploy", 1), ("mail", 2), ("lunch", 2)]);
+/// ```
+pub fn radix_sort<T>(v:&mut [T])
+    where T: RadixKey
+{
+    radix_sort_by_key(v, |x| *x);
+}
+
+pub fn radix_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where T: Clone, K: RadixKey, F: FnMut(&T) -> K
+{
+    // (key, position) pairs are sorted instead of the values themselves
+    let mut pairs:Vec<(u64, usize)> = v.iter().map(|x| key(x).radix_key()).zip(0..).collect();
+    let mut next:Vec<(u64, usize)> = vec![(0, 0); pairs.len()];
+
+    for shift in (0..u64::BITS).step_by(8) {
+        let digit = |k:u64| ((k >> shift) & 0xff) as usize;
+
+        let mut starts:[usize; 256] = [0; 256];
+        for &(k, _) in pairs.iter() {
+            starts[digit(k)] += 1;
+        }
+        if starts.contains(&pairs.len()) { continue; }
+
+        let mut total:usize = 0;
+        for start in starts.iter_mut() {
+            let count:usize = *start;
+            *start = total;
+            total += count;
+        }
+
+        for &(k, i) in pairs.iter() {
+            next[starts[digit(k)]] = (k, i);
+            starts[digit(k)] += 1;
+        }
+        std::mem::swap(&mut pairs, &mut next);
+    }
+
+    let sorted:Vec<T> = pairs.iter().map(|&(_, i)| v[i].clone()).collect();
+    v.clone_from_slice(&sorted);
+}
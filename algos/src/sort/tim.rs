@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+
+use crate::sort::{insertion::binary_insert_from, merge::merge};
+
+// below this many values a single binary insertion sort does it all
+const MIN_MERGE:usize = 32;
+
+/// Stable Timsort-style sort: finds the runs already in the data (reversing strictly descending
+/// ones), extends short runs with binary insertion sort and merges them off a stack whose run
+/// lengths keep the Timsort invariants, so merges stay balanced. O(n) on sorted or reversed
+/// input, O(n log n) otherwise. Merges are plain, without galloping
+///
+/// #Example
+/// ```
+/// use algos::sort::tim::{tim_sort, tim_sort_by_key};
+///
+/// // two sorted halves, one of them reversed
+/// let mut v:Vec<u32> = (0..500).chain((500..1_000).rev()).collect();
+/// tim_sort(&mut v);
+/// assert_eq!(v, (0..1_000).collect::<Vec<u32>>());
+///
+/// let mut log:Vec<(u8, &str)> = vec![(2, "disk"), (1, "boot"), (2, "net"), (1, "cpu")];
+/// tim_sort_by_key(&mut log, |e| e.0);
+/// assert_eq!(log, vec![(1, "boot"), (1, "cpu"), (2, "disk"), (2, "net")]);
+/// ```
+pub fn tim_sort<T>(v:&mut [T])
+    where T: Ord + Clone
+{
+    tim_sort_by(v, T::cmp);
+}
+
+pub fn tim_sort_by<T, F>(v:&mut [T], mut compare:F)
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+{
+    let len:usize = v.len();
+    let min_run:usize = min_run(len);
+    // (start, length) of the runs waiting to be merged
+    let mut runs:Vec<(usize, usize)> = Vec::new();
+    let mut buf:Vec<T> = Vec::new();
+
+    let mut start:usize = 0;
+    while start < len {
+        let mut end:usize = start + 1;
+        if end < len && compare(&v[end], &v[start]) == Ordering::Less {
+            while end < len && compare(&v[end], &v[end - 1]) == Ordering::Less {
+                end += 1;
+            }
+            v[start..end].reverse();
+        } else {
+            while end < len && compare(&v[end], &v[end - 1]) != Ordering::Less {
+                end += 1;
+            }
+        }
+
+        let stop:usize = (start + min_run).min(len);
+        if end < stop {
+            binary_insert_from(&mut v[start..stop], end - start, &mut compare);
+            end = stop;
+        }
+
+        runs.push((start, end - start));
+        collapse(v, &mut runs, &mut buf, &mut compare, false);
+        start = end;
+    }
+
+    collapse(v, &mut runs, &mut buf, &mut compare, true);
+}
+
+pub fn tim_sort_by_key<T, K, F>(v:&mut [T], mut key:F)
+    where T: Clone, K: Ord, F: FnMut(&T) -> K
+{
+    tim_sort_by(v, |a, b| key(a).cmp(&key(b)));
+}
+
+// run length between MIN_MERGE / 2 and MIN_MERGE making len / run a power of two or just below
+fn min_run(mut len:usize) -> usize {
+    let mut extra:usize = 0;
+    while len >= MIN_MERGE {
+        extra |= len & 1;
+        len >>= 1;
+    }
+    len + extra
+}
+
+// merge runs until, from the top of the stack, every run is longer than the one above it and
+// than the two above it together. `all` merges everything left
+fn collapse<T, F>(v:&mut [T], runs:&mut Vec<(usize, usize)>, buf:&mut Vec<T>, compare:&mut F, all:bool)
+    where T: Clone, F: FnMut(&T, &T) -> Ordering
+{
+    while runs.len() > 1 {
+        let n:usize = runs.len();
+        let size = |i:usize| runs[i].1;
+
+        let broken:bool = (n >= 3 && size(n - 3) <= size(n - 2) + size(n - 1))
+            || (n >= 4 && size(n - 4) <= size(n - 3) + size(n - 2));
+
+        let at:usize = if (all || broken) && n >= 3 && size(n - 3) < size(n - 1) {
+            n - 3
+        } else if all || broken || size(n - 2) <= size(n - 1) {
+            n - 2
+        } else {
+            break;
+        };
+
+        let (start, left) = runs[at];
+        let right:usize = runs[at + 1].1;
+        merge(&mut v[start..start + left + right], left, buf, compare);
+
+        runs[at].1 = left + right;
+        runs.remove(at + 1);
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use structs::list::list::{BaseOperations, List};
+
+/// One occurrence of a pattern: `text[start..end]` equals pattern number `pattern`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub pattern:usize,
+    pub start:usize,
+    pub end:usize,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    next:BTreeMap<u8, usize>,
+    // longest proper suffix of this node's string that is also in the trie
+    fail:usize,
+    // patterns ending exactly here
+    ends:Vec<usize>,
+    // nearest node down the fail chain where some pattern ends
+    dict:Option<usize>,
+}
+
+/// Aho-Corasick automaton: a trie of every pattern with failure links, so all the patterns are
+/// searched at once in a single pass, O(n + number of matches) after an O(total pattern length)
+/// build. Works on the bytes of anything that is `AsRef<[u8]>`. Empty patterns match nowhere
+///
+/// #Example
+/// ```
+/// use algos::strings::aho_corasick::{AhoCorasick, Match};
+///
+/// let ac:AhoCorasick = AhoCorasick::new(["he", "she", "his", "hers"]);
+/// let found:Vec<(usize, usize)> = ac.find_iter("ushers").map(|m| (m.pattern, m.start)).collect();
+///
+/// // "she" and "he" both end at 4, the longer one comes first
+/// assert_eq!(found, vec![(1, 1), (0, 2), (3, 2)]);
+/// assert_eq!(ac.find_iter(&b"his"[..]).next(), Some(Match { pattern: 2, start: 0, end: 3 }));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AhoCorasick {
+    nodes:Vec<Node>,
+    lens:Vec<usize>,
+}
+
+impl AhoCorasick {
+    pub fn new<I, P>(patterns:I) -> Self
+        where I: IntoIterator<Item = P>, P: AsRef<[u8]>
+    {
+        let mut nodes:Vec<Node> = vec![Node::default()];
+        let mut lens:Vec<usize> = Vec::new();
+
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern:&[u8] = pattern.as_ref();
+            lens.push(pattern.len());
+            if pattern.is_empty() { continue; }
+
+            let mut node:usize = 0;
+            for &c in pattern {
+                node = match nodes[node].next.get(&c) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child:usize = nodes.len() - 1;
+                        nodes[node].next.insert(c, child);
+                        child
+                    }
+                };
+            }
+            nodes[node].ends.push(id);
+        }
+
+        // failure links, parents before children
+        let mut queue:List<usize> = List::empty();
+        let root_children:Vec<usize> = nodes[0].next.values().copied().collect();
+        for child in root_children {
+            queue.append(child);
+        }
+
+        while let Some(node) = queue.pop() {
+            let children:Vec<(u8, usize)> = nodes[node].next.iter().map(|(&c, &child)| (c, child)).collect();
+
+            for (c, child) in children {
+                let mut fail:usize = nodes[node].fail;
+                while fail != 0 && !nodes[fail].next.contains_key(&c) {
+                    fail = nodes[fail].fail;
+                }
+                let fail:usize = nodes[fail].next.get(&c).copied().unwrap_or(0);
+
+                nodes[child].fail = fail;
+                nodes[child].dict = if nodes[fail].ends.is_empty() { nodes[fail].dict } else { Some(fail) };
+                queue.append(child);
+            }
+        }
+
+        Self { nodes, lens }
+    }
+
+    /// Number of patterns, empty ones included
+    pub fn patterns(&self) -> usize {
+        self.lens.len()
+    }
+
+    /// Every occurrence of every pattern in `text`, ordered by where they end,
+    /// longer patterns first among those ending at the same place
+    pub fn find_iter<'a, T>(&'a self, text:&'a T) -> AhoCorasickMatches<'a>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        AhoCorasickMatches { ac: self, text: text.as_ref(), pos: 0, node: 0, emit: None, emitted: 0 }
+    }
+
+    /// Whether any pattern occurs in `text`
+    pub fn is_match<T>(&self, text:&T) -> bool
+        where T: AsRef<[u8]> + ?Sized
+    {
+        self.find_iter(text).next().is_some()
+    }
+}
+
+pub struct AhoCorasickMatches<'a> {
+    ac:&'a AhoCorasick,
+    text:&'a [u8],
+    pos:usize,
+    node:usize,
+    // node whose patterns are being reported, and how many of them were
+    emit:Option<usize>,
+    emitted:usize,
+}
+
+impl Iterator for AhoCorasickMatches<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        let nodes:&[Node] = &self.ac.nodes;
+
+        loop {
+            while let Some(node) = self.emit {
+                if let Some(&pattern) = nodes[node].ends.get(self.emitted) {
+                    self.emitted += 1;
+                    let start:usize = self.pos - self.ac.lens[pattern];
+                    return Some(Match { pattern, start, end: self.pos });
+                }
+                self.emit = nodes[node].dict;
+                self.emitted = 0;
+            }
+
+            let &c = self.text.get(self.pos)?;
+            let mut node:usize = self.node;
+            while node != 0 && !nodes[node].next.contains_key(&c) {
+                node = nodes[node].fail;
+            }
+            self.node = nodes[node].next.get(&c).copied().unwrap_or(0);
+            self.pos += 1;
+
+            let here:&Node = &nodes[self.node];
+            self.emit = if here.ends.is_empty() { here.dict } else { Some(self.node) };
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/// Prefix function: `pi[i]` is the length of the longest proper prefix of `s[..=i]`
+/// that is also a suffix of it
+///
+/// #Example
+/// ```
+/// use algos::strings::kmp::prefix_function;
+///
+/// assert_eq!(prefix_function(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+/// ```
+pub fn prefix_function<T>(s:&[T]) -> Vec<usize>
+    where T: PartialEq
+{
+    let mut pi:Vec<usize> = vec![0; s.len()];
+
+    for i in 1..s.len() {
+        let mut len:usize = pi[i - 1];
+        while len > 0 && s[i] != s[len] {
+            len = pi[len - 1];
+        }
+        if s[i] == s[len] { len += 1; }
+        pi[i] = len;
+    }
+    pi
+}
+
+/// Knuth-Morris-Pratt search: the prefix function of the pattern says how far it can shift after
+/// a mismatch, so the text is read once and never backed up. O(m) to build, O(n) per search.
+/// Works on the bytes of anything that is `AsRef<[u8]>`, for `&str` the offsets found are always
+/// char boundaries. An empty pattern matches nowhere
+///
+/// #Example
+/// ```
+/// use algos::strings::kmp::Kmp;
+///
+/// let kmp:Kmp = Kmp::new("ana");
+/// assert_eq!(kmp.find_iter("bananas").collect::<Vec<usize>>(), vec![1, 3]);
+/// assert_eq!(kmp.find(&b"ban"[..]), None);
+///
+/// let bytes:Kmp = Kmp::new(&[0xff, 0x00]);
+/// assert_eq!(bytes.find(&[1, 0xff, 0x00, 2]), Some(1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Kmp {
+    pattern:Vec<u8>,
+    pi:Vec<usize>,
+}
+
+impl Kmp {
+    pub fn new<P>(pattern:&P) -> Self
+        where P: AsRef<[u8]> + ?Sized
+    {
+        let pattern:Vec<u8> = pattern.as_ref().to_vec();
+        let pi:Vec<usize> = prefix_function(&pattern);
+        Self { pattern, pi }
+    }
+
+    pub fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    /// Start of every match in `text`, overlapping ones included
+    pub fn find_iter<'a, T>(&'a self, text:&'a T) -> KmpMatches<'a>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        KmpMatches { kmp: self, text: text.as_ref(), pos: 0, matched: 0 }
+    }
+
+    /// Start of the first match in `text`
+    pub fn find<T>(&self, text:&T) -> Option<usize>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        self.find_iter(text).next()
+    }
+}
+
+pub struct KmpMatches<'a> {
+    kmp:&'a Kmp,
+    text:&'a [u8],
+    pos:usize,
+    // length of the pattern prefix ending at pos
+    matched:usize,
+}
+
+impl Iterator for KmpMatches<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let (pattern, pi) = (&self.kmp.pattern, &self.kmp.pi);
+        if pattern.is_empty() { return None; }
+
+        while self.pos < self.text.len() {
+            let c:u8 = self.text[self.pos];
+            while self.matched > 0 && pattern[self.matched] != c {
+                self.matched = pi[self.matched - 1];
+            }
+            if pattern[self.matched] == c { self.matched += 1; }
+            self.pos += 1;
+
+            if self.matched == pattern.len() {
+                self.matched = pi[pattern.len() - 1];
+                return Some(self.pos - pattern.len());
+            }
+        }
+        None
+    }
+}
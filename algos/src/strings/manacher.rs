@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+/// Every palindrome of a sequence found with Manacher's algorithm in O(n): for each of the
+/// 2n + 1 centres (on an element or between two) the longest palindrome around it, reusing the
+/// mirror image inside the rightmost palindrome found so far.
+/// Slices are read element by element and ranges are indices into them. Strings are read char by
+/// char, since a reversed multi-byte char is not the same bytes, and ranges are byte offsets
+///
+/// #Example
+/// ```
+/// use algos::strings::manacher::Palindromes;
+///
+/// let text:&str = "forgeeksskeegfor";
+/// let pals:Palindromes = Palindromes::of_str(text);
+/// assert_eq!(&text[pals.longest()], "geeksskeeg");
+/// assert!(pals.is_palindrome(5..11));
+/// assert!(!pals.is_palindrome(0..3));
+///
+/// // the bytes of "éxé" are not a palindrome, its chars are
+/// assert_eq!(Palindromes::of_str("éxé").longest(), 0..5);
+/// assert_eq!(Palindromes::new("éxé".as_bytes()).longest(), 0..1);
+///
+/// let bytes:Palindromes = Palindromes::new(b"abaab");
+/// assert_eq!(bytes.iter().filter(|r| r.len() > 1).collect::<Vec<_>>(), vec![0..3, 1..5]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Palindromes {
+    // radius[c] is the length of the longest palindrome centred at c, centre 2i + 1 being
+    // element i and centre 2i the gap before it
+    radius:Vec<usize>,
+    // offset of every element and of the end, byte offsets for strings
+    offsets:Vec<usize>,
+}
+
+impl Palindromes {
+    pub fn new<T>(s:&[T]) -> Self
+        where T: PartialEq
+    {
+        Self { radius: manacher(s), offsets: (0..=s.len()).collect() }
+    }
+
+    pub fn of_str(s:&str) -> Self {
+        let chars:Vec<char> = s.chars().collect();
+        let offsets:Vec<usize> = s.char_indices()
+            .map(|(i, _)| i)
+            .chain([s.len()])
+            .collect();
+
+        Self { radius: manacher(&chars), offsets }
+    }
+
+    // element range of a palindrome around a centre
+    fn around(&self, centre:usize) -> Range<usize> {
+        let len:usize = self.radius[centre];
+        let start:usize = (centre - len) / 2;
+        self.offsets[start]..self.offsets[start + len]
+    }
+
+    /// First longest palindrome, empty for an empty sequence
+    pub fn longest(&self) -> Range<usize> {
+        let centre:usize = (0..self.radius.len())
+            .rev()
+            .max_by_key(|&c| self.radius[c])
+            .unwrap_or(0);
+        self.around(centre)
+    }
+
+    /// Whether the range is a palindrome, O(1). False for ranges that do not fall on elements
+    /// (or chars for strings)
+    pub fn is_palindrome(&self, range:Range<usize>) -> bool {
+        let (Ok(start), Ok(end)) = (self.offsets.binary_search(&range.start), self.offsets.binary_search(&range.end)) else {
+            return false;
+        };
+        start <= end && self.radius[start + end] >= end - start
+    }
+
+    /// Longest palindrome around every centre, left to right, skipping the empty ones
+    pub fn iter(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        (0..self.radius.len())
+            .filter(|&c| self.radius[c] > 0)
+            .map(|c| self.around(c))
+    }
+
+    /// Number of palindromic substrings, counted once per position
+    pub fn count(&self) -> usize {
+        // a palindrome of length l around a centre holds (l + 1) / 2 shorter ones with the same centre
+        self.radius.iter().map(|len| len.div_ceil(2)).sum()
+    }
+}
+
+fn manacher<T>(s:&[T]) -> Vec<usize>
+    where T: PartialEq
+{
+    // positions of the interleaved sequence gap, s[0], gap, s[1], ..., gap
+    let m:usize = 2 * s.len() + 1;
+    // both ends of a radius are gaps or both are elements, and gaps always match
+    let same = |a:usize, b:usize| a.is_multiple_of(2) || s[a / 2] == s[b / 2];
+
+    let mut radius:Vec<usize> = vec![0; m];
+    // the palindrome reaching furthest right is centred at `centre` and ends at `reach`
+    let (mut centre, mut reach) = (0, 0);
+    for i in 0..m {
+        let mut len:usize = if i < reach { radius[2 * centre - i].min(reach - i) } else { 0 };
+        while len < i && i + len + 1 < m && same(i - len - 1, i + len + 1) {
+            len += 1;
+        }
+        radius[i] = len;
+
+        if i + len > reach {
+            centre = i;
+            reach = i + len;
+        }
+    }
+    radius
+}
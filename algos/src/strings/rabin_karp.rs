@@ -0,0 +1,96 @@
+// hashes are taken modulo the Mersenne prime 2⁶¹ - 1
+const MOD:u64 = (1 << 61) - 1;
+const BASE:u64 = 1_000_003;
+
+fn mul_mod(a:u64, b:u64) -> u64 {
+    ((a as u128 * b as u128) % MOD as u128) as u64
+}
+
+/// Polynomial hash of `bytes` modulo 2⁶¹ - 1
+fn hash(bytes:&[u8]) -> u64 {
+    bytes.iter().fold(0, |h, &b| (mul_mod(h, BASE) + b as u64) % MOD)
+}
+
+/// Rabin-Karp search: compares a rolling hash of every text window with the hash of the pattern
+/// and only checks the bytes when they agree, so there are no false matches. O(n + m) expected,
+/// O(n·m) if an adversary forces hash collisions.
+/// Works on the bytes of anything that is `AsRef<[u8]>`. An empty pattern matches nowhere
+///
+/// #Example
+/// ```
+/// use algos::strings::rabin_karp::RabinKarp;
+///
+/// let rk:RabinKarp = RabinKarp::new("abra");
+/// assert_eq!(rk.find_iter("abracadabra").collect::<Vec<usize>>(), vec![0, 7]);
+/// assert_eq!(rk.find(&b"cadabr"[..]), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RabinKarp {
+    pattern:Vec<u8>,
+    hash:u64,
+    // BASE^(m - 1), weight of the byte leaving the window
+    power:u64,
+}
+
+impl RabinKarp {
+    pub fn new<P>(pattern:&P) -> Self
+        where P: AsRef<[u8]> + ?Sized
+    {
+        let pattern:Vec<u8> = pattern.as_ref().to_vec();
+        let power:u64 = (1..pattern.len()).fold(1, |p, _| mul_mod(p, BASE));
+        Self { hash: hash(&pattern), pattern, power }
+    }
+
+    pub fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    /// Start of every match in `text`, overlapping ones included
+    pub fn find_iter<'a, T>(&'a self, text:&'a T) -> RabinKarpMatches<'a>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        let text:&[u8] = text.as_ref();
+        let window:u64 = text.get(..self.pattern.len()).map_or(0, hash);
+        RabinKarpMatches { rk: self, text, pos: 0, window }
+    }
+
+    /// Start of the first match in `text`
+    pub fn find<T>(&self, text:&T) -> Option<usize>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        self.find_iter(text).next()
+    }
+}
+
+pub struct RabinKarpMatches<'a> {
+    rk:&'a RabinKarp,
+    text:&'a [u8],
+    pos:usize,
+    // hash of text[pos..pos + m]
+    window:u64,
+}
+
+impl Iterator for RabinKarpMatches<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let (rk, text) = (self.rk, self.text);
+        let m:usize = rk.pattern.len();
+        if m == 0 { return None; }
+
+        while self.pos + m <= text.len() {
+            let pos:usize = self.pos;
+            let found:bool = self.window == rk.hash && text[pos..pos + m] == rk.pattern[..];
+
+            if pos + m < text.len() {
+                let out:u64 = mul_mod(text[pos] as u64, rk.power);
+                let kept:u64 = (self.window + MOD - out) % MOD;
+                self.window = (mul_mod(kept, BASE) + text[pos + m] as u64) % MOD;
+            }
+            self.pos += 1;
+
+            if found { return Some(pos); }
+        }
+        None
+    }
+}
@@ -0,0 +1,144 @@
+use std::{cmp::Ordering, ops::Range};
+
+use crate::sort::radix::radix_sort_by_key;
+
+/// Suffix array of a byte string with its LCP array: the start of every suffix in sorted order,
+/// built by prefix doubling with a radix sort per round in O(n log n), and the length of the
+/// common prefix of every two neighbouring suffixes by Kasai's algorithm in O(n).
+/// Works on the bytes of anything that is `AsRef<[u8]>`
+///
+/// #Example
+/// ```
+/// use algos::strings::suffix_array::SuffixArray;
+///
+/// let sa:SuffixArray = SuffixArray::new("banana");
+/// // a, ana, anana, banana, na, nana
+/// assert_eq!(sa.suffixes(), &[5, 3, 1, 0, 4, 2]);
+/// assert_eq!(sa.lcp(), &[0, 1, 3, 0, 0, 2]);
+///
+/// let mut found:Vec<usize> = sa.find_iter("ana").collect();
+/// found.sort();
+/// assert_eq!(found, vec![1, 3]);
+/// assert_eq!(sa.longest_repeated(), Some(1..4));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SuffixArray {
+    text:Vec<u8>,
+    sa:Vec<usize>,
+    lcp:Vec<usize>,
+}
+
+impl SuffixArray {
+    pub fn new<T>(text:&T) -> Self
+        where T: AsRef<[u8]> + ?Sized
+    {
+        let text:Vec<u8> = text.as_ref().to_vec();
+        let n:usize = text.len();
+        assert!((n as u64) < u32::MAX as u64, "text too long for the 32-bit ranks");
+
+        let mut sa:Vec<usize> = (0..n).collect();
+        let mut rank:Vec<u64> = text.iter().map(|&b| b as u64).collect();
+        let mut next:Vec<u64> = vec![0; n];
+
+        let mut k:usize = 1;
+        loop {
+            // rank of the first k bytes, then of the k after them, 0 past the end
+            let key = |i:usize| ((rank[i] + 1) << 32) | rank.get(i + k).map_or(0, |r| r + 1);
+            radix_sort_by_key(&mut sa, |&i| key(i));
+
+            if let Some(&first) = sa.first() {
+                next[first] = 0;
+            }
+            for j in 1..n {
+                next[sa[j]] = next[sa[j - 1]] + u64::from(key(sa[j]) != key(sa[j - 1]));
+            }
+            std::mem::swap(&mut rank, &mut next);
+
+            if n == 0 || rank[sa[n - 1]] as usize == n - 1 || k >= n { break; }
+            k *= 2;
+        }
+
+        let lcp:Vec<usize> = kasai(&text, &sa);
+        Self { text, sa, lcp }
+    }
+
+    pub fn text(&self) -> &[u8] {
+        &self.text
+    }
+
+    /// Start of every suffix, in sorted order
+    pub fn suffixes(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// `lcp()[i]` is the length of the common prefix of the suffixes at `i - 1` and `i` in sorted
+    /// order, 0 for the first one
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    // sorted positions of the suffixes starting with `pattern`
+    fn range(&self, pattern:&[u8]) -> Range<usize> {
+        let head = |s:usize| &self.text[s..(s + pattern.len()).min(self.text.len())];
+
+        let start:usize = self.sa.partition_point(|&s| head(s) < pattern);
+        let end:usize = start + self.sa[start..].partition_point(|&s| head(s).cmp(pattern) == Ordering::Equal);
+        start..end
+    }
+
+    /// Start of every occurrence of `pattern`, in the order of the suffixes and not of the text.
+    /// Two binary searches, O(m log n). An empty pattern matches nowhere
+    pub fn find_iter<P>(&self, pattern:&P) -> impl Iterator<Item = usize> + '_
+        where P: AsRef<[u8]> + ?Sized
+    {
+        let pattern:&[u8] = pattern.as_ref();
+        let range:Range<usize> = if pattern.is_empty() { 0..0 } else { self.range(pattern) };
+        self.sa[range].iter().copied()
+    }
+
+    /// Number of occurrences of `pattern`
+    pub fn count<P>(&self, pattern:&P) -> usize
+        where P: AsRef<[u8]> + ?Sized
+    {
+        self.find_iter(pattern).count()
+    }
+
+    /// First longest substring occurring at least twice, as a range of the text
+    pub fn longest_repeated(&self) -> Option<Range<usize>> {
+        let (i, &len) = self.lcp.iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, len)| **len)?;
+
+        if len == 0 { return None; }
+        let start:usize = self.sa[i].min(self.sa[i - 1]);
+        Some(start..start + len)
+    }
+}
+
+// Kasai: going through the suffixes in text order, the common prefix with the previous suffix in
+// sorted order shrinks by at most one each step
+fn kasai(text:&[u8], sa:&[usize]) -> Vec<usize> {
+    let n:usize = text.len();
+    let mut rank:Vec<usize> = vec![0; n];
+    for (i, &s) in sa.iter().enumerate() {
+        rank[s] = i;
+    }
+
+    let mut lcp:Vec<usize> = vec![0; n];
+    let mut len:usize = 0;
+    for s in 0..n {
+        if rank[s] == 0 {
+            len = 0;
+            continue;
+        }
+
+        let prev:usize = sa[rank[s] - 1];
+        while s + len < n && prev + len < n && text[s + len] == text[prev + len] {
+            len += 1;
+        }
+        lcp[rank[s]] = len;
+        len = len.saturating_sub(1);
+    }
+    lcp
+}
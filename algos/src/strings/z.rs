@@ -0,0 +1,129 @@
+/// Z-array: `z[i]` is the length of the longest common prefix of `s` and `s[i..]`,
+/// with `z[0] = s.len()`. O(n) by reusing the rightmost match found so far
+///
+/// #Example
+/// ```
+/// use algos::strings::z::z_array;
+///
+/// assert_eq!(z_array(b"aabxaab"), vec![7, 1, 0, 0, 3, 1, 0]);
+/// ```
+pub fn z_array<T>(s:&[T]) -> Vec<usize>
+    where T: PartialEq
+{
+    let n:usize = s.len();
+    let mut z:Vec<usize> = vec![0; n];
+    if n == 0 { return z; }
+    z[0] = n;
+
+    // s[l..r] is the match reaching furthest right, equal to s[..r - l]
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        let mut len:usize = if i < r { z[i - l].min(r - i) } else { 0 };
+        while i + len < n && s[len] == s[i + len] {
+            len += 1;
+        }
+        z[i] = len;
+
+        if i + len > r {
+            l = i;
+            r = i + len;
+        }
+    }
+    z
+}
+
+/// Search with the Z-algorithm: the Z-array of the pattern lets every text position reuse the
+/// furthest match so far, the same trick as for `z_array`, so a search reads the text in O(n).
+/// Works on the bytes of anything that is `AsRef<[u8]>`. An empty pattern matches nowhere
+///
+/// #Example
+/// ```
+/// use algos::strings::z::ZSearch;
+///
+/// let search:ZSearch = ZSearch::new("aa");
+/// assert_eq!(search.find_iter("aaab aa").collect::<Vec<usize>>(), vec![0, 1, 5]);
+///
+/// // longest prefix of the pattern starting at every position
+/// assert_eq!(ZSearch::new("abc").prefix_lengths("xabab").collect::<Vec<usize>>(), vec![0, 2, 0, 2, 0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ZSearch {
+    pattern:Vec<u8>,
+    z:Vec<usize>,
+}
+
+impl ZSearch {
+    pub fn new<P>(pattern:&P) -> Self
+        where P: AsRef<[u8]> + ?Sized
+    {
+        let pattern:Vec<u8> = pattern.as_ref().to_vec();
+        let z:Vec<usize> = z_array(&pattern);
+        Self { pattern, z }
+    }
+
+    pub fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    /// For every position of `text`, how long a prefix of the pattern starts there
+    pub fn prefix_lengths<'a, T>(&'a self, text:&'a T) -> ZPrefixes<'a>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        ZPrefixes { search: self, text: text.as_ref(), i: 0, l: 0, r: 0 }
+    }
+
+    /// Start of every match in `text`, overlapping ones included
+    pub fn find_iter<'a, T>(&'a self, text:&'a T) -> impl Iterator<Item = usize> + 'a
+        where T: AsRef<[u8]> + ?Sized
+    {
+        let m:usize = self.pattern.len();
+        self.prefix_lengths(text)
+            .enumerate()
+            .filter(move |&(_, len)| m > 0 && len == m)
+            .map(|(i, _)| i)
+    }
+
+    /// Start of the first match in `text`
+    pub fn find<T>(&self, text:&T) -> Option<usize>
+        where T: AsRef<[u8]> + ?Sized
+    {
+        self.find_iter(text).next()
+    }
+}
+
+pub struct ZPrefixes<'a> {
+    search:&'a ZSearch,
+    text:&'a [u8],
+    i:usize,
+    // text[l..r] is the match reaching furthest right, equal to pattern[..r - l]
+    l:usize,
+    r:usize,
+}
+
+impl Iterator for ZPrefixes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let (pattern, z, text) = (&self.search.pattern, &self.search.z, self.text);
+        let i:usize = self.i;
+        if i >= text.len() { return None; }
+
+        // r - l <= pattern length, so i - l indexes the pattern
+        let mut len:usize = if i < self.r { z[i - self.l].min(self.r - i) } else { 0 };
+        while len < pattern.len() && i + len < text.len() && pattern[len] == text[i + len] {
+            len += 1;
+        }
+
+        if i + len > self.r {
+            self.l = i;
+            self.r = i + len;
+        }
+        self.i += 1;
+        Some(len)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left:usize = self.text.len() - self.i;
+        (left, Some(left))
+    }
+}
@@ -0,0 +1,230 @@
+use core::fmt;
+use std::fmt::Debug;
+
+use crate::base::{Graph, Kind};
+
+/// Graph storing the outgoing edges of every node in a list, O(V + E) memory.
+/// Weights are `()` for unweighted graphs
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Graph};
+///
+/// let mut roads:AdjList<u32> = AdjList::undirected();
+/// let a:usize = roads.add_node();
+/// let b:usize = roads.add_node();
+/// let c:usize = roads.add_node();
+///
+/// roads.add_edge(a, b, 7);
+/// roads.add_edge(b, c, 3);
+///
+/// assert_eq!(roads.edge_weight(c, b), Some(&3));
+/// assert_eq!(roads.degree(b), 2);
+///
+/// roads.remove_node(b);
+/// assert_eq!(roads.edge_count(), 0);
+/// assert_eq!(roads.nodes().collect::<Vec<usize>>(), vec![a, c]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdjList<W = ()> {
+    kind:Kind,
+    // outgoing edges of every node, None once the node is removed
+    out:Vec<Option<Vec<(usize, W)>>>,
+    // sources of the incoming edges, only kept for directed graphs
+    inc:Vec<Vec<usize>>,
+    nodes:usize,
+    edges:usize,
+}
+
+impl<W> AdjList<W> {
+    pub fn new(kind:Kind) -> Self {
+        Self { kind, out: Vec::new(), inc: Vec::new(), nodes: 0, edges: 0 }
+    }
+
+    pub fn directed() -> Self {
+        Self::new(Kind::Directed)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(Kind::Undirected)
+    }
+
+    /// Create a graph with the nodes `0..nodes` and no edges
+    pub fn with_nodes(kind:Kind, nodes:usize) -> Self {
+        let mut graph:Self = Self::new(kind);
+        for _ in 0..nodes {
+            graph.add_node();
+        }
+        graph
+    }
+
+    /// Add a node and return its id
+    pub fn add_node(&mut self) -> usize {
+        self.out.push(Some(Vec::new()));
+        self.inc.push(Vec::new());
+        self.nodes += 1;
+        self.out.len() - 1
+    }
+
+    /// Remove a node with every edge touching it
+    pub fn remove_node(&mut self, node:usize) -> bool {
+        let Some(out) = self.out.get_mut(node).and_then(Option::take) else {
+            return false;
+        };
+
+        for &(to, _) in out.iter() {
+            if to == node { continue; }
+
+            match self.kind {
+                Kind::Directed => self.inc[to].retain(|s| *s != node),
+                Kind::Undirected => self.out[to].as_mut().unwrap().retain(|(t, _)| *t != node)
+            }
+        }
+
+        let mut removed:usize = out.len();
+        for from in std::mem::take(&mut self.inc[node]) {
+            if from == node { continue; }
+
+            self.out[from].as_mut().unwrap().retain(|(t, _)| *t != node);
+            removed += 1;
+        }
+
+        self.nodes -= 1;
+        self.edges -= removed;
+        true
+    }
+
+    fn out_mut(&mut self, node:usize) -> &mut Vec<(usize, W)> {
+        match self.out.get_mut(node) {
+            Some(Some(out)) => out,
+            _ => panic!("node {} is not in the graph", node)
+        }
+    }
+
+    /// Add an edge, or replace its weight returning the previous one.
+    /// Panics if either node is missing
+    pub fn add_edge(&mut self, from:usize, to:usize, weight:W) -> Option<W>
+        where W: Clone
+    {
+        assert!(self.contains_node(to), "node {} is not in the graph", to);
+
+        if let Some((_, w)) = self.out_mut(from).iter_mut().find(|(t, _)| *t == to) {
+            let old:W = std::mem::replace(w, weight.clone());
+            if self.kind == Kind::Undirected && from != to {
+                let back:&mut (usize, W) = self.out_mut(to).iter_mut().find(|(t, _)| *t == from).unwrap();
+                back.1 = weight;
+            }
+            return Some(old);
+        }
+
+        match self.kind {
+            Kind::Directed => self.inc[to].push(from),
+            Kind::Undirected if from != to => self.out_mut(to).push((from, weight.clone())),
+            Kind::Undirected => {}
+        }
+        self.out_mut(from).push((to, weight));
+        self.edges += 1;
+
+        None
+    }
+
+    /// Remove the edge between `from` and `to`, returning its weight
+    pub fn remove_edge(&mut self, from:usize, to:usize) -> Option<W> {
+        if !self.contains_node(from) || !self.contains_node(to) { return None; }
+
+        let out:&mut Vec<(usize, W)> = self.out_mut(from);
+        let pos:usize = out.iter().position(|(t, _)| *t == to)?;
+        let (_, weight) = out.remove(pos);
+
+        match self.kind {
+            Kind::Directed => self.inc[to].retain(|s| *s != from),
+            Kind::Undirected if from != to => self.out_mut(to).retain(|(t, _)| *t != from),
+            Kind::Undirected => {}
+        }
+        self.edges -= 1;
+
+        Some(weight)
+    }
+
+    /// Nodes with an edge into `node`, the same as the neighbors for undirected graphs
+    pub fn in_neighbors(&self, node:usize) -> impl Iterator<Item = usize> {
+        let (inc, out) = match self.kind {
+            Kind::Directed => (self.inc.get(node), None),
+            Kind::Undirected => (None, self.out.get(node).and_then(Option::as_ref))
+        };
+
+        inc.into_iter().flatten().copied()
+            .chain(out.into_iter().flatten().map(|(t, _)| *t))
+    }
+
+    /// Build a graph over the nodes `0..nodes` from `(from, to, weight)` triples
+    pub fn from_edges<I>(kind:Kind, nodes:usize, edges:I) -> Self
+        where I: IntoIterator<Item = (usize, usize, W)>, W: Clone
+    {
+        let mut graph:Self = Self::with_nodes(kind, nodes);
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+impl<W> Graph for AdjList<W> {
+    type Weight = W;
+
+    fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    fn node_bound(&self) -> usize {
+        self.out.len()
+    }
+
+    fn node_count(&self) -> usize {
+        self.nodes
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    fn contains_node(&self, node:usize) -> bool {
+        matches!(self.out.get(node), Some(Some(_)))
+    }
+
+    fn neighbors(&self, node:usize) -> impl Iterator<Item = (usize, &W)> {
+        self.out.get(node)
+            .and_then(Option::as_ref)
+            .into_iter()
+            .flatten()
+            .map(|(to, w)| (*to, w))
+    }
+
+    fn edge_weight(&self, from:usize, to:usize) -> Option<&W> {
+        self.neighbors(from).find(|(t, _)| *t == to).map(|(_, w)| w)
+    }
+
+    fn out_degree(&self, node:usize) -> usize {
+        self.out.get(node).and_then(Option::as_ref).map_or(0, Vec::len)
+    }
+
+    fn in_degree(&self, node:usize) -> usize {
+        match self.kind {
+            Kind::Directed => if self.contains_node(node) { self.inc[node].len() } else { 0 },
+            Kind::Undirected => self.out_degree(node)
+        }
+    }
+}
+
+impl<W> fmt::Display for AdjList<W>
+    where W: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arrow:&str = if self.is_directed() { "->" } else { "--" };
+        for node in self.nodes() {
+            let edges:Vec<String> = self.neighbors(node).map(|(to, w)| format!("{} {:?}", to, w)).collect();
+            writeln!(f, "{} {} [{}]", node, arrow, edges.join(", "))?;
+        }
+        Ok(())
+    }
+}
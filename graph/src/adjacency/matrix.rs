@@ -0,0 +1,234 @@
+use core::fmt;
+use std::fmt::Debug;
+
+use crate::{adjacency::list::AdjList, base::{Graph, Kind}};
+
+/// Graph storing a cell for every pair of nodes, O(V²) memory but O(1) edge lookups.
+/// Suited to dense graphs and all-pairs algorithms
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::matrix::AdjMatrix, base::{Graph, Kind}};
+///
+/// let mut deps:AdjMatrix = AdjMatrix::with_nodes(Kind::Directed, 3);
+/// deps.add_edge(0, 1, ());
+/// deps.add_edge(0, 2, ());
+/// deps.add_edge(2, 1, ());
+///
+/// assert!(deps.contains_edge(2, 1));
+/// assert!(!deps.contains_edge(1, 2));
+/// assert_eq!(deps.in_degree(1), 2);
+/// assert_eq!(deps.neighbors(0).map(|(n, _)| n).collect::<Vec<usize>>(), vec![1, 2]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdjMatrix<W = ()> {
+    kind:Kind,
+    // side of the allocated matrix, grows by doubling
+    cap:usize,
+    // row major, cells[from * cap + to]
+    cells:Vec<Option<W>>,
+    present:Vec<bool>,
+    nodes:usize,
+    edges:usize,
+}
+
+impl<W> AdjMatrix<W> {
+    pub fn new(kind:Kind) -> Self {
+        Self { kind, cap: 0, cells: Vec::new(), present: Vec::new(), nodes: 0, edges: 0 }
+    }
+
+    pub fn directed() -> Self {
+        Self::new(Kind::Directed)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(Kind::Undirected)
+    }
+
+    /// Create a graph with the nodes `0..nodes` and no edges
+    pub fn with_nodes(kind:Kind, nodes:usize) -> Self {
+        let mut graph:Self = Self::new(kind);
+        graph.grow(nodes);
+        graph.present = vec![true; nodes];
+        graph.nodes = nodes;
+        graph
+    }
+
+    fn grow(&mut self, cap:usize) {
+        let mut cells:Vec<Option<W>> = (0..cap * cap).map(|_| None).collect();
+        for (i, cell) in std::mem::take(&mut self.cells).into_iter().enumerate() {
+            cells[(i / self.cap) * cap + i % self.cap] = cell;
+        }
+
+        self.cells = cells;
+        self.cap = cap;
+    }
+
+    fn cell(&self, from:usize, to:usize) -> Option<&Option<W>> {
+        if !self.contains_node(from) || !self.contains_node(to) { return None; }
+        Some(&self.cells[from * self.cap + to])
+    }
+
+    /// Add a node and return its id
+    pub fn add_node(&mut self) -> usize {
+        if self.present.len() == self.cap {
+            self.grow((2 * self.cap).max(4));
+        }
+
+        self.present.push(true);
+        self.nodes += 1;
+        self.present.len() - 1
+    }
+
+    /// Remove a node with every edge touching it
+    pub fn remove_node(&mut self, node:usize) -> bool {
+        if !self.contains_node(node) { return false; }
+
+        for other in 0..self.present.len() {
+            self.remove_edge(node, other);
+            if self.kind == Kind::Directed {
+                self.remove_edge(other, node);
+            }
+        }
+
+        self.present[node] = false;
+        self.nodes -= 1;
+        true
+    }
+
+    /// Add an edge, or replace its weight returning the previous one.
+    /// Panics if either node is missing
+    pub fn add_edge(&mut self, from:usize, to:usize, weight:W) -> Option<W>
+        where W: Clone
+    {
+        assert!(self.contains_node(from), "node {} is not in the graph", from);
+        assert!(self.contains_node(to), "node {} is not in the graph", to);
+
+        if self.kind == Kind::Undirected && from != to {
+            self.cells[to * self.cap + from] = Some(weight.clone());
+        }
+
+        let old:Option<W> = self.cells[from * self.cap + to].replace(weight);
+        if old.is_none() {
+            self.edges += 1;
+        }
+        old
+    }
+
+    /// Remove the edge between `from` and `to`, returning its weight
+    pub fn remove_edge(&mut self, from:usize, to:usize) -> Option<W> {
+        if !self.contains_node(from) || !self.contains_node(to) { return None; }
+
+        let weight:W = self.cells[from * self.cap + to].take()?;
+        if self.kind == Kind::Undirected {
+            self.cells[to * self.cap + from] = None;
+        }
+        self.edges -= 1;
+
+        Some(weight)
+    }
+
+    /// Nodes with an edge into `node`, the same as the neighbors for undirected graphs
+    pub fn in_neighbors(&self, node:usize) -> impl Iterator<Item = usize> {
+        (0..self.present.len()).filter(move |from| matches!(self.cell(*from, node), Some(Some(_))))
+    }
+
+    /// Build a graph over the nodes `0..nodes` from `(from, to, weight)` triples
+    pub fn from_edges<I>(kind:Kind, nodes:usize, edges:I) -> Self
+        where I: IntoIterator<Item = (usize, usize, W)>, W: Clone
+    {
+        let mut graph:Self = Self::with_nodes(kind, nodes);
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+impl<W> Graph for AdjMatrix<W> {
+    type Weight = W;
+
+    fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    fn node_bound(&self) -> usize {
+        self.present.len()
+    }
+
+    fn node_count(&self) -> usize {
+        self.nodes
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    fn contains_node(&self, node:usize) -> bool {
+        self.present.get(node).copied().unwrap_or(false)
+    }
+
+    fn neighbors(&self, node:usize) -> impl Iterator<Item = (usize, &W)> {
+        (0..self.present.len()).filter_map(move |to| match self.cell(node, to) {
+            Some(Some(w)) => Some((to, w)),
+            _ => None
+        })
+    }
+
+    fn edge_weight(&self, from:usize, to:usize) -> Option<&W> {
+        self.cell(from, to)?.as_ref()
+    }
+
+    fn in_degree(&self, node:usize) -> usize {
+        self.in_neighbors(node).count()
+    }
+}
+
+impl<W> From<&AdjList<W>> for AdjMatrix<W>
+    where W: Clone
+{
+    /// Same nodes and edges, removed node ids stay unused
+    fn from(list:&AdjList<W>) -> Self {
+        let mut matrix:Self = Self::with_nodes(list.kind(), list.node_bound());
+        for (from, to, w) in list.edges() {
+            matrix.add_edge(from, to, w.clone());
+        }
+        for node in (0..list.node_bound()).filter(|n| !list.contains_node(*n)) {
+            matrix.remove_node(node);
+        }
+        matrix
+    }
+}
+
+impl<W> From<&AdjMatrix<W>> for AdjList<W>
+    where W: Clone
+{
+    /// Same nodes and edges, removed node ids stay unused
+    fn from(matrix:&AdjMatrix<W>) -> Self {
+        let mut list:Self = Self::with_nodes(matrix.kind(), matrix.node_bound());
+        for (from, to, w) in matrix.edges() {
+            list.add_edge(from, to, w.clone());
+        }
+        for node in (0..matrix.node_bound()).filter(|n| !matrix.contains_node(*n)) {
+            list.remove_node(node);
+        }
+        list
+    }
+}
+
+impl<W> fmt::Display for AdjMatrix<W>
+    where W: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for from in self.nodes() {
+            let row:Vec<String> = self.nodes()
+                .map(|to| match self.edge_weight(from, to) {
+                    Some(w) => format!("{:?}", w),
+                    None => ".".to_string()
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+/// Whether edges have a direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Directed,
+    Undirected,
+}
+
+/// Read access shared by the graph representations, so the algorithms work on any of them.
+///
+/// Nodes are ids in `0..node_bound()`, the ids of removed nodes are never reused
+pub trait Graph {
+    type Weight;
+
+    fn kind(&self) -> Kind;
+
+    fn is_directed(&self) -> bool {
+        self.kind() == Kind::Directed
+    }
+
+    /// Every node id is below this bound, removed ones included
+    fn node_bound(&self) -> usize;
+
+    fn node_count(&self) -> usize;
+
+    fn edge_count(&self) -> usize;
+
+    fn contains_node(&self, node:usize) -> bool;
+
+    /// Ids of the nodes in increasing order
+    fn nodes(&self) -> impl Iterator<Item = usize> {
+        (0..self.node_bound()).filter(|n| self.contains_node(*n))
+    }
+
+    /// Nodes reachable through one edge from `node` with the weight of that edge
+    fn neighbors(&self, node:usize) -> impl Iterator<Item = (usize, &Self::Weight)>;
+
+    fn edge_weight(&self, from:usize, to:usize) -> Option<&Self::Weight>;
+
+    fn contains_edge(&self, from:usize, to:usize) -> bool {
+        self.edge_weight(from, to).is_some()
+    }
+
+    /// Every edge once as `(from, to, weight)`, undirected edges with `from <= to`
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, &Self::Weight)> {
+        let directed:bool = self.is_directed();
+        self.nodes().flat_map(move |from| {
+            self.neighbors(from)
+                .filter(move |(to, _)| directed || from <= *to)
+                .map(move |(to, w)| (from, to, w))
+        })
+    }
+
+    /// Edges leaving `node`, for undirected graphs the edges touching it
+    fn out_degree(&self, node:usize) -> usize {
+        self.neighbors(node).count()
+    }
+
+    /// Edges entering `node`, for undirected graphs the edges touching it
+    fn in_degree(&self, node:usize) -> usize;
+
+    /// Edges touching `node`, a loop counts twice
+    fn degree(&self, node:usize) -> usize {
+        if self.is_directed() {
+            return self.in_degree(node) + self.out_degree(node);
+        }
+
+        let loops:usize = usize::from(self.contains_edge(node, node));
+        self.out_degree(node) + loops
+    }
+}
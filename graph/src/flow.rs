@@ -0,0 +1,249 @@
+use structs::{list::list::{BaseOperations, List}, range::ops::Numeric};
+
+use crate::base::Graph;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Arc<C> {
+    to:usize,
+    // capacity left
+    cap:C,
+    // capacity before any flow
+    orig:C,
+}
+
+// residual network, arcs come in pairs 2i and 2i + 1 going opposite ways
+struct Network<C> {
+    arcs:Vec<Arc<C>>,
+    out:Vec<Vec<usize>>,
+}
+
+impl<C> Network<C> 
+    where C: Numeric
+{
+    fn new<G>(graph:&G) -> Self
+        where G: Graph<Weight = C>
+    {
+        let mut net:Self = Self { arcs: Vec::new(), out: vec![Vec::new(); graph.node_bound()] };
+
+        for (from, to, &w) in graph.edges() {
+            assert!(w >= C::ZERO, "capacities cannot be negative");
+            if from == to { continue; }
+
+            // an undirected edge carries flow either way
+            let back:C = if graph.is_directed() { C::ZERO } else { w };
+            net.out[from].push(net.arcs.len());
+            net.arcs.push(Arc { to, cap: w, orig: w });
+            net.out[to].push(net.arcs.len());
+            net.arcs.push(Arc { to: from, cap: back, orig: back });
+        }
+        net
+    }
+
+    fn push(&mut self, arc:usize, amount:C) {
+        self.arcs[arc].cap = self.arcs[arc].cap - amount;
+        self.arcs[arc ^ 1].cap = self.arcs[arc ^ 1].cap + amount;
+    }
+
+    // hops from `source` through arcs with capacity left, None for unreachable nodes
+    fn levels(&self, source:usize) -> Vec<Option<usize>> {
+        let mut level:Vec<Option<usize>> = vec![None; self.out.len()];
+        let mut queue:List<usize> = List::empty();
+        level[source] = Some(0);
+        queue.append(source);
+
+        while let Some(node) = queue.pop() {
+            for &arc in self.out[node].iter() {
+                let Arc { to, cap, .. } = self.arcs[arc];
+                if cap > C::ZERO && level[to].is_none() {
+                    level[to] = Some(level[node].unwrap() + 1);
+                    queue.append(to);
+                }
+            }
+        }
+        level
+    }
+
+    fn into_flow<G>(self, graph:&G, source:usize, total:C) -> Flow<C>
+        where G: Graph<Weight = C>
+    {
+        let mut edges:Vec<(usize, usize, C)> = Vec::new();
+        for pair in self.arcs.chunks(2) {
+            let (from, to) = (pair[1].to, pair[0].to);
+            let net:C = pair[0].orig - pair[0].cap;
+
+            if net > C::ZERO {
+                edges.push((from, to, net));
+            } else if net < C::ZERO {
+                edges.push((to, from, C::ZERO - net));
+            }
+        }
+
+        // nodes still reachable in the residual network form the source side of a minimum cut
+        let source_side:Vec<bool> = self.levels(source).into_iter().map(|l| l.is_some()).collect();
+        let mut cut:Vec<(usize, usize, C)> = Vec::new();
+        for (from, to, &w) in graph.edges() {
+            if source_side[from] && !source_side[to] {
+                cut.push((from, to, w));
+            } else if !graph.is_directed() && source_side[to] && !source_side[from] {
+                cut.push((to, from, w));
+            }
+        }
+
+        Flow { total, edges, cut, source_side }
+    }
+}
+
+/// Maximum flow between two nodes, with a minimum cut proving it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Flow<C> {
+    pub total:C,
+    /// Every edge carrying flow as `(from, to, flow)`, undirected edges in the direction used
+    pub edges:Vec<(usize, usize, C)>,
+    /// Edges of a minimum cut as `(from, to, capacity)` leaving the source side,
+    /// their capacities add up to `total`
+    pub cut:Vec<(usize, usize, C)>,
+    source_side:Vec<bool>,
+}
+
+impl<C> Flow<C> {
+    /// Whether `node` is on the source side of the minimum cut
+    pub fn is_source_side(&self, node:usize) -> bool {
+        self.source_side.get(node).copied().unwrap_or(false)
+    }
+}
+
+/// Maximum flow with Edmonds-Karp, O(V E²): flow is pushed along the shortest path
+/// with capacity left until there is none. Edge weights are the capacities
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, flow::{dinic, edmonds_karp}};
+///
+/// let pipes:AdjList<u32> = AdjList::from_edges(Kind::Directed, 6, [
+///     (0, 1, 10), (0, 2, 10), (1, 2, 2), (1, 3, 4), (1, 4, 8), (2, 4, 9), (4, 3, 6), (3, 5, 10), (4, 5, 10)
+/// ]);
+///
+/// let flow = edmonds_karp(&pipes, 0, 5);
+/// assert_eq!(flow.total, 19);
+/// assert_eq!(flow.cut.iter().map(|e| e.2).sum::<u32>(), 19);
+/// assert!(flow.is_source_side(2) && !flow.is_source_side(5));
+///
+/// assert_eq!(dinic(&pipes, 0, 5).total, 19);
+/// ```
+pub fn edmonds_karp<G, C>(graph:&G, source:usize, sink:usize) -> Flow<C>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    assert!(graph.contains_node(source) && graph.contains_node(sink), "source and sink must be in the graph");
+    assert!(source != sink, "source and sink must differ");
+
+    let mut net:Network<C> = Network::new(graph);
+    let mut total:C = C::ZERO;
+
+    loop {
+        // arc used to reach every node
+        let mut via:Vec<Option<usize>> = vec![None; graph.node_bound()];
+        let mut queue:List<usize> = List::empty();
+        queue.append(source);
+
+        while let Some(node) = queue.pop() {
+            if node == sink { break; }
+
+            for &arc in net.out[node].iter() {
+                let Arc { to, cap, .. } = net.arcs[arc];
+                if cap > C::ZERO && to != source && via[to].is_none() {
+                    via[to] = Some(arc);
+                    queue.append(to);
+                }
+            }
+        }
+
+        if via[sink].is_none() { break; }
+
+        let mut path:Vec<usize> = Vec::new();
+        let mut node:usize = sink;
+        while let Some(arc) = via[node] {
+            path.push(arc);
+            node = net.arcs[arc ^ 1].to;
+        }
+
+        let mut amount:C = C::MAX;
+        for &arc in path.iter() {
+            if net.arcs[arc].cap < amount {
+                amount = net.arcs[arc].cap;
+            }
+        }
+        for &arc in path.iter() {
+            net.push(arc, amount);
+        }
+        total = total + amount;
+    }
+
+    net.into_flow(graph, source, total)
+}
+
+/// Maximum flow with Dinic's algorithm, O(V² E) and much faster in practice: nodes are
+/// layered by distance from the source, then a blocking flow is pushed along the layers.
+/// Edge weights are the capacities
+pub fn dinic<G, C>(graph:&G, source:usize, sink:usize) -> Flow<C>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    assert!(graph.contains_node(source) && graph.contains_node(sink), "source and sink must be in the graph");
+    assert!(source != sink, "source and sink must differ");
+
+    let mut net:Network<C> = Network::new(graph);
+    let mut total:C = C::ZERO;
+
+    loop {
+        let mut level:Vec<Option<usize>> = net.levels(source);
+        if level[sink].is_none() { break; }
+
+        // next arc to try out of every node, dead ends are never retried
+        let mut next:Vec<usize> = vec![0; graph.node_bound()];
+        let mut path:Vec<usize> = Vec::new();
+
+        loop {
+            let node:usize = path.last().map_or(source, |&arc| net.arcs[arc].to);
+
+            if node == sink {
+                let mut amount:C = C::MAX;
+                for &arc in path.iter() {
+                    if net.arcs[arc].cap < amount {
+                        amount = net.arcs[arc].cap;
+                    }
+                }
+                for &arc in path.iter() {
+                    net.push(arc, amount);
+                }
+                total = total + amount;
+
+                // back up to the tail of the first saturated arc
+                let first:usize = path.iter().position(|&arc| net.arcs[arc].cap <= C::ZERO).unwrap();
+                path.truncate(first);
+                continue;
+            }
+
+            let mut found:Option<usize> = None;
+            while let Some(&arc) = net.out[node].get(next[node]) {
+                let Arc { to, cap, .. } = net.arcs[arc];
+                if cap > C::ZERO && level[to].is_some() && level[to] == level[node].map(|l| l + 1) {
+                    found = Some(arc);
+                    break;
+                }
+                next[node] += 1;
+            }
+
+            match found {
+                Some(arc) => path.push(arc),
+                None => {
+                    level[node] = None;
+
+                    let Some(arc) = path.pop() else { break; };
+                    let tail:usize = net.arcs[arc ^ 1].to;
+                    next[tail] += 1;
+                }
+            }
+        }
+    }
+
+    net.into_flow(graph, source, total)
+}
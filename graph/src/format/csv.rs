@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+use crate::{adjacency::list::AdjList, base::{Graph, Kind}, format::text::{tokens, ParseError, TextWeight}};
+
+/// Read a square adjacency matrix with one row per line and comma separated cells, the cell
+/// at row `from` and column `to` holding the weight of the edge between them. Empty cells
+/// have no edge, unweighted graphs use `1` for an edge and `0` or nothing otherwise.
+/// Undirected matrices must be symmetric
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::{Graph, Kind}, format::csv::{read_matrix_csv, write_matrix_csv}};
+///
+/// let text:&str = "0,1,0\n0,0,1\n1,0,0\n";
+/// let ring:AdjList = read_matrix_csv(text, Kind::Directed).unwrap();
+/// assert_eq!(ring.edges().map(|(a, b, _)| (a, b)).collect::<Vec<(usize, usize)>>(), vec![(0, 1), (1, 2), (2, 0)]);
+///
+/// let weighted:AdjList<f64> = read_matrix_csv(" , 2.5\n2.5 , \n", Kind::Undirected).unwrap();
+/// assert_eq!(weighted.edge_weight(1, 0), Some(&2.5));
+/// assert_eq!(write_matrix_csv(&weighted), ",2.5\n2.5,\n");
+///
+/// let err = read_matrix_csv::<u8>("1,2\n3\n", Kind::Directed).unwrap_err();
+/// assert_eq!(err.to_string(), "line 2, column 2: expected 2 cells, found 1");
+/// ```
+pub fn read_matrix_csv<W>(text:&str, kind:Kind) -> Result<AdjList<W>, ParseError>
+    where W: TextWeight + Clone + PartialEq
+{
+    let rows:Vec<(usize, &str)> = text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    let size:usize = rows.len();
+
+    let mut cells:Vec<Vec<Option<W>>> = Vec::with_capacity(size);
+    // where every cell starts, to point at the asymmetric ones
+    let mut places:Vec<Vec<(usize, usize)>> = Vec::with_capacity(size);
+
+    for &(line_no, line) in rows.iter() {
+        let toks:Vec<(usize, &str)> = tokens(line, Some(','));
+        if toks.len() != size {
+            let column:usize = toks.get(size).map_or(line.chars().count() + 1, |t| t.0);
+            return Err(ParseError::new(line_no, column, format!("expected {} cells, found {}", size, toks.len())));
+        }
+
+        let mut row:Vec<Option<W>> = Vec::with_capacity(size);
+        for &(column, tok) in toks.iter() {
+            let cell:&str = tok.trim();
+            let weight:Option<W> = match (cell, W::WEIGHTED) {
+                ("", _) | ("0", false) => None,
+                ("1", false) => Some(W::read("").map_err(|msg| ParseError::new(line_no, column, msg))?),
+                (_, false) => return Err(ParseError::new(line_no, column, format!("expected 0 or 1, found '{}'", cell))),
+                (_, true) => Some(W::read(cell).map_err(|msg| ParseError::new(line_no, column, msg))?)
+            };
+            row.push(weight);
+        }
+
+        places.push(toks.iter().map(|t| (line_no, t.0)).collect());
+        cells.push(row);
+    }
+
+    let mut graph:AdjList<W> = AdjList::with_nodes(kind, size);
+    for (from, row) in cells.iter().enumerate() {
+        for (to, cell) in row.iter().enumerate() {
+            if kind == Kind::Undirected {
+                if cells[to][from] != *cell {
+                    let (line, column) = places[from][to];
+                    return Err(ParseError::new(line, column, format!("undirected matrix is not symmetric at ({}, {})", from, to)));
+                }
+                if to < from { continue; }
+            }
+
+            if let Some(w) = cell {
+                graph.add_edge(from, to, w.clone());
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Write the adjacency matrix over the ids `0..node_bound()` in the format `read_matrix_csv` takes
+pub fn write_matrix_csv<G>(graph:&G) -> String
+    where G: Graph, G::Weight: TextWeight
+{
+    let mut out:String = String::new();
+
+    for from in 0..graph.node_bound() {
+        let row:Vec<String> = (0..graph.node_bound())
+            .map(|to| match graph.edge_weight(from, to) {
+                Some(w) if G::Weight::WEIGHTED => w.write(),
+                Some(_) => "1".to_string(),
+                None if G::Weight::WEIGHTED => String::new(),
+                None => "0".to_string()
+            })
+            .collect();
+        let _ = writeln!(out, "{}", row.join(","));
+    }
+
+    out
+}
@@ -0,0 +1,358 @@
+use std::{collections::HashMap, fmt::Write};
+
+use crate::{adjacency::list::AdjList, base::{Graph, Kind}, format::text::{ParseError, TextWeight}};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Id(String),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Semi,
+    Comma,
+    Equal,
+    Colon,
+    // true for `->`
+    EdgeOp(bool),
+}
+
+// token with the line and column it starts at
+type Spanned = (Token, usize, usize);
+
+fn lex(text:&str) -> Result<Vec<Spanned>, ParseError> {
+    let mut res:Vec<Spanned> = Vec::new();
+    let chars:Vec<char> = text.chars().collect();
+    let (mut i, mut line, mut column) = (0, 1, 1);
+
+    // move past `n` chars keeping track of the position
+    let advance = |i:&mut usize, line:&mut usize, column:&mut usize, n:usize| {
+        for _ in 0..n {
+            if chars[*i] == '\n' {
+                *line += 1;
+                *column = 1;
+            } else {
+                *column += 1;
+            }
+            *i += 1;
+        }
+    };
+
+    while i < chars.len() {
+        let c:char = chars[i];
+        let next:Option<char> = chars.get(i + 1).copied();
+        let (start_line, start_column) = (line, column);
+
+        // `#` lines are preprocessor output, skipped like comments
+        let line_comment:bool = (c == '/' && next == Some('/')) || (c == '#' && column == 1);
+        if c.is_whitespace() {
+            advance(&mut i, &mut line, &mut column, 1);
+        } else if line_comment {
+            while i < chars.len() && chars[i] != '\n' {
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+        } else if c == '/' && next == Some('*') {
+            advance(&mut i, &mut line, &mut column, 2);
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            if i == chars.len() {
+                return Err(ParseError::new(start_line, start_column, "unterminated comment"));
+            }
+            advance(&mut i, &mut line, &mut column, 2);
+        } else if c == '-' && (next == Some('>') || next == Some('-')) {
+            res.push((Token::EdgeOp(next == Some('>')), line, column));
+            advance(&mut i, &mut line, &mut column, 2);
+        } else if c == '"' {
+            advance(&mut i, &mut line, &mut column, 1);
+            let mut id:String = String::new();
+            loop {
+                match chars.get(i) {
+                    None => return Err(ParseError::new(start_line, start_column, "unterminated string")),
+                    Some('"') => break,
+                    Some('\\') if chars.get(i + 1) == Some(&'"') => {
+                        id.push('"');
+                        advance(&mut i, &mut line, &mut column, 2);
+                    },
+                    Some(&c) => {
+                        id.push(c);
+                        advance(&mut i, &mut line, &mut column, 1);
+                    }
+                }
+            }
+            advance(&mut i, &mut line, &mut column, 1);
+            res.push((Token::Id(id), start_line, start_column));
+        } else if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' {
+            let mut id:String = String::new();
+            while let Some(&c) = chars.get(i) {
+                let more:bool = c.is_alphanumeric() || c == '_' || c == '.' || (c == '-' && id.is_empty());
+                if !more { break; }
+                id.push(c);
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            res.push((Token::Id(id), start_line, start_column));
+        } else {
+            let token:Token = match c {
+                '{' => Token::LBrace,
+                '}' => Token::RBrace,
+                '[' => Token::LBracket,
+                ']' => Token::RBracket,
+                ';' => Token::Semi,
+                ',' => Token::Comma,
+                '=' => Token::Equal,
+                ':' => Token::Colon,
+                _ => return Err(ParseError::new(line, column, format!("unexpected character '{}'", c)))
+            };
+            res.push((token, line, column));
+            advance(&mut i, &mut line, &mut column, 1);
+        }
+    }
+
+    Ok(res)
+}
+
+struct Parser {
+    tokens:Vec<Spanned>,
+    pos:usize,
+    // position reported when the input ends too early
+    end:(usize, usize),
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.0)
+    }
+
+    fn place(&self) -> (usize, usize) {
+        self.tokens.get(self.pos).map_or(self.end, |t| (t.1, t.2))
+    }
+
+    fn error(&self, message:impl Into<String>) -> ParseError {
+        let (line, column) = self.place();
+        ParseError::new(line, column, message)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token:Option<Token> = self.peek().cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token:&Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, token:Token, what:&str) -> Result<(), ParseError> {
+        if self.eat(&token) { return Ok(()); }
+        Err(self.error(format!("expected {}", what)))
+    }
+
+    fn id(&mut self, what:&str) -> Result<String, ParseError> {
+        match self.peek() {
+            Some(Token::Id(id)) => {
+                let id:String = id.clone();
+                self.pos += 1;
+                Ok(id)
+            },
+            _ => Err(self.error(format!("expected {}", what)))
+        }
+    }
+
+    fn keyword(&self, word:&str) -> bool {
+        matches!(self.peek(), Some(Token::Id(id)) if id.eq_ignore_ascii_case(word))
+    }
+
+    // `[a=b, c=d][e=f]`, possibly none
+    fn attributes(&mut self) -> Result<Vec<(String, String)>, ParseError> {
+        let mut attrs:Vec<(String, String)> = Vec::new();
+        while self.eat(&Token::LBracket) {
+            while !self.eat(&Token::RBracket) {
+                let key:String = self.id("an attribute name or ']'")?;
+                self.expect(Token::Equal, "'='")?;
+                let value:String = self.id("an attribute value")?;
+                attrs.push((key, value));
+
+                if !self.eat(&Token::Comma) {
+                    self.eat(&Token::Semi);
+                }
+            }
+        }
+        Ok(attrs)
+    }
+}
+
+/// Graph read from DOT with the name of every node, nodes are numbered in order of appearance
+#[derive(Clone, Debug, PartialEq)]
+pub struct DotGraph<W> {
+    pub graph:AdjList<W>,
+    pub names:Vec<String>,
+}
+
+/// Read a Graphviz `graph` or `digraph` made of node, edge and attribute statements.
+/// Edge weights come from the `weight` attribute, or `label` when there is none.
+/// Subgraphs and ports are not supported
+///
+/// #Example
+/// ```
+/// use graph::{base::{Graph, Kind}, format::dot::{read_dot, DotGraph}};
+///
+/// let text:&str = r#"
+///     digraph build {
+///         node [shape=box];
+///         app -> core -> "std lib" [label=2];
+///         app -> tests [weight=5];
+///     }
+/// "#;
+///
+/// let dot:DotGraph<u32> = read_dot(text).unwrap();
+/// assert_eq!(dot.graph.kind(), Kind::Directed);
+/// assert_eq!(dot.names, vec!["app", "core", "std lib", "tests"]);
+/// assert_eq!(dot.graph.edge_weight(1, 2), Some(&2));
+/// assert_eq!(dot.graph.edge_weight(0, 3), Some(&5));
+///
+/// let err = read_dot::<()>("graph { a -> b }").unwrap_err();
+/// assert_eq!(err.to_string(), "line 1, column 11: use '--' for the edges of a graph");
+/// ```
+pub fn read_dot<W>(text:&str) -> Result<DotGraph<W>, ParseError>
+    where W: TextWeight + Clone
+{
+    let last_line:usize = text.lines().count().max(1);
+    let last_column:usize = text.lines().last().map_or(0, |l| l.chars().count()) + 1;
+    let mut p:Parser = Parser { tokens: lex(text)?, pos: 0, end: (last_line, last_column) };
+
+    if p.keyword("strict") {
+        p.pos += 1;
+    }
+    let kind:Kind = if p.keyword("digraph") {
+        Kind::Directed
+    } else if p.keyword("graph") {
+        Kind::Undirected
+    } else {
+        return Err(p.error("expected 'graph' or 'digraph'"));
+    };
+    p.pos += 1;
+
+    if let Some(Token::Id(_)) = p.peek() {
+        p.pos += 1;
+    }
+    p.expect(Token::LBrace, "'{'")?;
+
+    let mut dot:DotGraph<W> = DotGraph { graph: AdjList::new(kind), names: Vec::new() };
+    let mut ids:HashMap<String, usize> = HashMap::new();
+    let mut node = |name:String, dot:&mut DotGraph<W>| -> usize {
+        *ids.entry(name).or_insert_with_key(|name| {
+            dot.names.push(name.clone());
+            dot.graph.add_node()
+        })
+    };
+
+    while !p.eat(&Token::RBrace) {
+        if p.peek().is_none() {
+            return Err(p.error("expected '}'"));
+        }
+        if p.keyword("subgraph") || p.peek() == Some(&Token::LBrace) {
+            return Err(p.error("subgraphs are not supported"));
+        }
+
+        // default attributes for the graph, nodes or edges
+        if ["graph", "node", "edge"].iter().any(|k| p.keyword(k)) {
+            p.pos += 1;
+            p.attributes()?;
+            p.eat(&Token::Semi);
+            continue;
+        }
+
+        let first:String = p.id("a node or '}'")?;
+        if p.eat(&Token::Equal) {
+            p.id("an attribute value")?;
+            p.eat(&Token::Semi);
+            continue;
+        }
+
+        let mut chain:Vec<(String, (usize, usize))> = vec![(first, (0, 0))];
+        loop {
+            if p.peek() == Some(&Token::Colon) {
+                return Err(p.error("ports are not supported"));
+            }
+
+            let place:(usize, usize) = p.place();
+            match p.peek() {
+                Some(Token::EdgeOp(directed)) => {
+                    if *directed != (kind == Kind::Directed) {
+                        let op:&str = if kind == Kind::Directed { "->" } else { "--" };
+                        let graph:&str = if kind == Kind::Directed { "digraph" } else { "graph" };
+                        return Err(p.error(format!("use '{}' for the edges of a {}", op, graph)));
+                    }
+                    p.next();
+                    let name:String = p.id("a node")?;
+                    chain.push((name, place));
+                },
+                _ => break
+            }
+        }
+
+        let attrs:Vec<(String, String)> = p.attributes()?;
+        p.eat(&Token::Semi);
+
+        let ids:Vec<usize> = chain.iter().map(|(name, _)| node(name.clone(), &mut dot)).collect();
+        if ids.len() == 1 { continue; }
+
+        let weight:W = if W::WEIGHTED {
+            let value:Option<&String> = ["weight", "label"].iter()
+                .find_map(|key| attrs.iter().find(|(k, _)| k == key).map(|(_, v)| v));
+            let (line, column) = chain[1].1;
+
+            match value {
+                Some(value) => W::read(value).map_err(|msg| ParseError::new(line, column, msg))?,
+                None => return Err(ParseError::new(line, column, "edge has no 'weight' or 'label'"))
+            }
+        } else {
+            W::read("").map_err(|msg| p.error(msg))?
+        };
+
+        for pair in ids.windows(2) {
+            dot.graph.add_edge(pair[0], pair[1], weight.clone());
+        }
+    }
+
+    if p.peek().is_some() {
+        return Err(p.error("unexpected input after the graph"));
+    }
+    Ok(dot)
+}
+
+/// Write as a Graphviz `graph` or `digraph`, every node named by its id and declared first
+/// so reading it back numbers them in the same order. Weights become edge labels
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, format::dot::write_dot};
+///
+/// let g:AdjList<i32> = AdjList::from_edges(Kind::Undirected, 3, [(0, 1, -4), (1, 2, 6)]);
+/// assert_eq!(write_dot(&g, "g"), "graph \"g\" {\n    0;\n    1;\n    2;\n    0 -- 1 [label=\"-4\"];\n    1 -- 2 [label=\"6\"];\n}\n");
+/// ```
+pub fn write_dot<G>(graph:&G, name:&str) -> String
+    where G: Graph, G::Weight: TextWeight
+{
+    let (keyword, op) = if graph.is_directed() { ("digraph", "->") } else { ("graph", "--") };
+    let mut out:String = String::new();
+
+    let _ = writeln!(out, "{} \"{}\" {{", keyword, name.replace('"', "\\\""));
+    for node in graph.nodes() {
+        let _ = writeln!(out, "    {};", node);
+    }
+    for (from, to, w) in graph.edges() {
+        if G::Weight::WEIGHTED {
+            let _ = writeln!(out, "    {} {} {} [label=\"{}\"];", from, op, to, w.write().replace('"', "\\\""));
+        } else {
+            let _ = writeln!(out, "    {} {} {};", from, op, to);
+        }
+    }
+    out.push_str("}\n");
+
+    out
+}
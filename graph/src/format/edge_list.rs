@@ -0,0 +1,103 @@
+use std::fmt::Write;
+
+use crate::{adjacency::list::AdjList, base::{Graph, Kind}, format::text::{tokens, ParseError, TextWeight}};
+
+/// Node ids `read_edge_list` accepts are below this, the graph gets a list for every id up to the largest
+pub const MAX_NODES:usize = 1 << 24;
+
+/// Read a graph from lines of `from to weight`, or `from to` when unweighted, separated by
+/// whitespace. A line with a single node adds it without edges, blank lines and those starting
+/// with `#` are skipped. Nodes run up to the largest id found, which must be below `MAX_NODES`,
+/// a repeated edge keeps its last weight
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::{Graph, Kind}, format::edge_list::{read_edge_list, write_edge_list}};
+///
+/// let text:&str = "# roads\n0 1 7\n1 2 3\n4\n";
+/// let roads:AdjList<u32> = read_edge_list(text, Kind::Undirected).unwrap();
+///
+/// assert_eq!(roads.node_count(), 5);
+/// assert_eq!(roads.edge_weight(2, 1), Some(&3));
+/// assert_eq!(write_edge_list(&roads), "0 1 7\n1 2 3\n3\n4\n");
+///
+/// let err = read_edge_list::<u32>("0 1 7\n1 x 3\n", Kind::Undirected).unwrap_err();
+/// assert_eq!(err.to_string(), "line 2, column 3: invalid node id 'x'");
+///
+/// let err = read_edge_list::<u32>("0 1 7\n1 99999999999 3\n", Kind::Undirected).unwrap_err();
+/// assert_eq!(err.to_string(), "line 2, column 3: node id 99999999999 is not below 16777216");
+/// ```
+pub fn read_edge_list<W>(text:&str, kind:Kind) -> Result<AdjList<W>, ParseError>
+    where W: TextWeight + Clone
+{
+    let mut nodes:Vec<usize> = Vec::new();
+    let mut edges:Vec<(usize, usize, W)> = Vec::new();
+    let fields:usize = if W::WEIGHTED { 3 } else { 2 };
+
+    for (i, line) in text.lines().enumerate() {
+        let line_no:usize = i + 1;
+        let toks:Vec<(usize, &str)> = tokens(line, None);
+        if toks.is_empty() || toks[0].1.starts_with('#') { continue; }
+
+        if toks.len() != 1 && toks.len() != fields {
+            let column:usize = toks.get(fields).map_or(line.chars().count() + 1, |t| t.0);
+            let expected:&str = if W::WEIGHTED { "'from to weight'" } else { "'from to'" };
+            return Err(ParseError::new(line_no, column, format!("expected {} or a single node", expected)));
+        }
+
+        let mut ids:Vec<usize> = Vec::new();
+        for &(column, tok) in toks.iter().take(2) {
+            let id:usize = tok.parse().map_err(|_| ParseError::new(line_no, column, format!("invalid node id '{}'", tok)))?;
+            if id >= MAX_NODES {
+                return Err(ParseError::new(line_no, column, format!("node id {} is not below {}", id, MAX_NODES)));
+            }
+            ids.push(id);
+        }
+
+        match ids[..] {
+            [node] => nodes.push(node),
+            [from, to] => {
+                let weight:W = match toks.get(2) {
+                    Some(&(column, tok)) => W::read(tok).map_err(|msg| ParseError::new(line_no, column, msg))?,
+                    None => W::read("").map_err(|msg| ParseError::new(line_no, 1, msg))?
+                };
+                edges.push((from, to, weight));
+            },
+            _ => unreachable!()
+        }
+    }
+
+    let bound:usize = nodes.iter().copied()
+        .chain(edges.iter().flat_map(|e| [e.0, e.1]))
+        .max()
+        .map_or(0, |max| max + 1);
+
+    Ok(AdjList::from_edges(kind, bound, edges))
+}
+
+/// Write every edge on its own line, then every node without edges, in the format
+/// `read_edge_list` takes
+pub fn write_edge_list<G>(graph:&G) -> String
+    where G: Graph, G::Weight: TextWeight
+{
+    let mut out:String = String::new();
+
+    for (from, to, w) in graph.edges() {
+        if G::Weight::WEIGHTED {
+            let _ = writeln!(out, "{} {} {}", from, to, w.write());
+        } else {
+            let _ = writeln!(out, "{} {}", from, to);
+        }
+    }
+
+    let mut linked:Vec<bool> = vec![false; graph.node_bound()];
+    for (from, to, _) in graph.edges() {
+        linked[from] = true;
+        linked[to] = true;
+    }
+    for node in graph.nodes().filter(|n| !linked[*n]) {
+        let _ = writeln!(out, "{}", node);
+    }
+
+    out
+}
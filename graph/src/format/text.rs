@@ -0,0 +1,95 @@
+use core::fmt;
+use std::error::Error;
+
+/// Malformed input with the place it was found, lines and columns counting from 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line:usize,
+    pub column:usize,
+    pub message:String,
+}
+
+impl ParseError {
+    pub fn new(line:usize, column:usize, message:impl Into<String>) -> Self {
+        Self { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Edge weights that can be written as text and read back, `()` for unweighted graphs
+pub trait TextWeight: Sized {
+    /// Whether edges carry a weight in the text formats
+    const WEIGHTED:bool;
+
+    fn read(text:&str) -> Result<Self, String>;
+
+    fn write(&self) -> String;
+}
+
+impl TextWeight for () {
+    const WEIGHTED:bool = false;
+
+    fn read(_text:&str) -> Result<Self, String> {
+        Ok(())
+    }
+
+    fn write(&self) -> String {
+        String::new()
+    }
+}
+
+macro_rules! impl_text_weight {
+    ($($t:ty),*) => {$(
+        impl TextWeight for $t {
+            const WEIGHTED:bool = true;
+
+            fn read(text:&str) -> Result<Self, String> {
+                text.parse().map_err(|_| format!("invalid {} weight '{}'", stringify!($t), text))
+            }
+
+            fn write(&self) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+
+impl_text_weight!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Tokens of a line separated by `sep`, or by whitespace when `sep` is None,
+/// each with the column it starts at
+pub(crate) fn tokens(line:&str, sep:Option<char>) -> Vec<(usize, &str)> {
+    let mut res:Vec<(usize, &str)> = Vec::new();
+    let mut start:Option<(usize, usize)> = None;
+
+    for (column, (i, c)) in line.char_indices().enumerate() {
+        let split:bool = match sep {
+            Some(sep) => c == sep,
+            None => c.is_whitespace()
+        };
+
+        match (split, start) {
+            (true, Some((col, from))) => {
+                res.push((col + 1, &line[from..i]));
+                start = None;
+            },
+            (true, None) if sep.is_some() => res.push((column + 1, "")),
+            (false, None) => start = Some((column, i)),
+            _ => {}
+        }
+    }
+
+    match start {
+        Some((col, from)) => res.push((col + 1, &line[from..])),
+        None if sep.is_some() => res.push((line.chars().count() + 1, "")),
+        None => {}
+    }
+    res
+}
@@ -0,0 +1,29 @@
+pub mod base;
+
+pub mod adjacency {
+    pub mod list;
+    pub mod matrix;
+}
+
+pub mod shortest {
+    pub mod paths;
+    pub mod dijkstra;
+    pub mod bellman_ford;
+    pub mod floyd_warshall;
+}
+
+pub mod traversal {
+    pub mod search;
+    pub mod topo;
+    pub mod components;
+}
+
+pub mod spanning;
+pub mod flow;
+
+pub mod format {
+    pub mod text;
+    pub mod edge_list;
+    pub mod csv;
+    pub mod dot;
+}
@@ -0,0 +1,75 @@
+use structs::range::ops::Numeric;
+
+use crate::{base::Graph, shortest::paths::{NegativeCycle, ShortestPaths}};
+
+/// Shortest paths from `source` in O(V E), allowing negative weights. Fails with the cycle
+/// when one of negative total weight can be reached from `source`.
+///
+/// An undirected edge goes both ways, so a single negative one is already such a cycle
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, shortest::bellman_ford::bellman_ford};
+///
+/// let mut trades:AdjList<i32> = AdjList::from_edges(Kind::Directed, 4, [
+///     (0, 1, 5), (0, 2, 2), (2, 1, -4), (1, 3, 1)
+/// ]);
+///
+/// let paths = bellman_ford(&trades, 0).unwrap();
+/// assert_eq!(paths.distance(3), Some(-1));
+/// assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+///
+/// trades.add_edge(3, 2, 1);
+/// let err = bellman_ford(&trades, 0).unwrap_err();
+/// assert_eq!(err.cycle, vec![1, 3, 2]);
+/// ```
+pub fn bellman_ford<G, C>(graph:&G, source:usize) -> Result<ShortestPaths<C>, NegativeCycle>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    assert!(graph.contains_node(source), "node {} is not in the graph", source);
+
+    let mut paths:ShortestPaths<C> = ShortestPaths::new(source, graph.node_bound());
+    let rounds:usize = graph.node_count();
+
+    // after V - 1 rounds every shortest path is settled, any change in round V is a cycle
+    for round in 0..rounds {
+        let mut changed:Option<usize> = None;
+
+        for from in graph.nodes() {
+            let Some(dist) = paths.distance(from) else { continue; };
+
+            for (to, &w) in graph.neighbors(from) {
+                if paths.relax(from, to, dist + w) {
+                    changed = Some(to);
+                }
+            }
+        }
+
+        match changed {
+            None => break,
+            Some(node) if round + 1 == rounds => return Err(cycle_through(&paths, node, rounds)),
+            Some(_) => {}
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Walk the predecessors of a node relaxed in the last round, V steps back lands on the cycle
+fn cycle_through<C>(paths:&ShortestPaths<C>, mut node:usize, rounds:usize) -> NegativeCycle
+    where C: Numeric
+{
+    for _ in 0..rounds {
+        node = paths.predecessor(node).unwrap();
+    }
+
+    let mut cycle:Vec<usize> = vec![node];
+    let mut cur:usize = paths.predecessor(node).unwrap();
+    while cur != node {
+        cycle.push(cur);
+        cur = paths.predecessor(cur).unwrap();
+    }
+    cycle.reverse();
+
+    NegativeCycle::new(cycle)
+}
@@ -0,0 +1,94 @@
+use structs::{heap::IndexedMinHeap, range::ops::Numeric};
+
+use crate::{base::Graph, shortest::paths::ShortestPaths};
+
+/// Shortest paths from `source` to every node in O((V + E) log V), for non-negative weights.
+/// Nodes wait in an `IndexedMinHeap` whose decrease-key updates them in place when a
+/// shorter path shows up. Panics on a negative weight
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, shortest::dijkstra::dijkstra};
+///
+/// let roads:AdjList<u32> = AdjList::from_edges(Kind::Undirected, 5, [
+///     (0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5), (2, 3, 8)
+/// ]);
+///
+/// let paths = dijkstra(&roads, 0);
+/// assert_eq!(paths.distance(3), Some(8));
+/// assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+/// assert_eq!(paths.distance(4), None);
+/// ```
+pub fn dijkstra<G, C>(graph:&G, source:usize) -> ShortestPaths<C>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    assert!(graph.contains_node(source), "node {} is not in the graph", source);
+
+    let mut paths:ShortestPaths<C> = ShortestPaths::new(source, graph.node_bound());
+    let mut heap:IndexedMinHeap<C> = IndexedMinHeap::new(graph.node_bound());
+    heap.push(source, C::ZERO);
+
+    while let Some((node, dist)) = heap.pop() {
+        for (next, &w) in graph.neighbors(node) {
+            assert!(w >= C::ZERO, "dijkstra needs non-negative weights, use bellman_ford");
+
+            if paths.relax(node, next, dist + w) {
+                heap.push(next, dist + w);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Shortest path from `source` to `target` with its length, guided by `heuristic`, an
+/// estimate of the remaining distance from a node to `target`. The result is exact as long
+/// as the heuristic never overestimates, and with a zero heuristic this is Dijkstra
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, shortest::dijkstra::a_star};
+///
+/// // 3x3 grid, node = 3 * row + col
+/// let mut edges:Vec<(usize, usize, u32)> = Vec::new();
+/// for node in 0..9 {
+///     if node % 3 < 2 { edges.push((node, node + 1, 1)); }
+///     if node < 6 { edges.push((node, node + 3, 1)); }
+/// }
+/// let grid:AdjList<u32> = AdjList::from_edges(Kind::Undirected, 9, edges);
+///
+/// let manhattan = |node:usize| (2 - node / 3 + 2 - node % 3) as u32;
+/// let (len, path) = a_star(&grid, 0, 8, manhattan).unwrap();
+///
+/// assert_eq!(len, 4);
+/// assert_eq!(path.len(), 5);
+/// assert_eq!((path[0], path[4]), (0, 8));
+/// ```
+pub fn a_star<G, C, H>(graph:&G, source:usize, target:usize, heuristic:H) -> Option<(C, Vec<usize>)>
+    where G: Graph<Weight = C>, C: Numeric, H: Fn(usize) -> C
+{
+    assert!(graph.contains_node(source), "node {} is not in the graph", source);
+
+    let mut paths:ShortestPaths<C> = ShortestPaths::new(source, graph.node_bound());
+    // keyed by the known distance plus the estimate of what is left
+    let mut heap:IndexedMinHeap<C> = IndexedMinHeap::new(graph.node_bound());
+    heap.push(source, heuristic(source));
+
+    while let Some((node, _)) = heap.pop() {
+        if node == target {
+            return Some((paths.distance(target)?, paths.path_to(target)?));
+        }
+
+        let dist:C = paths.distance(node).unwrap();
+        for (next, &w) in graph.neighbors(node) {
+            assert!(w >= C::ZERO, "a_star needs non-negative weights");
+
+            // a node closed too early by an inconsistent heuristic simply goes back in
+            if paths.relax(node, next, dist + w) {
+                heap.push(next, dist + w + heuristic(next));
+            }
+        }
+    }
+
+    None
+}
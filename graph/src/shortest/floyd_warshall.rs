@@ -0,0 +1,116 @@
+use structs::range::ops::Numeric;
+
+use crate::{base::Graph, shortest::paths::NegativeCycle};
+
+/// Distances between every pair of nodes with the next hop of every shortest path
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllPairs<C> {
+    bound:usize,
+    // row major, dist[from * bound + to]
+    dist:Vec<Option<C>>,
+    next:Vec<Option<usize>>,
+}
+
+impl<C> AllPairs<C> 
+    where C: Numeric
+{
+    /// Length of the shortest path from `from` to `to`, None when it cannot be reached
+    pub fn distance(&self, from:usize, to:usize) -> Option<C> {
+        if from >= self.bound || to >= self.bound { return None; }
+        self.dist[from * self.bound + to]
+    }
+
+    /// Nodes of the shortest path from `from` to `to`, both included
+    pub fn path(&self, from:usize, to:usize) -> Option<Vec<usize>> {
+        self.distance(from, to)?;
+
+        let mut path:Vec<usize> = vec![from];
+        let mut cur:usize = from;
+        while cur != to {
+            cur = self.next[cur * self.bound + to]?;
+            path.push(cur);
+        }
+        Some(path)
+    }
+}
+
+/// Shortest paths between every pair of nodes in O(V³), allowing negative weights.
+/// Fails with one of the cycles of negative total weight if there is any
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::matrix::AdjMatrix, base::Kind, shortest::floyd_warshall::floyd_warshall};
+///
+/// let flights:AdjMatrix<i64> = AdjMatrix::from_edges(Kind::Directed, 4, [
+///     (0, 1, 3), (1, 2, -2), (0, 2, 4), (2, 3, 2), (3, 0, 1)
+/// ]);
+///
+/// let all = floyd_warshall(&flights).unwrap();
+/// assert_eq!(all.distance(0, 3), Some(3));
+/// assert_eq!(all.path(3, 2), Some(vec![3, 0, 1, 2]));
+/// assert_eq!(all.distance(2, 2), Some(0));
+///
+/// let looped:AdjMatrix<i64> = AdjMatrix::from_edges(Kind::Directed, 3, [(0, 1, 1), (1, 2, -3), (2, 1, 1)]);
+/// assert_eq!(floyd_warshall(&looped).unwrap_err().cycle, vec![1, 2]);
+/// ```
+pub fn floyd_warshall<G, C>(graph:&G) -> Result<AllPairs<C>, NegativeCycle>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    let bound:usize = graph.node_bound();
+    let mut all:AllPairs<C> = AllPairs { bound, dist: vec![None; bound * bound], next: vec![None; bound * bound] };
+
+    for node in graph.nodes() {
+        all.dist[node * bound + node] = Some(C::ZERO);
+        all.next[node * bound + node] = Some(node);
+    }
+    for from in graph.nodes() {
+        for (to, &w) in graph.neighbors(from) {
+            let cell:usize = from * bound + to;
+            if all.dist[cell].is_none_or(|cur| w < cur) {
+                all.dist[cell] = Some(w);
+                all.next[cell] = Some(to);
+            }
+        }
+    }
+
+    let nodes:Vec<usize> = graph.nodes().collect();
+    for &mid in nodes.iter() {
+        for &from in nodes.iter() {
+            let Some(first) = all.dist[from * bound + mid] else { continue; };
+
+            for &to in nodes.iter() {
+                let Some(second) = all.dist[mid * bound + to] else { continue; };
+
+                let cell:usize = from * bound + to;
+                if all.dist[cell].is_none_or(|cur| first + second < cur) {
+                    all.dist[cell] = Some(first + second);
+                    all.next[cell] = all.next[from * bound + mid];
+                }
+            }
+        }
+
+        // stop at the first negative cycle before the distances around it blow up
+        if let Some(&node) = nodes.iter().find(|n| all.dist[*n * bound + *n].is_some_and(|d| d < C::ZERO)) {
+            return Err(cycle_through(&all, node));
+        }
+    }
+
+    Ok(all)
+}
+
+/// Follow the next hops towards a node with a negative distance to itself until one repeats.
+/// Every next hop is an edge, so the repeated stretch is a cycle of the graph
+fn cycle_through<C>(all:&AllPairs<C>, node:usize) -> NegativeCycle {
+    // position of every node in the walk
+    let mut seen:Vec<Option<usize>> = vec![None; all.bound];
+    let mut walk:Vec<usize> = Vec::new();
+
+    let mut cur:usize = node;
+    while seen[cur].is_none() {
+        seen[cur] = Some(walk.len());
+        walk.push(cur);
+        cur = all.next[cur * all.bound + node].unwrap();
+    }
+
+    NegativeCycle::new(walk.split_off(seen[cur].unwrap()))
+}
@@ -0,0 +1,88 @@
+use core::fmt;
+use std::error::Error;
+
+use structs::range::ops::Numeric;
+
+/// Distances from one source with the predecessor of every reached node on its shortest path
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortestPaths<C> {
+    source:usize,
+    dist:Vec<Option<C>>,
+    prev:Vec<Option<usize>>,
+}
+
+impl<C> ShortestPaths<C> 
+    where C: Numeric
+{
+    pub(crate) fn new(source:usize, bound:usize) -> Self {
+        let mut paths:Self = Self { source, dist: vec![None; bound], prev: vec![None; bound] };
+        paths.dist[source] = Some(C::ZERO);
+        paths
+    }
+
+    pub(crate) fn relax(&mut self, from:usize, to:usize, dist:C) -> bool {
+        if self.dist[to].is_some_and(|cur| cur <= dist) { return false; }
+
+        self.dist[to] = Some(dist);
+        self.prev[to] = Some(from);
+        true
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Length of the shortest path to `target`, None when it cannot be reached
+    pub fn distance(&self, target:usize) -> Option<C> {
+        self.dist.get(target).copied().flatten()
+    }
+
+    pub fn is_reachable(&self, target:usize) -> bool {
+        self.distance(target).is_some()
+    }
+
+    /// Node before `target` on its shortest path
+    pub fn predecessor(&self, target:usize) -> Option<usize> {
+        self.prev.get(target).copied().flatten()
+    }
+
+    /// Nodes of the shortest path from the source to `target`, both included
+    pub fn path_to(&self, target:usize) -> Option<Vec<usize>> {
+        self.distance(target)?;
+
+        let mut path:Vec<usize> = vec![target];
+        let mut cur:usize = target;
+        while cur != self.source {
+            cur = self.prev[cur]?;
+            path.push(cur);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Cycle whose weights add up below zero, making shortest paths through it undefined.
+/// Nodes are listed in the order of the edges, the last one leads back to the first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub cycle:Vec<usize>,
+}
+
+impl NegativeCycle {
+    // start from the smallest id so the same cycle is always reported the same way
+    pub(crate) fn new(mut cycle:Vec<usize>) -> Self {
+        let min:usize = cycle.iter().enumerate().min_by_key(|(_, n)| **n).map_or(0, |(i, _)| i);
+        cycle.rotate_left(min);
+        Self { cycle }
+    }
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes:Vec<String> = self.cycle.iter().map(usize::to_string).collect();
+        write!(f, "negative cycle through {}", nodes.join(" -> "))
+    }
+}
+
+impl Error for NegativeCycle {}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use structs::{disjoint_set::DisjointSet, heap::IndexedMinHeap, range::ops::Numeric};
+
+use crate::base::Graph;
+
+/// Edges of a minimum spanning forest, one tree per connected component, with their total weight
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanningTree<C> {
+    pub total:C,
+    /// `(from, to, weight)` with `from < to`
+    pub edges:Vec<(usize, usize, C)>,
+}
+
+impl<C> SpanningTree<C> 
+    where C: Numeric
+{
+    fn new() -> Self {
+        Self { total: C::ZERO, edges: Vec::new() }
+    }
+
+    fn add(&mut self, a:usize, b:usize, weight:C) {
+        self.total = self.total + weight;
+        self.edges.push((a.min(b), a.max(b), weight));
+    }
+}
+
+/// Minimum spanning forest with Kruskal's algorithm, O(E log E): edges are taken by increasing
+/// weight, skipping those whose ends a `DisjointSet` already has in the same tree.
+/// Edges come out sorted by weight. Undirected graphs only
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, spanning::{kruskal, prim}};
+///
+/// let cables:AdjList<u32> = AdjList::from_edges(Kind::Undirected, 5, [
+///     (0, 1, 4), (0, 2, 1), (1, 2, 2), (1, 3, 5), (2, 3, 8), (3, 4, 3)
+/// ]);
+///
+/// let tree = kruskal(&cables);
+/// assert_eq!(tree.total, 11);
+/// assert_eq!(tree.edges, vec![(0, 2, 1), (1, 2, 2), (3, 4, 3), (1, 3, 5)]);
+///
+/// assert_eq!(prim(&cables).total, 11);
+/// ```
+pub fn kruskal<G, C>(graph:&G) -> SpanningTree<C>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    assert!(!graph.is_directed(), "spanning trees need an undirected graph");
+
+    let mut edges:Vec<(usize, usize, C)> = graph.edges()
+        .filter(|(from, to, _)| from != to)
+        .map(|(from, to, w)| (from, to, *w))
+        .collect();
+    edges.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+
+    let mut set:DisjointSet = DisjointSet::new(graph.node_bound());
+    let mut tree:SpanningTree<C> = SpanningTree::new();
+    for (from, to, w) in edges {
+        if set.union(from, to) {
+            tree.add(from, to, w);
+        }
+    }
+
+    tree
+}
+
+/// Minimum spanning forest with Prim's algorithm, O(E log V): every tree grows from its
+/// smallest node through the lightest edge leaving it, found with the decrease-key
+/// `IndexedMinHeap`. Edges come out in the order they join. Undirected graphs only
+pub fn prim<G, C>(graph:&G) -> SpanningTree<C>
+    where G: Graph<Weight = C>, C: Numeric
+{
+    assert!(!graph.is_directed(), "spanning trees need an undirected graph");
+
+    let mut done:Vec<bool> = vec![false; graph.node_bound()];
+    // lightest known edge linking every waiting node to the tree
+    let mut link:Vec<Option<usize>> = vec![None; graph.node_bound()];
+    let mut heap:IndexedMinHeap<C> = IndexedMinHeap::new(graph.node_bound());
+    let mut tree:SpanningTree<C> = SpanningTree::new();
+
+    for root in graph.nodes() {
+        if done[root] { continue; }
+        heap.push(root, C::ZERO);
+
+        while let Some((node, w)) = heap.pop() {
+            done[node] = true;
+            if let Some(from) = link[node] {
+                tree.add(from, node, w);
+            }
+
+            for (next, &w) in graph.neighbors(node) {
+                if done[next] { continue; }
+
+                if heap.push(next, w) {
+                    link[next] = Some(node);
+                }
+            }
+        }
+    }
+
+    tree
+}
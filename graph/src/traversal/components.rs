@@ -0,0 +1,224 @@
+use structs::{disjoint_set::DisjointSet, list::list::{BaseOperations, List}};
+
+use crate::{base::Graph, traversal::search::{dfs_visit, Event}};
+
+/// Groups of nodes linked by paths ignoring edge directions, ordered by their smallest node
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::components::connected_components};
+///
+/// let g:AdjList = AdjList::from_edges(Kind::Directed, 5, [(3, 0, ()), (4, 2, ())]);
+/// assert_eq!(connected_components(&g), vec![vec![0, 3], vec![1], vec![2, 4]]);
+/// ```
+pub fn connected_components<G>(graph:&G) -> Vec<Vec<usize>>
+    where G: Graph
+{
+    let mut set:DisjointSet = DisjointSet::new(graph.node_bound());
+    for (from, to, _) in graph.edges() {
+        set.union(from, to);
+    }
+
+    set.components()
+        .into_iter()
+        .filter(|comp| graph.contains_node(comp[0]))
+        .collect()
+}
+
+/// Strongly connected components with Tarjan's algorithm in one depth first search, O(V + E).
+/// Components come in reverse topological order, every one before the ones reaching it,
+/// each sorted. For undirected graphs these are the connected components
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::components::{kosaraju_scc, tarjan_scc}};
+///
+/// let g:AdjList = AdjList::from_edges(Kind::Directed, 6, [
+///     (0, 1, ()), (1, 2, ()), (2, 0, ()), (2, 3, ()), (3, 4, ()), (4, 3, ()), (5, 4, ())
+/// ]);
+///
+/// assert_eq!(tarjan_scc(&g), vec![vec![3, 4], vec![0, 1, 2], vec![5]]);
+/// assert_eq!(kosaraju_scc(&g), vec![vec![5], vec![0, 1, 2], vec![3, 4]]);
+/// ```
+pub fn tarjan_scc<G>(graph:&G) -> Vec<Vec<usize>>
+    where G: Graph
+{
+    if !graph.is_directed() { return connected_components(graph); }
+
+    let bound:usize = graph.node_bound();
+    let mut index:Vec<usize> = vec![0; bound];
+    let mut low:Vec<usize> = vec![0; bound];
+    let mut parent:Vec<Option<usize>> = vec![None; bound];
+    let mut on_stack:Vec<bool> = vec![false; bound];
+    let mut stack:Vec<usize> = Vec::new();
+    let mut counter:usize = 0;
+    let mut res:Vec<Vec<usize>> = Vec::new();
+
+    dfs_visit(graph, graph.nodes(), |event| match event {
+        Event::Discover(node) => {
+            index[node] = counter;
+            low[node] = counter;
+            counter += 1;
+            stack.push(node);
+            on_stack[node] = true;
+        },
+        Event::TreeEdge(from, to) => parent[to] = Some(from),
+        Event::BackEdge(from, to) | Event::NonTreeEdge(from, to) if on_stack[to] => {
+            low[from] = low[from].min(index[to]);
+        },
+        Event::Finish(node) => {
+            if low[node] == index[node] {
+                let mut comp:Vec<usize> = Vec::new();
+                loop {
+                    let top:usize = stack.pop().unwrap();
+                    on_stack[top] = false;
+                    comp.push(top);
+                    if top == node { break; }
+                }
+                comp.sort();
+                res.push(comp);
+            }
+            if let Some(p) = parent[node] {
+                low[p] = low[p].min(low[node]);
+            }
+        },
+        _ => {}
+    });
+
+    res
+}
+
+/// Strongly connected components with Kosaraju's algorithm, a search over the graph then one
+/// over its reverse, O(V + E). Components come in topological order, every one before the
+/// ones it reaches, each sorted. For undirected graphs these are the connected components
+pub fn kosaraju_scc<G>(graph:&G) -> Vec<Vec<usize>>
+    where G: Graph
+{
+    if !graph.is_directed() { return connected_components(graph); }
+
+    let mut finished:Vec<usize> = Vec::with_capacity(graph.node_count());
+    dfs_visit(graph, graph.nodes(), |event| {
+        if let Event::Finish(node) = event {
+            finished.push(node);
+        }
+    });
+
+    let mut rev:Vec<Vec<usize>> = vec![Vec::new(); graph.node_bound()];
+    for (from, to, _) in graph.edges() {
+        rev[to].push(from);
+    }
+
+    // in the reverse graph, the last node to finish can only reach its own component
+    let mut seen:Vec<bool> = vec![false; graph.node_bound()];
+    let mut res:Vec<Vec<usize>> = Vec::new();
+    for &root in finished.iter().rev() {
+        if seen[root] { continue; }
+        seen[root] = true;
+
+        let mut comp:Vec<usize> = Vec::new();
+        let mut stack:List<usize> = List::empty();
+        stack.push(root);
+
+        while let Some(node) = stack.pop() {
+            comp.push(node);
+            for &from in rev[node].iter() {
+                if !seen[from] {
+                    seen[from] = true;
+                    stack.push(from);
+                }
+            }
+        }
+
+        comp.sort();
+        res.push(comp);
+    }
+
+    res
+}
+
+// discovery time and lowest reachable discovery time of every node, with the tree parents,
+// shared by the bridge and articulation point searches
+fn low_links<G, F>(graph:&G, mut on_finish:F)
+    where G: Graph, F: FnMut(usize, Option<usize>, &[usize], &[usize])
+{
+    assert!(!graph.is_directed(), "bridges and articulation points need an undirected graph");
+
+    let bound:usize = graph.node_bound();
+    let mut tin:Vec<usize> = vec![0; bound];
+    let mut low:Vec<usize> = vec![0; bound];
+    let mut parent:Vec<Option<usize>> = vec![None; bound];
+    let mut counter:usize = 0;
+
+    dfs_visit(graph, graph.nodes(), |event| match event {
+        Event::Discover(node) => {
+            tin[node] = counter;
+            low[node] = counter;
+            counter += 1;
+        },
+        Event::TreeEdge(from, to) => parent[to] = Some(from),
+        Event::BackEdge(from, to) => low[from] = low[from].min(tin[to]),
+        Event::Finish(node) => {
+            if let Some(p) = parent[node] {
+                low[p] = low[p].min(low[node]);
+            }
+            on_finish(node, parent[node], &tin, &low);
+        },
+        _ => {}
+    });
+}
+
+/// Edges whose removal disconnects their ends, sorted with `from < to`. Undirected graphs only
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::components::{articulation_points, bridges}};
+///
+/// // two triangles joined by the edge 2 - 3, with 5 hanging off 4
+/// let g:AdjList = AdjList::from_edges(Kind::Undirected, 7, [
+///     (0, 1, ()), (1, 2, ()), (2, 0, ()), (2, 3, ()), (3, 4, ()), (4, 6, ()), (6, 3, ()), (4, 5, ())
+/// ]);
+///
+/// assert_eq!(bridges(&g), vec![(2, 3), (4, 5)]);
+/// assert_eq!(articulation_points(&g), vec![2, 3, 4]);
+/// ```
+pub fn bridges<G>(graph:&G) -> Vec<(usize, usize)>
+    where G: Graph
+{
+    let mut res:Vec<(usize, usize)> = Vec::new();
+
+    low_links(graph, |node, parent, tin, low| {
+        if let Some(p) = parent && low[node] > tin[p] {
+            res.push((p.min(node), p.max(node)));
+        }
+    });
+
+    res.sort();
+    res
+}
+
+/// Nodes whose removal disconnects the rest of their component, sorted. Undirected graphs only
+pub fn articulation_points<G>(graph:&G) -> Vec<usize>
+    where G: Graph
+{
+    let mut cut:Vec<bool> = vec![false; graph.node_bound()];
+    let mut root_children:Vec<usize> = vec![0; graph.node_bound()];
+    let mut roots:Vec<usize> = Vec::new();
+
+    low_links(graph, |node, parent, tin, low| match parent {
+        None => roots.push(node),
+        Some(p) => {
+            root_children[p] += 1;
+            // no path from below `node` climbs above `p`, which the root handles apart
+            if low[node] >= tin[p] {
+                cut[p] = true;
+            }
+        }
+    });
+
+    // a root only cuts when it has at least two subtrees
+    for root in roots {
+        cut[root] = root_children[root] > 1;
+    }
+
+    (0..graph.node_bound()).filter(|n| cut[*n]).collect()
+}
@@ -0,0 +1,311 @@
+use structs::list::list::{BaseOperations, List};
+
+use crate::base::Graph;
+
+/// What a traversal reports to its visitor, in the order it happens
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// First time the node is reached
+    Discover(usize),
+    /// Edge through which a node got discovered
+    TreeEdge(usize, usize),
+    /// Edge to a node whose search is still open, every one of them closes a cycle (DFS only)
+    BackEdge(usize, usize),
+    /// Any other edge to an already discovered node: forward and cross edges in DFS
+    NonTreeEdge(usize, usize),
+    /// Every edge leaving the node has been looked at
+    Finish(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    New,
+    Open,
+    Done,
+}
+
+/// Breadth first iterator over the nodes reachable from its sources, with the queue kept
+/// in a `structs` list. Nodes come out by increasing number of hops from the closest source
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::search::Bfs};
+///
+/// let g:AdjList = AdjList::from_edges(Kind::Directed, 6, [
+///     (0, 1, ()), (0, 2, ()), (1, 3, ()), (2, 3, ()), (3, 4, ())
+/// ]);
+///
+/// let mut bfs = Bfs::new(&g, 0);
+/// assert_eq!(bfs.by_ref().collect::<Vec<usize>>(), vec![0, 1, 2, 3, 4]);
+/// assert_eq!(bfs.depth(4), Some(3));
+/// assert_eq!(bfs.parent(3), Some(1));
+/// assert_eq!(bfs.depth(5), None);
+/// ```
+pub struct Bfs<'a, G> {
+    graph:&'a G,
+    queue:List<usize>,
+    depth:Vec<Option<usize>>,
+    parent:Vec<Option<usize>>,
+}
+
+impl<'a, G> Bfs<'a, G> 
+    where G: Graph
+{
+    pub fn new(graph:&'a G, start:usize) -> Self {
+        Self::with_sources(graph, [start])
+    }
+
+    /// Search from all the sources at once, each of them at depth 0
+    pub fn with_sources<I>(graph:&'a G, sources:I) -> Self
+        where I: IntoIterator<Item = usize>
+    {
+        let mut bfs:Self = Self {
+            graph,
+            queue: List::empty(),
+            depth: vec![None; graph.node_bound()],
+            parent: vec![None; graph.node_bound()]
+        };
+
+        for source in sources {
+            assert!(graph.contains_node(source), "node {} is not in the graph", source);
+            if bfs.depth[source].is_some() { continue; }
+
+            bfs.depth[source] = Some(0);
+            bfs.queue.append(source);
+        }
+        bfs
+    }
+
+    /// Hops from the closest source, for the nodes discovered so far
+    pub fn depth(&self, node:usize) -> Option<usize> {
+        self.depth.get(node).copied().flatten()
+    }
+
+    /// Node the search came from when discovering `node`
+    pub fn parent(&self, node:usize) -> Option<usize> {
+        self.parent.get(node).copied().flatten()
+    }
+}
+
+impl<G> Iterator for Bfs<'_, G> 
+    where G: Graph
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let node:usize = self.queue.pop()?;
+        let depth:usize = self.depth[node].unwrap();
+
+        for (to, _) in self.graph.neighbors(node) {
+            if self.depth[to].is_some() { continue; }
+
+            self.depth[to] = Some(depth + 1);
+            self.parent[to] = Some(node);
+            self.queue.append(to);
+        }
+
+        Some(node)
+    }
+}
+
+/// Depth first iterator yielding the nodes reachable from its sources in preorder, with the
+/// stack kept in a `structs` list. Neighbors are explored in the order the graph lists them
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::search::Dfs};
+///
+/// let g:AdjList = AdjList::from_edges(Kind::Undirected, 6, [
+///     (0, 1, ()), (0, 2, ()), (1, 3, ()), (2, 3, ()), (4, 5, ())
+/// ]);
+///
+/// assert_eq!(Dfs::new(&g, 0).collect::<Vec<usize>>(), vec![0, 1, 3, 2]);
+/// assert_eq!(Dfs::with_sources(&g, [5, 0]).collect::<Vec<usize>>(), vec![5, 4, 0, 1, 3, 2]);
+/// ```
+pub struct Dfs<'a, G> {
+    graph:&'a G,
+    stack:List<usize>,
+    seen:Vec<bool>,
+}
+
+impl<'a, G> Dfs<'a, G> 
+    where G: Graph
+{
+    pub fn new(graph:&'a G, start:usize) -> Self {
+        Self::with_sources(graph, [start])
+    }
+
+    /// Search from every source in turn, skipping the ones already reached
+    pub fn with_sources<I>(graph:&'a G, sources:I) -> Self
+        where I: IntoIterator<Item = usize>
+    {
+        let sources:Vec<usize> = sources.into_iter().collect();
+        let mut stack:List<usize> = List::empty();
+
+        for &source in sources.iter().rev() {
+            assert!(graph.contains_node(source), "node {} is not in the graph", source);
+            stack.push(source);
+        }
+
+        Self { graph, stack, seen: vec![false; graph.node_bound()] }
+    }
+
+    /// Whether the node has been yielded already
+    pub fn is_visited(&self, node:usize) -> bool {
+        self.seen.get(node).copied().unwrap_or(false)
+    }
+}
+
+impl<G> Iterator for Dfs<'_, G> 
+    where G: Graph
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let node:usize = self.stack.pop()?;
+            if self.seen[node] { continue; }
+            self.seen[node] = true;
+
+            let next:Vec<usize> = self.graph.neighbors(node)
+                .map(|(to, _)| to)
+                .filter(|to| !self.seen[*to])
+                .collect();
+            // pushed backwards so the first neighbor is on top
+            for &to in next.iter().rev() {
+                self.stack.push(to);
+            }
+
+            return Some(node);
+        }
+    }
+}
+
+/// Breadth first search from all the sources at once, reporting every step to `visit`.
+/// In undirected graphs every edge is reported once
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::search::{bfs_visit, Event}};
+///
+/// let g:AdjList = AdjList::from_edges(Kind::Undirected, 3, [(0, 1, ()), (0, 2, ()), (1, 2, ())]);
+///
+/// let mut events:Vec<Event> = Vec::new();
+/// bfs_visit(&g, [0], |event| events.push(event));
+///
+/// assert_eq!(events, vec![
+///     Event::Discover(0),
+///     Event::TreeEdge(0, 1), Event::Discover(1),
+///     Event::TreeEdge(0, 2), Event::Discover(2),
+///     Event::Finish(0),
+///     Event::NonTreeEdge(1, 2),
+///     Event::Finish(1),
+///     Event::Finish(2)
+/// ]);
+/// ```
+pub fn bfs_visit<G, I, F>(graph:&G, sources:I, mut visit:F)
+    where G: Graph, I: IntoIterator<Item = usize>, F: FnMut(Event)
+{
+    let undirected:bool = !graph.is_directed();
+    let mut state:Vec<State> = vec![State::New; graph.node_bound()];
+    let mut queue:List<usize> = List::empty();
+
+    for source in sources {
+        assert!(graph.contains_node(source), "node {} is not in the graph", source);
+        if state[source] != State::New { continue; }
+
+        state[source] = State::Open;
+        visit(Event::Discover(source));
+        queue.append(source);
+    }
+
+    while let Some(node) = queue.pop() {
+        for (to, _) in graph.neighbors(node) {
+            match state[to] {
+                State::New => {
+                    state[to] = State::Open;
+                    visit(Event::TreeEdge(node, to));
+                    visit(Event::Discover(to));
+                    queue.append(to);
+                },
+                // already reported from the other end
+                State::Done if undirected => {},
+                _ => visit(Event::NonTreeEdge(node, to))
+            }
+        }
+
+        state[node] = State::Done;
+        visit(Event::Finish(node));
+    }
+}
+
+/// Depth first search from every source in turn, reporting every step to `visit`.
+/// In undirected graphs every edge is reported once, the edge back to the parent never
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::search::{dfs_visit, Event}};
+///
+/// let g:AdjList = AdjList::from_edges(Kind::Directed, 3, [(0, 1, ()), (1, 2, ()), (2, 0, ()), (0, 2, ())]);
+///
+/// let mut events:Vec<Event> = Vec::new();
+/// dfs_visit(&g, [0], |event| events.push(event));
+///
+/// assert_eq!(events, vec![
+///     Event::Discover(0),
+///     Event::TreeEdge(0, 1), Event::Discover(1),
+///     Event::TreeEdge(1, 2), Event::Discover(2),
+///     Event::BackEdge(2, 0),
+///     Event::Finish(2),
+///     Event::Finish(1),
+///     Event::NonTreeEdge(0, 2),
+///     Event::Finish(0)
+/// ]);
+/// ```
+pub fn dfs_visit<G, I, F>(graph:&G, sources:I, mut visit:F)
+    where G: Graph, I: IntoIterator<Item = usize>, F: FnMut(Event)
+{
+    let undirected:bool = !graph.is_directed();
+    let mut state:Vec<State> = vec![State::New; graph.node_bound()];
+    let mut parent:Vec<Option<usize>> = vec![None; graph.node_bound()];
+    // neighbors of the open nodes, collected when they are discovered
+    let mut adj:Vec<Vec<usize>> = vec![Vec::new(); graph.node_bound()];
+    // open nodes with the index of the next neighbor to look at
+    let mut stack:List<(usize, usize)> = List::empty();
+
+    for source in sources {
+        assert!(graph.contains_node(source), "node {} is not in the graph", source);
+        if state[source] != State::New { continue; }
+
+        state[source] = State::Open;
+        visit(Event::Discover(source));
+        adj[source] = graph.neighbors(source).map(|(to, _)| to).collect();
+        stack.push((source, 0));
+
+        while let Some((node, i)) = stack.pop() {
+            let Some(&to) = adj[node].get(i) else {
+                state[node] = State::Done;
+                adj[node] = Vec::new();
+                visit(Event::Finish(node));
+                continue;
+            };
+            stack.push((node, i + 1));
+
+            match state[to] {
+                State::New => {
+                    state[to] = State::Open;
+                    parent[to] = Some(node);
+                    visit(Event::TreeEdge(node, to));
+                    visit(Event::Discover(to));
+                    adj[to] = graph.neighbors(to).map(|(next, _)| next).collect();
+                    stack.push((to, 0));
+                },
+                // edges are unique, so the one to the parent is the tree edge itself
+                State::Open if undirected && parent[node] == Some(to) => {},
+                State::Open => visit(Event::BackEdge(node, to)),
+                State::Done if undirected => {},
+                State::Done => visit(Event::NonTreeEdge(node, to))
+            }
+        }
+    }
+}
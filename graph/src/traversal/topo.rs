@@ -0,0 +1,76 @@
+use core::fmt;
+use std::error::Error;
+
+use crate::{base::Graph, traversal::search::{dfs_visit, Event}};
+
+/// Directed cycle preventing a topological order. Nodes are listed in the order of the
+/// edges, the last one leads back to the first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub nodes:Vec<usize>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes:Vec<String> = self.nodes.iter().map(usize::to_string).collect();
+        write!(f, "cycle through {}", nodes.join(" -> "))
+    }
+}
+
+impl Error for Cycle {}
+
+/// Order the nodes of a directed graph so that every edge goes forward, dependencies
+/// of a build after the targets depending on them. Fails with one of the cycles
+///
+/// #Example
+/// ```
+/// use graph::{adjacency::list::AdjList, base::Kind, traversal::topo::toposort};
+///
+/// // edges from a crate to its dependencies
+/// let mut deps:AdjList = AdjList::from_edges(Kind::Directed, 4, [
+///     (0, 1, ()), (0, 2, ()), (2, 1, ()), (1, 3, ())
+/// ]);
+/// assert_eq!(toposort(&deps), Ok(vec![0, 2, 1, 3]));
+///
+/// deps.add_edge(3, 2, ());
+/// let cycle = toposort(&deps).unwrap_err();
+/// assert_eq!(cycle.nodes, vec![1, 3, 2]);
+/// assert_eq!(cycle.to_string(), "cycle through 1 -> 3 -> 2");
+/// ```
+pub fn toposort<G>(graph:&G) -> Result<Vec<usize>, Cycle>
+    where G: Graph
+{
+    assert!(graph.is_directed(), "only directed graphs have a topological order");
+
+    let mut order:Vec<usize> = Vec::with_capacity(graph.node_count());
+    let mut parent:Vec<Option<usize>> = vec![None; graph.node_bound()];
+    let mut cycle:Option<Cycle> = None;
+
+    dfs_visit(graph, graph.nodes(), |event| match event {
+        Event::TreeEdge(from, to) => parent[to] = Some(from),
+        Event::BackEdge(from, to) if cycle.is_none() => {
+            // `to` is an ancestor of `from`, climb the tree back up to it
+            let mut nodes:Vec<usize> = vec![from];
+            let mut cur:usize = from;
+            while cur != to {
+                cur = parent[cur].unwrap();
+                nodes.push(cur);
+            }
+            nodes.reverse();
+
+            let min:usize = nodes.iter().enumerate().min_by_key(|(_, n)| **n).unwrap().0;
+            nodes.rotate_left(min);
+            cycle = Some(Cycle { nodes });
+        },
+        Event::Finish(node) => order.push(node),
+        _ => {}
+    });
+
+    match cycle {
+        Some(cycle) => Err(cycle),
+        None => {
+            order.reverse();
+            Ok(order)
+        }
+    }
+}
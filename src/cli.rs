@@ -0,0 +1,67 @@
+use std::{error::Error, path::PathBuf};
+
+use advent::{registry::Registry, runner::{self, Report, Verdict}};
+use colored::Colorize;
+
+const USAGE:&str = "usage: main advent <year> <day> [1|2] [--input FILE] [--answers FILE] [--inputs DIR]
+       main advent list";
+
+/// `main advent ...`: run a day of Advent of Code on a local input, by default `<year>/dayDD.txt`
+/// in the directory of `--inputs`, `$ADVENT_INPUTS` or `./inputs`, checking the answers stored next to it
+pub fn advent(args:&[String]) -> Result<(), Box<dyn Error>> {
+    let registry:Registry = Registry::builtin();
+
+    if args.first().map(String::as_str) == Some("list") {
+        for (year, day) in registry.days() {
+            println!("{} day {:02}", year, day);
+        }
+        return Ok(());
+    }
+
+    let mut positional:Vec<&str> = Vec::new();
+    let (mut input, mut answers, mut dir) = (None, None, None);
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().map(PathBuf::from).ok_or_else(|| format!("{} needs a value\n{}", arg, USAGE));
+        match arg.as_str() {
+            "--input" => input = Some(value()?),
+            "--answers" => answers = Some(value()?),
+            "--inputs" => dir = Some(value()?),
+            _ => positional.push(arg)
+        }
+    }
+
+    let (year, day, parts):(u16, u8, Vec<u8>) = match positional[..] {
+        [year, day] => (year.parse()?, day.parse()?, vec![1, 2]),
+        [year, day, part] => (year.parse()?, day.parse()?, vec![part.parse()?]),
+        _ => return Err(USAGE.into())
+    };
+
+    let dir:PathBuf = dir.unwrap_or_else(runner::inputs_dir);
+    let input:PathBuf = input.unwrap_or_else(|| runner::input_path(&dir, year, day));
+    let answers:PathBuf = answers.unwrap_or_else(|| runner::answers_path(&dir, year, day));
+
+    let report:Report = runner::run_day(&registry, year, day, &parts, &input, &answers)?;
+    print(&report);
+
+    if report.has_wrong() {
+        return Err(format!("wrong answer for {} day {}", year, day).into());
+    }
+    Ok(())
+}
+
+fn print(report:&Report) {
+    println!("{}", format!("{} day {:02}", report.year, report.day).bold());
+    println!("  parse   {}", format!("({:?})", report.parse_time).dimmed());
+
+    for run in report.parts.iter() {
+        let verdict:Verdict = run.verdict();
+        let verdict = match verdict {
+            Verdict::Ok => verdict.to_string().green(),
+            Verdict::Wrong { .. } => verdict.to_string().red(),
+            Verdict::Unverified => verdict.to_string().yellow()
+        };
+        println!("  part {}  {}  {}  {}", run.part, run.answer.bold(), format!("({:?})", run.time).dimmed(), verdict);
+    }
+}
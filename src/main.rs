@@ -1,14 +1,23 @@
-use std::time::Instant;
+use std::{collections::HashMap, time::{Duration, Instant}};
 
-use structs::list::list::FindSmallest as ListFindSmallest;
-use structs::heap::FindSmallest as HeapFindSmallest; 
+use structs::hash::{open::OpenHashMap, chained::ChainedHashMap};
+use trees::{avl::map::AvlMap, btree::{self, BTree}, bplus::{self, BPlusTree}, kdtree::KdTree};
+use graph::{adjacency::list::AdjList, base::{Graph, Kind}, shortest::dijkstra::{a_star, dijkstra}, spanning::prim, traversal::{search::Bfs, topo::toposort}};
+use algos::{select::top_k::{selectors, SelectK}, sort::harness::{check, sorters}};
+use num_complex::Complex;
 
 use num_gen::Generator;
 
+mod cli;
 mod num_gen;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+
+    let args:Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("advent") {
+        return cli::advent(&args[1..]);
+    }
     
     let now:Instant = Instant::now();
 
@@ -26,30 +35,200 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         batches.push(num_gen.generate_batch(quantity, (0, 50_000)));
     }
 
-    println!("### Heap Benchmarks ###");
+    println!("### Top-k Selection Benchmarks ###");
 
+    let selectors:Vec<Box<dyn SelectK<usize>>> = selectors();
     for batch in batches.iter() {
         for percent in percents.iter() {
-            let t_now:Instant = Instant::now();
-    
             let total:usize = ((percent / 100.0) * batch.len() as f32) as usize;
-            let _ = HeapFindSmallest::find_smallest(batch, total);
-            
-            println!("[Q {} | P {}] => {:?}", batch.len(), percent, t_now.elapsed());
+
+            for selector in selectors.iter() {
+                let t_now:Instant = Instant::now();
+                let _ = selector.select_k(batch, total);
+
+                println!("[Q {} | P {} | {}] => {:?}", batch.len(), percent, selector.name(), t_now.elapsed());
+            }
         }
         println!();
     }
 
-    println!("### List Benchmarks ###");
+    println!("### Hash Map Benchmarks ###");
 
     for batch in batches.iter() {
-        for percent in percents.iter() {
-            let t_now:Instant = Instant::now();
-    
-            let total:usize = ((percent / 100.0) * batch.len() as f32) as usize;
-            let _ = ListFindSmallest::find_smallest(batch, total);
-            
-            println!("[Q {} | P {}] => {:?}", batch.len(), percent, t_now.elapsed());
+        let t_now:Instant = Instant::now();
+
+        let mut std_map:HashMap<usize, usize> = HashMap::new();
+        for num in batch {
+            *std_map.entry(*num).or_insert(0) += 1;
+        }
+        println!("[Q {} | std] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+
+        let mut open_map:OpenHashMap<usize, usize> = OpenHashMap::new();
+        for num in batch {
+            *open_map.get_or_insert_with(*num, || 0) += 1;
+        }
+        println!("[Q {} | open] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+
+        let mut chained_map:ChainedHashMap<usize, usize> = ChainedHashMap::new();
+        for num in batch {
+            if !chained_map.update(num, |count| *count += 1) {
+                chained_map.insert(*num, 1);
+            }
+        }
+        println!("[Q {} | chained] => {:?}", batch.len(), t_now.elapsed());
+
+        println!();
+    }
+
+    println!("### Tree Benchmarks ###");
+
+    for batch in batches.iter() {
+        let t_now:Instant = Instant::now();
+
+        let mut avl:AvlMap<usize, usize> = AvlMap::new();
+        for num in batch {
+            let count:usize = avl.get(num).copied().unwrap_or(0);
+            avl.insert(*num, count + 1);
+        }
+        println!("[Q {} | avl insert] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+
+        let mut btree:BTree<usize, usize> = BTree::new();
+        for num in batch {
+            let count:usize = btree.get(num).copied().unwrap_or(0);
+            btree.insert(*num, count + 1);
+        }
+        println!("[Q {} | btree insert] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+
+        let mut bplus:BPlusTree<usize, usize> = BPlusTree::new();
+        for num in batch {
+            let count:usize = bplus.get(num).copied().unwrap_or(0);
+            bplus.insert(*num, count + 1);
+        }
+        println!("[Q {} | bplus insert] => {:?}", batch.len(), t_now.elapsed());
+
+        let mut sorted:Vec<usize> = batch.clone();
+        sorted.sort();
+
+        let t_now:Instant = Instant::now();
+        let _ = BTree::from_sorted(btree::DEFAULT_ORDER, sorted.iter().map(|num| (*num, ())));
+        println!("[Q {} | btree bulk load] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = BPlusTree::from_sorted(bplus::DEFAULT_ORDER, sorted.iter().map(|num| (*num, ())));
+        println!("[Q {} | bplus bulk load] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = avl.range(10_000..40_000).map(|(_, count)| count).sum::<usize>();
+        println!("[Q {} | avl range scan] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = btree.range(10_000..40_000).map(|(_, count)| count).sum::<usize>();
+        println!("[Q {} | btree range scan] => {:?}", batch.len(), t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = bplus.range(10_000..40_000).map(|(_, count)| count).sum::<usize>();
+        println!("[Q {} | bplus range scan] => {:?}", batch.len(), t_now.elapsed());
+
+        println!();
+    }
+
+    println!("### Nearest Neighbour Benchmarks ###");
+
+    for quantity in [1_000, 10_000, 100_000] {
+        let coords:Vec<f64> = num_gen.generate_batch(2 * quantity, (-1_000.0, 1_000.0));
+        let points:Vec<Complex<f64>> = coords.chunks(2).map(|c| Complex::new(c[0], c[1])).collect();
+        let queries:Vec<Complex<f64>> = points.iter().take(100).map(|p| p + Complex::new(0.5, -0.5)).collect();
+
+        let t_now:Instant = Instant::now();
+        for query in queries.iter() {
+            let _ = points.iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (*a - query).norm().total_cmp(&(*b - query).norm()));
+        }
+        println!("[Q {} | brute force nearest] => {:?}", quantity, t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let kd:KdTree<2> = points.iter().map(|p| [p.re, p.im]).collect();
+        println!("[Q {} | kd build] => {:?}", quantity, t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        for query in queries.iter() {
+            let _ = kd.nearest(&[query.re, query.im]);
+        }
+        println!("[Q {} | kd nearest] => {:?}", quantity, t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        for query in queries.iter() {
+            let _ = kd.k_nearest(&[query.re, query.im], 10);
+        }
+        println!("[Q {} | kd 10 nearest] => {:?}", quantity, t_now.elapsed());
+
+        println!();
+    }
+
+    println!("### Graph Benchmarks ###");
+
+    for nodes in [1_000, 10_000, 100_000] {
+        let t_now:Instant = Instant::now();
+        let ba:AdjList<u32> = num_gen.barabasi_albert(nodes, 4, (1, 100));
+        println!("[N {} | barabasi-albert generation] => {:?}", nodes, t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = dijkstra(&ba, 0);
+        println!("[N {} | barabasi-albert dijkstra] => {:?}", nodes, t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = prim(&ba).total;
+        println!("[N {} | barabasi-albert prim] => {:?}", nodes, t_now.elapsed());
+
+        let side:usize = (nodes as f64).sqrt() as usize;
+        let maze:AdjList<u32> = num_gen.maze(side, side, 0.05, (1, 1));
+        let goal:usize = side * side - 1;
+
+        let t_now:Instant = Instant::now();
+        let _ = Bfs::new(&maze, 0).count();
+        println!("[N {} | maze bfs] => {:?}", nodes, t_now.elapsed());
+
+        let t_now:Instant = Instant::now();
+        let _ = a_star(&maze, 0, goal, |node| ((side - 1 - node / side) + (side - 1 - node % side)) as u32);
+        println!("[N {} | maze a*] => {:?}", nodes, t_now.elapsed());
+
+        // the dense generators look at every pair of nodes
+        if nodes > 10_000 {
+            println!();
+            continue;
+        }
+
+        let er:AdjList<u32> = num_gen.erdos_renyi(nodes, 8.0 / nodes as f64, Kind::Directed, (1, 100));
+        let t_now:Instant = Instant::now();
+        let _ = dijkstra(&er, 0);
+        println!("[N {} | erdos-renyi dijkstra, {} edges] => {:?}", nodes, er.edge_count(), t_now.elapsed());
+
+        let dag:AdjList<u32> = num_gen.dag(nodes, 8.0 / nodes as f64, (1, 100));
+        let t_now:Instant = Instant::now();
+        let _ = toposort(&dag);
+        println!("[N {} | dag toposort, {} edges] => {:?}", nodes, dag.edge_count(), t_now.elapsed());
+
+        println!();
+    }
+
+    println!("### Sort Benchmarks ###");
+
+    for batch in batches.iter() {
+        for sorter in sorters::<usize>() {
+            if sorter.quadratic && batch.len() > 10_000 { continue; }
+            if !sorter.handles(batch) { continue; }
+
+            let elapsed:Duration = check(&sorter, batch)?;
+            println!("[Q {} | {} sort] => {:?}", batch.len(), sorter.name, elapsed);
         }
         println!();
     }
@@ -60,3 +239,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use algos::sort::{harness::{check, sorters}, radix::RadixKey};
+
+    use crate::num_gen::Generator;
+
+    fn check_all<K>(batches:&[Vec<K>])
+        where K: Ord + Copy + Debug + RadixKey
+    {
+        for batch in batches.iter() {
+            for sorter in sorters::<K>() {
+                if let Err(err) = check(&sorter, batch) {
+                    panic!("{} on {} values", err, batch.len());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sorters_pass_the_harness_on_generated_batches() {
+        let mut num_gen:Generator = Generator::new(Some(42));
+
+        check_all::<u64>(&[
+            Vec::new(),
+            num_gen.generate_batch(1, (0, 10)),
+            num_gen.generate_batch(2_000, (0, 50_000)),
+            // duplicate heavy
+            num_gen.generate_batch(2_000, (0, 3)),
+            // too wide to count
+            num_gen.generate_batch(2_000, (0, u64::MAX)),
+            vec![0, 1 << 40, 3],
+        ]);
+
+        check_all::<i64>(&[
+            num_gen.generate_batch(2_000, (-1_000, 1_000)),
+            num_gen.generate_batch(2_000, (i64::MIN, i64::MAX)),
+        ]);
+    }
+}
@@ -2,11 +2,13 @@
 
 use std::{fs::{File, OpenOptions}, io::Write};
 
-use rand::{distributions::uniform::SampleUniform, rngs::{StdRng, ThreadRng}, Rng, SeedableRng};
+use graph::{adjacency::list::AdjList, base::Kind};
+use rand::{distributions::uniform::SampleUniform, rngs::{StdRng, ThreadRng}, seq::SliceRandom, Rng, RngCore, SeedableRng};
+use structs::disjoint_set::DisjointSet;
 
 pub enum RngType {
     Rng(ThreadRng),
-    SRng(StdRng)
+    SRng(Box<StdRng>)
 }
 
 pub struct Generator {
@@ -20,7 +22,7 @@ impl Generator {
             let srng = StdRng::seed_from_u64(s);
             return Self { 
                 seed,
-                rng: RngType::SRng(srng)
+                rng: RngType::SRng(Box::new(srng))
             };
         }
 
@@ -60,7 +62,6 @@ impl Generator {
         let _tfile:File = File::create(path).expect("File could not be truncated");
 
         let mut file:File = OpenOptions::new()
-            .write(true)
             .append(true)
             .create(true)
             .open(path)
@@ -70,7 +71,7 @@ impl Generator {
             RngType::Rng( rng ) => {
                 for i in 0..quantity {
                     if i % 100 == 0 {
-                        let _ = file.write_all( format!("\n").as_bytes() );   
+                        let _ = file.write_all( b"\n" );   
                     } 
                     let _ = file.write_all( format!("{:?} ", rng.gen_range(range.0..=range.1) ).as_bytes() );
                 }
@@ -78,7 +79,7 @@ impl Generator {
             RngType::SRng( srng ) => {
                 for i in 0..quantity {
                     if i % 100 == 0 {
-                        let _ = file.write_all( format!("\n").as_bytes() );   
+                        let _ = file.write_all( b"\n" );   
                     }
                     let _ = file.write_all( format!("{:?} ", srng.gen_range(range.0..=range.1) ).as_bytes() );
                 }
@@ -86,4 +87,238 @@ impl Generator {
         }
     }
 
-}
\ No newline at end of file
+    fn rng(&mut self) -> &mut dyn RngCore {
+        match &mut self.rng {
+            RngType::Rng( rng ) => rng,
+            RngType::SRng( srng ) => srng
+        }
+    }
+
+    fn weight<W>(&mut self, weights:(W, W)) -> W 
+        where W: SampleUniform + PartialOrd + Copy
+    {
+        self.rng().gen_range(weights.0..=weights.1)
+    }
+
+    /// Erdős–Rényi graph: every pair of distinct nodes gets an edge with probability `p`,
+    /// each direction on its own for directed graphs. Looks at every pair, O(n²)
+    /// 
+    /// #### weights: Range of the edge weights (from, to)
+    pub fn erdos_renyi<W>(&mut self, nodes:usize, p:f64, kind:Kind, weights:(W, W)) -> AdjList<W> 
+        where W: SampleUniform + PartialOrd + Copy
+    {
+        assert!((0.0..=1.0).contains(&p), "need 0 <= p <= 1");
+
+        let mut graph:AdjList<W> = AdjList::with_nodes(kind, nodes);
+
+        for from in 0..nodes {
+            let first:usize = if kind == Kind::Directed { 0 } else { from + 1 };
+            for to in first..nodes {
+                if from != to && self.rng().gen_bool(p) {
+                    let w:W = self.weight(weights);
+                    graph.add_edge(from, to, w);
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Barabási–Albert graph: starting from a clique of `m + 1` nodes, every new node links to `m`
+    /// distinct nodes picked with probability proportional to their degree, so a few hubs end up
+    /// with most of the edges. Undirected
+    /// 
+    /// #### weights: Range of the edge weights (from, to)
+    pub fn barabasi_albert<W>(&mut self, nodes:usize, m:usize, weights:(W, W)) -> AdjList<W> 
+        where W: SampleUniform + PartialOrd + Copy
+    {
+        assert!(m > 0 && nodes > m, "need 0 < m < nodes");
+
+        let mut graph:AdjList<W> = AdjList::with_nodes(Kind::Undirected, nodes);
+        // every node once per edge touching it, drawing from it follows the degrees
+        let mut ends:Vec<usize> = Vec::with_capacity(2 * m * nodes);
+
+        for from in 0..=m {
+            for to in from + 1..=m {
+                let w:W = self.weight(weights);
+                graph.add_edge(from, to, w);
+                ends.extend([from, to]);
+            }
+        }
+
+        for node in m + 1..nodes {
+            let mut targets:Vec<usize> = Vec::with_capacity(m);
+            while targets.len() < m {
+                let target:usize = ends[self.rng().gen_range(0..ends.len())];
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+
+            for target in targets {
+                let w:W = self.weight(weights);
+                graph.add_edge(node, target, w);
+                ends.extend([node, target]);
+            }
+        }
+
+        graph
+    }
+
+    /// Grid of `rows` x `cols` nodes, node `row * cols + col`, each linked to its 4 neighbours.
+    /// Undirected
+    /// 
+    /// #### weights: Range of the edge weights (from, to)
+    pub fn grid<W>(&mut self, rows:usize, cols:usize, weights:(W, W)) -> AdjList<W> 
+        where W: SampleUniform + PartialOrd + Copy
+    {
+        let mut graph:AdjList<W> = AdjList::with_nodes(Kind::Undirected, rows * cols);
+
+        for (from, to) in grid_links(rows, cols) {
+            let w:W = self.weight(weights);
+            graph.add_edge(from, to, w);
+        }
+
+        graph
+    }
+
+    /// Maze carved in a `rows` x `cols` grid, numbered like `grid()`. The passages form a random
+    /// spanning tree so there is exactly one path between two cells, then every wall left is
+    /// knocked down with probability `loops` to open alternative routes. Undirected
+    /// 
+    /// #### weights: Range of the edge weights (from, to)
+    pub fn maze<W>(&mut self, rows:usize, cols:usize, loops:f64, weights:(W, W)) -> AdjList<W> 
+        where W: SampleUniform + PartialOrd + Copy
+    {
+        assert!((0.0..=1.0).contains(&loops), "need 0 <= loops <= 1");
+
+        let mut graph:AdjList<W> = AdjList::with_nodes(Kind::Undirected, rows * cols);
+        let mut walls:Vec<(usize, usize)> = grid_links(rows, cols);
+        walls.shuffle(self.rng());
+
+        // randomized Kruskal, a wall goes when the cells behind it are not linked yet
+        let mut cells:DisjointSet = DisjointSet::new(rows * cols);
+        for (from, to) in walls {
+            if cells.union(from, to) || self.rng().gen_bool(loops) {
+                let w:W = self.weight(weights);
+                graph.add_edge(from, to, w);
+            }
+        }
+
+        graph
+    }
+
+    /// Directed acyclic graph: the nodes get a random order and every pair gets an edge from
+    /// the earlier to the later one with probability `p`, so no edge ever leads back. O(n²)
+    /// 
+    /// #### weights: Range of the edge weights (from, to)
+    pub fn dag<W>(&mut self, nodes:usize, p:f64, weights:(W, W)) -> AdjList<W> 
+        where W: SampleUniform + PartialOrd + Copy
+    {
+        assert!((0.0..=1.0).contains(&p), "need 0 <= p <= 1");
+
+        let mut graph:AdjList<W> = AdjList::with_nodes(Kind::Directed, nodes);
+        let mut order:Vec<usize> = (0..nodes).collect();
+        order.shuffle(self.rng());
+
+        for i in 0..nodes {
+            for j in i + 1..nodes {
+                if self.rng().gen_bool(p) {
+                    let w:W = self.weight(weights);
+                    graph.add_edge(order[i], order[j], w);
+                }
+            }
+        }
+
+        graph
+    }
+
+}
+
+// pairs of neighbouring cells in a grid, right and down of every cell
+fn grid_links(rows:usize, cols:usize) -> Vec<(usize, usize)> {
+    let mut links:Vec<(usize, usize)> = Vec::with_capacity(2 * rows * cols);
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let node:usize = row * cols + col;
+            if col + 1 < cols {
+                links.push((node, node + 1));
+            }
+            if row + 1 < rows {
+                links.push((node, node + cols));
+            }
+        }
+    }
+
+    links
+}
+#[cfg(test)]
+mod tests {
+    use graph::{adjacency::list::AdjList, base::{Graph, Kind}, traversal::{search::Bfs, topo::toposort}};
+
+    use super::Generator;
+
+    #[test]
+    fn same_seed_gives_the_same_graph() {
+        let a:AdjList<u32> = Generator::new(Some(7)).erdos_renyi(40, 0.2, Kind::Directed, (1, 9));
+        let b:AdjList<u32> = Generator::new(Some(7)).erdos_renyi(40, 0.2, Kind::Directed, (1, 9));
+        assert_eq!(a, b);
+
+        let a:AdjList<u32> = Generator::new(Some(7)).maze(8, 9, 0.1, (1, 9));
+        let b:AdjList<u32> = Generator::new(Some(7)).maze(8, 9, 0.1, (1, 9));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn maze_is_connected() {
+        let mut num_gen:Generator = Generator::new(Some(3));
+
+        for (rows, cols) in [(1, 1), (1, 12), (7, 5), (20, 20)] {
+            let maze:AdjList<u32> = num_gen.maze(rows, cols, 0.0, (1, 1));
+            assert_eq!(Bfs::new(&maze, 0).count(), rows * cols);
+            // without loops the passages are a spanning tree
+            assert_eq!(maze.edge_count(), rows * cols - 1);
+        }
+    }
+
+    #[test]
+    fn dag_has_a_topological_order() {
+        let mut num_gen:Generator = Generator::new(Some(5));
+
+        for p in [0.0, 0.3, 1.0] {
+            let dag:AdjList<u32> = num_gen.dag(30, p, (1, 9));
+            let order:Vec<usize> = toposort(&dag).expect("dag() made a cycle");
+            assert_eq!(order.len(), 30);
+        }
+    }
+
+    #[test]
+    fn barabasi_albert_edge_count() {
+        let mut num_gen:Generator = Generator::new(Some(11));
+
+        for (nodes, m) in [(2, 1), (50, 1), (50, 3), (200, 5)] {
+            let graph:AdjList<u32> = num_gen.barabasi_albert(nodes, m, (1, 9));
+            // the starting clique, then m edges per node added
+            assert_eq!(graph.node_count(), nodes);
+            assert_eq!(graph.edge_count(), m * (m + 1) / 2 + (nodes - m - 1) * m);
+        }
+    }
+
+    #[test]
+    fn grid_node_and_edge_counts() {
+        let mut num_gen:Generator = Generator::new(Some(13));
+
+        for (rows, cols) in [(1, 1), (1, 6), (4, 4), (3, 8)] {
+            let grid:AdjList<u32> = num_gen.grid(rows, cols, (1, 9));
+            assert_eq!(grid.node_count(), rows * cols);
+            assert_eq!(grid.edge_count(), rows * (cols - 1) + cols * (rows - 1));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "need 0 <= p <= 1")]
+    fn probability_out_of_range() {
+        Generator::new(Some(1)).dag::<u32>(5, 1.5, (1, 9));
+    }
+}
@@ -0,0 +1,147 @@
+use core::fmt;
+
+use crate::bits::bitvec::{BitVec, Ones, RankSelect};
+
+/// Set of integers in `0..size` with a fixed universe, one bit per possible element
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    bits:BitVec,
+}
+
+impl BitSet {
+    /// Create an empty set able to hold `0..size`
+    ///
+    /// #Example
+    /// ```
+    /// use structs::bits::bitset::BitSet;
+    ///
+    /// let mut evens = BitSet::new(10);
+    /// let mut small = BitSet::new(10);
+    /// (0..10).step_by(2).for_each(|x| { evens.insert(x); });
+    /// (0..5).for_each(|x| { small.insert(x); });
+    ///
+    /// let both = evens.intersection(&small);
+    /// assert_eq!(both.iter().collect::<Vec<usize>>(), vec![0, 2, 4]);
+    /// assert_eq!(evens.union(&small).count(), 7);
+    /// assert!(both.is_subset(&evens));
+    /// ```
+    pub fn new(size:usize) -> Self {
+        Self { bits: BitVec::filled(size, false) }
+    }
+
+    /// Create a set containing all of `0..size`
+    pub fn full(size:usize) -> Self {
+        Self { bits: BitVec::filled(size, true) }
+    }
+
+    /// Size of the universe
+    pub fn size(&self) -> usize {
+        self.bits.size()
+    }
+
+    /// Amount of elements in the set
+    pub fn count(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.bits.any()
+    }
+
+    pub fn contains(&self, val:usize) -> bool {
+        self.bits.get(val).unwrap_or(false)
+    }
+
+    /// Add `val`, returns false if it was already present. Panics if `val` is outside the universe
+    pub fn insert(&mut self, val:usize) -> bool {
+        let present:bool = self.contains(val);
+        self.bits.set(val, true);
+        !present
+    }
+
+    /// Remove `val`, returns false if it was not present
+    pub fn remove(&mut self, val:usize) -> bool {
+        if !self.contains(val) { return false; }
+        self.bits.set(val, false);
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Iterate over the elements in increasing order
+    pub fn iter(&self) -> Ones<'_> {
+        self.bits.ones()
+    }
+
+    pub fn min(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    pub fn union(&self, other:&BitSet) -> BitSet {
+        let mut res:BitSet = self.clone();
+        res.bits.union_with(&other.bits);
+        res
+    }
+
+    pub fn intersection(&self, other:&BitSet) -> BitSet {
+        let mut res:BitSet = self.clone();
+        res.bits.intersect_with(&other.bits);
+        res
+    }
+
+    pub fn difference(&self, other:&BitSet) -> BitSet {
+        let mut res:BitSet = self.clone();
+        res.bits.difference_with(&other.bits);
+        res
+    }
+
+    pub fn symmetric_difference(&self, other:&BitSet) -> BitSet {
+        let mut res:BitSet = self.clone();
+        res.bits.symmetric_difference_with(&other.bits);
+        res
+    }
+
+    /// Every element of the universe not in the set
+    pub fn complement(&self) -> BitSet {
+        let mut res:BitSet = self.clone();
+        res.bits.negate();
+        res
+    }
+
+    pub fn is_subset(&self, other:&BitSet) -> bool {
+        self.bits.is_subset(&other.bits)
+    }
+
+    pub fn is_disjoint(&self, other:&BitSet) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Build the rank/select index: `rank1(x)` counts the elements below `x`
+    /// and `select1(k)` finds the `k`-th smallest element
+    pub fn rank_select(&self) -> RankSelect<'_> {
+        self.bits.rank_select()
+    }
+
+    pub fn as_bitvec(&self) -> &BitVec {
+        &self.bits
+    }
+}
+
+impl From<BitVec> for BitSet {
+    fn from(bits:BitVec) -> Self {
+        Self { bits }
+    }
+}
+
+impl fmt::Display for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, val) in self.iter().enumerate() {
+            if i > 0 { write!(f, ", ")?; }
+            write!(f, "{}", val)?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -0,0 +1,357 @@
+use core::fmt;
+
+const WORD:usize = 64;
+
+/// Growable vector of bits packed into 64-bit words
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words:Vec<u64>,
+    size:usize,
+}
+
+impl BitVec {
+    pub fn new() -> Self {
+        Self { words: Vec::new(), size: 0 }
+    }
+
+    /// Create an empty vector with room for `cap` bits
+    pub fn with_capacity(cap:usize) -> Self {
+        Self { words: Vec::with_capacity(cap.div_ceil(WORD)), size: 0 }
+    }
+
+    /// Create `size` bits all set to `val`
+    pub fn filled(size:usize, val:bool) -> Self {
+        let fill:u64 = if val { u64::MAX } else { 0 };
+        let mut bits:BitVec = Self { words: vec![fill; size.div_ceil(WORD)], size };
+        bits.clear_tail();
+        bits
+    }
+
+    /// Amount of bits
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn get(&self, idx:usize) -> Option<bool> {
+        if idx >= self.size { return None; }
+        Some(self.words[idx / WORD] >> (idx % WORD) & 1 == 1)
+    }
+
+    /// Set the bit at `idx`, panics if out of bounds
+    pub fn set(&mut self, idx:usize, val:bool) {
+        assert!(idx < self.size, "index {} out of bounds for size {}", idx, self.size);
+
+        if val {
+            self.words[idx / WORD] |= 1 << (idx % WORD);
+        } else {
+            self.words[idx / WORD] &= !(1 << (idx % WORD));
+        }
+    }
+
+    /// Flip the bit at `idx`, panics if out of bounds
+    pub fn toggle(&mut self, idx:usize) {
+        assert!(idx < self.size, "index {} out of bounds for size {}", idx, self.size);
+        self.words[idx / WORD] ^= 1 << (idx % WORD);
+    }
+
+    /// Append a bit to the end
+    pub fn push(&mut self, val:bool) {
+        if self.size.is_multiple_of(WORD) {
+            self.words.push(0);
+        }
+        self.size += 1;
+        self.set(self.size - 1, val);
+    }
+
+    /// Remove the last bit
+    pub fn pop(&mut self) -> Option<bool> {
+        let val:bool = self.get(self.size.checked_sub(1)?)?;
+
+        self.set(self.size - 1, false);
+        self.size -= 1;
+        if self.size.is_multiple_of(WORD) {
+            self.words.pop();
+        }
+
+        Some(val)
+    }
+
+    /// Grow or shrink to `size` bits, new bits are set to `val`
+    pub fn resize(&mut self, size:usize, val:bool) {
+        while self.size > size {
+            self.pop();
+        }
+        while self.size < size {
+            self.push(val);
+        }
+    }
+
+    /// Set every bit to false
+    pub fn clear(&mut self) {
+        self.words.iter_mut().for_each(|w| *w = 0);
+    }
+
+    /// Amount of set bits
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn count_zeros(&self) -> usize {
+        self.size - self.count_ones()
+    }
+
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|w| *w != 0)
+    }
+
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.size
+    }
+
+    /// Iterate over every bit
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.size).map(|i| self.words[i / WORD] >> (i % WORD) & 1 == 1)
+    }
+
+    /// Iterate over the positions of the set bits in increasing order
+    ///
+    /// #Example
+    /// ```
+    /// use structs::bits::bitvec::BitVec;
+    ///
+    /// let bits = BitVec::from_bools(&[false, true, true, false, true]);
+    ///
+    /// assert_eq!(bits.ones().collect::<Vec<usize>>(), vec![1, 2, 4]);
+    /// assert_eq!(bits.count_ones(), 3);
+    /// ```
+    pub fn ones(&self) -> Ones<'_> {
+        Ones { words: &self.words, idx: 0, cur: self.words.first().copied().unwrap_or(0) }
+    }
+
+    pub fn from_bools(vals:&[bool]) -> Self {
+        let mut bits:BitVec = Self::with_capacity(vals.len());
+        for val in vals {
+            bits.push(*val);
+        }
+        bits
+    }
+
+    pub fn to_vec(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+
+    /// Keep the bits set in either vector. The result takes the larger size
+    pub fn union_with(&mut self, other:&BitVec) {
+        if other.size > self.size {
+            self.resize(other.size, false);
+        }
+        for (w, o) in self.words.iter_mut().zip(other.words.iter()) {
+            *w |= o;
+        }
+    }
+
+    /// Keep the bits set in both vectors
+    pub fn intersect_with(&mut self, other:&BitVec) {
+        for (i, w) in self.words.iter_mut().enumerate() {
+            *w &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    /// Keep the bits not set in `other`
+    pub fn difference_with(&mut self, other:&BitVec) {
+        for (w, o) in self.words.iter_mut().zip(other.words.iter()) {
+            *w &= !o;
+        }
+    }
+
+    /// Keep the bits set in exactly one of the vectors. The result takes the larger size
+    pub fn symmetric_difference_with(&mut self, other:&BitVec) {
+        if other.size > self.size {
+            self.resize(other.size, false);
+        }
+        for (w, o) in self.words.iter_mut().zip(other.words.iter()) {
+            *w ^= o;
+        }
+    }
+
+    /// Flip every bit
+    pub fn negate(&mut self) {
+        self.words.iter_mut().for_each(|w| *w = !*w);
+        self.clear_tail();
+    }
+
+    /// Check if every bit set here is also set in `other`
+    pub fn is_subset(&self, other:&BitVec) -> bool {
+        self.words.iter().enumerate()
+            .all(|(i, w)| w & !other.words.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    /// The packed words, bit `i` is bit `i % 64` of word `i / 64`
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Build the rank/select index, see `RankSelect`
+    pub fn rank_select(&self) -> RankSelect<'_> {
+        RankSelect::new(self)
+    }
+
+    // keep the unused bits of the last word at zero
+    fn clear_tail(&mut self) {
+        let used:usize = self.size % WORD;
+        if used > 0 && let Some(last) = self.words.last_mut() {
+            *last &= (1 << used) - 1;
+        }
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter:I) -> Self {
+        let mut bits:BitVec = BitVec::new();
+        for val in iter {
+            bits.push(val);
+        }
+        bits
+    }
+}
+
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            write!(f, "{}", if bit { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the set bits of a `BitVec`
+pub struct Ones<'a> {
+    words:&'a [u64],
+    idx:usize,
+    cur:u64,
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.cur == 0 {
+            self.idx += 1;
+            self.cur = *self.words.get(self.idx)?;
+        }
+
+        let bit:usize = self.cur.trailing_zeros() as usize;
+        self.cur &= self.cur - 1;
+
+        Some(self.idx * WORD + bit)
+    }
+}
+
+// bits covered by one cumulative count
+const BLOCK:usize = 512;
+const BLOCK_WORDS:usize = BLOCK / WORD;
+
+/// Succinct rank/select index over a `BitVec`.
+/// Rank is O(1) and select is O(log n), using about 1/8 extra space
+pub struct RankSelect<'a> {
+    bits:&'a BitVec,
+    // set bits before each block
+    blocks:Vec<usize>,
+}
+
+impl<'a> RankSelect<'a> {
+    pub fn new(bits:&'a BitVec) -> Self {
+        let mut blocks:Vec<usize> = Vec::with_capacity(bits.words.len() / BLOCK_WORDS + 1);
+
+        let mut total:usize = 0;
+        for chunk in bits.words.chunks(BLOCK_WORDS) {
+            blocks.push(total);
+            total += chunk.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+        }
+        blocks.push(total);
+
+        Self { bits, blocks }
+    }
+
+    /// Amount of set bits in `0..idx`
+    ///
+    /// #Example
+    /// ```
+    /// use structs::bits::bitvec::BitVec;
+    ///
+    /// let bits = BitVec::from_bools(&[true, false, true, true, false, true]);
+    /// let index = bits.rank_select();
+    ///
+    /// assert_eq!(index.rank1(3), 2);
+    /// assert_eq!(index.rank0(3), 1);
+    /// assert_eq!(index.select1(2), Some(3));
+    /// assert_eq!(index.select0(1), Some(4));
+    /// assert_eq!(index.select1(4), None);
+    /// ```
+    pub fn rank1(&self, idx:usize) -> usize {
+        let idx:usize = idx.min(self.bits.size);
+        let (word, bit) = (idx / WORD, idx % WORD);
+
+        let mut res:usize = self.blocks[word / BLOCK_WORDS];
+        for w in &self.bits.words[(word / BLOCK_WORDS) * BLOCK_WORDS..word] {
+            res += w.count_ones() as usize;
+        }
+        if bit > 0 {
+            res += (self.bits.words[word] & ((1 << bit) - 1)).count_ones() as usize;
+        }
+
+        res
+    }
+
+    /// Amount of unset bits in `0..idx`
+    pub fn rank0(&self, idx:usize) -> usize {
+        idx.min(self.bits.size) - self.rank1(idx)
+    }
+
+    /// Position of the `k`-th set bit, counting from 0
+    pub fn select1(&self, k:usize) -> Option<usize> {
+        self.select(k, true)
+    }
+
+    /// Position of the `k`-th unset bit, counting from 0
+    pub fn select0(&self, k:usize) -> Option<usize> {
+        self.select(k, false)
+    }
+
+    fn select(&self, k:usize, ones:bool) -> Option<usize> {
+        let before = |block:usize| -> usize {
+            if ones { self.blocks[block] } else { (block * BLOCK).min(self.bits.size) - self.blocks[block] }
+        };
+
+        let last:usize = self.blocks.len() - 1;
+        if k >= before(last) { return None; }
+
+        // last block with less than k + 1 bits before it
+        let (mut lo, mut hi) = (0, last);
+        while hi - lo > 1 {
+            let mid:usize = (lo + hi) / 2;
+            if before(mid) <= k { lo = mid; } else { hi = mid; }
+        }
+
+        let mut rest:usize = k - before(lo);
+        let end:usize = ((lo + 1) * BLOCK_WORDS).min(self.bits.words.len());
+        for word in lo * BLOCK_WORDS..end {
+            let mut w:u64 = if ones { self.bits.words[word] } else { !self.bits.words[word] };
+            let count:usize = w.count_ones() as usize;
+
+            if rest < count {
+                for _ in 0..rest {
+                    w &= w - 1;
+                }
+                return Some(word * WORD + w.trailing_zeros() as usize);
+            }
+            rest -= count;
+        }
+
+        None
+    }
+}
@@ -0,0 +1,308 @@
+use core::fmt;
+
+use crate::viz::{Diagram, Visualize};
+
+/// Union-Find over the elements `0..size` with union by rank and path compression
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisjointSet {
+    parent:Vec<usize>,
+    rank:Vec<u8>,
+    sizes:Vec<usize>,
+    count:usize,
+}
+
+impl DisjointSet {
+    /// Create `size` singleton sets
+    ///
+    /// #Example
+    /// ```
+    /// use structs::disjoint_set::DisjointSet;
+    ///
+    /// let mut set = DisjointSet::new(4);
+    /// set.union(0, 1);
+    ///
+    /// assert!(set.connected(0, 1));
+    /// assert!(!set.connected(1, 2));
+    /// assert_eq!(set.count(), 3);
+    /// ```
+    pub fn new(size:usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+            sizes: vec![1; size],
+            count: size
+        }
+    }
+
+    /// Amount of elements
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Amount of disjoint components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Add a new singleton set and return its element
+    pub fn make_set(&mut self) -> usize {
+        let idx:usize = self.parent.len();
+
+        self.parent.push(idx);
+        self.rank.push(0);
+        self.sizes.push(1);
+        self.count += 1;
+
+        idx
+    }
+
+    /// Get the representative of the set containing `x`, compressing the path on the way
+    pub fn find(&mut self, x:usize) -> usize {
+        let mut root:usize = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut cur:usize = x;
+        while self.parent[cur] != root {
+            let next:usize = self.parent[cur];
+            self.parent[cur] = root;
+            cur = next;
+        }
+
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`. Returns false if they were already merged
+    pub fn union(&mut self, a:usize, b:usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb { return false; }
+
+        if self.rank[ra] < self.rank[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        if self.rank[ra] == self.rank[rb] {
+            self.rank[ra] += 1;
+        }
+
+        self.parent[rb] = ra;
+        self.sizes[ra] += self.sizes[rb];
+        self.count -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a:usize, b:usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the component containing `x`
+    pub fn component_size(&mut self, x:usize) -> usize {
+        let root:usize = self.find(x);
+        self.sizes[root]
+    }
+
+    /// Group every element by its component. Components are ordered by their smallest element
+    ///
+    /// #Example
+    /// ```
+    /// use structs::disjoint_set::DisjointSet;
+    ///
+    /// let mut set = DisjointSet::new(5);
+    /// set.union(3, 0);
+    /// set.union(4, 2);
+    ///
+    /// assert_eq!(set.components(), vec![vec![0, 3], vec![1], vec![2, 4]]);
+    /// ```
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut slot:Vec<Option<usize>> = vec![None; self.parent.len()];
+        let mut res:Vec<Vec<usize>> = Vec::with_capacity(self.count);
+
+        for x in 0..self.parent.len() {
+            let root:usize = self.find(x);
+
+            match slot[root] {
+                Some(i) => res[i].push(x),
+                None => {
+                    slot[root] = Some(res.len());
+                    res.push(vec![x]);
+                }
+            }
+        }
+
+        res
+    }
+}
+
+// every element below its parent, with the roots highlighted
+fn forest(name:&str, parent:&[usize]) -> Diagram {
+    let mut children:Vec<Vec<usize>> = vec![Vec::new(); parent.len()];
+    for (x, &p) in parent.iter().enumerate() {
+        if p != x {
+            children[p].push(x);
+        }
+    }
+
+    let mut diagram:Diagram = Diagram::new(name);
+    for root in (0..parent.len()).filter(|&x| parent[x] == x) {
+        let id:usize = diagram.add_tree(root, |x| x.to_string(), |x| children[x].iter().map(|c| Some(*c)));
+        diagram.highlight(id);
+    }
+    diagram
+}
+
+impl Visualize for DisjointSet {
+    /// The parent links as they are, without compressing them first
+    fn diagram(&self) -> Diagram {
+        forest("DisjointSet", &self.parent)
+    }
+}
+
+impl fmt::Display for DisjointSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut set:DisjointSet = self.clone();
+        write!(f, "{:?}", set.components())
+    }
+}
+
+/// Union-Find that can undo its unions, for offline algorithms (e.g. dynamic connectivity).
+/// Uses union by rank only since path compression can not be rolled back
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollbackDisjointSet {
+    parent:Vec<usize>,
+    rank:Vec<u8>,
+    sizes:Vec<usize>,
+    count:usize,
+    // (attached root, rank increased) for every successful union
+    history:Vec<(usize, bool)>,
+}
+
+impl RollbackDisjointSet {
+    pub fn new(size:usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+            sizes: vec![1; size],
+            count: size,
+            history: Vec::new()
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Representative of the set containing `x` in O(log n)
+    pub fn find(&self, mut x:usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merge the sets containing `a` and `b`. Returns false if they were already merged
+    pub fn union(&mut self, a:usize, b:usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb { return false; }
+
+        if self.rank[ra] < self.rank[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        let ranked:bool = self.rank[ra] == self.rank[rb];
+        if ranked {
+            self.rank[ra] += 1;
+        }
+
+        self.parent[rb] = ra;
+        self.sizes[ra] += self.sizes[rb];
+        self.count -= 1;
+        self.history.push((rb, ranked));
+
+        true
+    }
+
+    pub fn connected(&self, a:usize, b:usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn component_size(&self, x:usize) -> usize {
+        self.sizes[self.find(x)]
+    }
+
+    /// Get a marker of the current state to later `rollback()` to
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo the last successful union. Returns false if there was nothing to undo
+    pub fn undo(&mut self) -> bool {
+        let Some((child, ranked)) = self.history.pop() else {
+            return false;
+        };
+
+        let root:usize = self.parent[child];
+        self.parent[child] = child;
+        self.sizes[root] -= self.sizes[child];
+        if ranked {
+            self.rank[root] -= 1;
+        }
+        self.count += 1;
+
+        true
+    }
+
+    /// Undo every union made after `snapshot`
+    ///
+    /// #Example
+    /// ```
+    /// use structs::disjoint_set::RollbackDisjointSet;
+    ///
+    /// let mut set = RollbackDisjointSet::new(4);
+    /// set.union(0, 1);
+    ///
+    /// let snap = set.snapshot();
+    /// set.union(1, 2);
+    /// set.union(2, 3);
+    /// assert_eq!(set.count(), 1);
+    ///
+    /// set.rollback(snap);
+    /// assert!(set.connected(0, 1));
+    /// assert!(!set.connected(1, 2));
+    /// assert_eq!(set.count(), 3);
+    /// ```
+    pub fn rollback(&mut self, snapshot:usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+
+    /// Group every element by its component. Components are ordered by their smallest element
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut slot:Vec<Option<usize>> = vec![None; self.parent.len()];
+        let mut res:Vec<Vec<usize>> = Vec::with_capacity(self.count);
+
+        for x in 0..self.parent.len() {
+            let root:usize = self.find(x);
+
+            match slot[root] {
+                Some(i) => res[i].push(x),
+                None => {
+                    slot[root] = Some(res.len());
+                    res.push(vec![x]);
+                }
+            }
+        }
+
+        res
+    }
+}
+
+impl Visualize for RollbackDisjointSet {
+    fn diagram(&self) -> Diagram {
+        forest("RollbackDisjointSet", &self.parent)
+    }
+}
@@ -0,0 +1,274 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    collections::hash_map::RandomState,
+    fmt::Debug,
+    hash::{BuildHasher, Hash}
+};
+
+use crate::{
+    list::{
+        list::{BaseOperations, List},
+        node::NodeRef
+    },
+    viz::{Diagram, Visualize}
+};
+
+const MIN_BUCKETS:usize = 8;
+
+/// Hash map with separate chaining, every bucket is a `List` of entries.
+/// Values are handed out as clones since the list nodes live behind `RefCell`s
+pub struct ChainedHashMap<K, V, S = RandomState> {
+    buckets:Vec<List<(K, V)>>,
+    size:usize,
+    hasher:S,
+}
+
+impl<K, V> ChainedHashMap<K, V, RandomState> {
+    /// Create an empty map using the std SipHash hasher
+    ///
+    /// #Example
+    /// ```
+    /// use structs::hash::chained::ChainedHashMap;
+    ///
+    /// let mut map:ChainedHashMap<String, usize> = ChainedHashMap::new();
+    /// map.insert("apple".to_string(), 3);
+    /// map.insert("pear".to_string(), 5);
+    ///
+    /// assert_eq!(map.get("pear"), Some(5));
+    /// assert_eq!(map.remove("apple"), Some(3));
+    /// assert_eq!(map.get("apple"), None);
+    /// assert_eq!(map.size(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V> Default for ChainedHashMap<K, V, RandomState> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> ChainedHashMap<K, V, S> {
+    pub fn with_hasher(hasher:S) -> Self {
+        Self { buckets: Vec::new(), size: 0, hasher }
+    }
+
+    /// Amount of entries
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Amount of buckets
+    pub fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Length of the longest bucket
+    pub fn max_chain(&self) -> usize {
+        self.buckets.iter().map(|b| b.size).max().unwrap_or(0)
+    }
+
+    fn bucket(&self, hash:u64) -> usize {
+        hash as usize & (self.buckets.len() - 1)
+    }
+
+    // find the node holding `key` in the bucket
+    fn find_node<Q>(&self, bucket:usize, key:&Q) -> Option<NodeRef<(K, V)>>
+        where K: Borrow<Q>, Q: Eq + ?Sized
+    {
+        let mut cur:Option<NodeRef<(K, V)>> = self.buckets[bucket].get_head();
+
+        while let Some(node) = cur {
+            if node.as_ref().borrow().val.0.borrow() == key { return Some(node); }
+            cur = node.as_ref().borrow().next.clone();
+        }
+
+        None
+    }
+}
+
+impl<K, V, S> ChainedHashMap<K, V, S>
+    where K: Hash + Eq + Clone, V: Clone, S: BuildHasher
+{
+    /// Insert a value, returning the previous value of the key
+    pub fn insert(&mut self, key:K, val:V) -> Option<V> {
+        if self.buckets.is_empty() {
+            self.resize(MIN_BUCKETS);
+        }
+
+        let bucket:usize = self.bucket(self.hasher.hash_one(&key));
+        if let Some(node) = self.find_node(bucket, &key) {
+            return Some(std::mem::replace(&mut node.as_ref().borrow_mut().val.1, val));
+        }
+
+        self.buckets[bucket].append((key, val));
+        self.size += 1;
+
+        if self.size > self.buckets.len() {
+            self.resize(self.buckets.len() * 2);
+        }
+
+        None
+    }
+
+    pub fn get<Q>(&self, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        if self.buckets.is_empty() { return None; }
+
+        let node:NodeRef<(K, V)> = self.find_node(self.bucket(self.hasher.hash_one(key)), key)?;
+        Some(node.as_ref().borrow().val.1.clone())
+    }
+
+    pub fn contains_key<Q>(&self, key:&Q) -> bool
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        if self.buckets.is_empty() { return false; }
+        self.find_node(self.bucket(self.hasher.hash_one(key)), key).is_some()
+    }
+
+    /// Change the value of `key` in place, returns false if the key is missing
+    pub fn update<Q, F>(&mut self, key:&Q, f:F) -> bool
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized, F: FnOnce(&mut V)
+    {
+        if self.buckets.is_empty() { return false; }
+
+        let Some(node) = self.find_node(self.bucket(self.hasher.hash_one(key)), key) else {
+            return false;
+        };
+        f(&mut node.as_ref().borrow_mut().val.1);
+        true
+    }
+
+    /// Remove `key` by swapping its entry with the last one of the bucket and detaching it
+    pub fn remove<Q>(&mut self, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        if self.buckets.is_empty() { return None; }
+
+        let bucket:usize = self.bucket(self.hasher.hash_one(key));
+        let node:NodeRef<(K, V)> = self.find_node(bucket, key)?;
+
+        let list:&mut List<(K, V)> = &mut self.buckets[bucket];
+        let last:NodeRef<(K, V)> = list.get_tail().or(list.get_head())?;
+        if !std::rc::Rc::ptr_eq(&node, &last) {
+            std::mem::swap(&mut node.as_ref().borrow_mut().val, &mut last.as_ref().borrow_mut().val);
+        }
+
+        let (_, val) = list.detach()?;
+        self.size -= 1;
+
+        Some(val)
+    }
+
+    pub fn clear(&mut self) {
+        for bucket in self.buckets.iter_mut() {
+            unlink(bucket);
+            *bucket = List::empty();
+        }
+        self.size = 0;
+    }
+
+    /// Get every entry in bucket order
+    pub fn to_vec(&self) -> Vec<(K, V)> {
+        let mut res:Vec<(K, V)> = Vec::with_capacity(self.size);
+        for bucket in self.buckets.iter() {
+            res.extend(bucket.clone().to_vec());
+        }
+        res
+    }
+
+    pub fn keys(&self) -> Vec<K> {
+        self.to_vec().into_iter().map(|(k, _)| k).collect()
+    }
+
+    fn resize(&mut self, buckets:usize) {
+        let entries:Vec<(K, V)> = self.to_vec();
+
+        for bucket in self.buckets.iter_mut() {
+            unlink(bucket);
+        }
+        self.buckets = (0..buckets).map(|_| List::empty()).collect();
+
+        for (key, val) in entries {
+            let bucket:usize = self.bucket(self.hasher.hash_one(&key));
+            self.buckets[bucket].append((key, val));
+        }
+    }
+}
+
+// drop the back links so the nodes are not kept alive by reference cycles
+fn unlink<T>(list:&List<T>) {
+    let mut cur:Option<NodeRef<T>> = list.get_head();
+
+    while let Some(node) = cur {
+        node.as_ref().borrow_mut().prev = None;
+        cur = node.as_ref().borrow().next.clone();
+    }
+}
+
+impl<K, V, S> Drop for ChainedHashMap<K, V, S> {
+    fn drop(&mut self) {
+        for bucket in self.buckets.iter() {
+            unlink(bucket);
+        }
+    }
+}
+
+impl<K, V, S> Clone for ChainedHashMap<K, V, S>
+    where K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Clone
+{
+    fn clone(&self) -> Self {
+        let mut map:Self = Self::with_hasher(self.hasher.clone());
+        for (key, val) in self.to_vec() {
+            map.insert(key, val);
+        }
+        map
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for ChainedHashMap<K, V, S>
+    where K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Default
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter:I) -> Self {
+        let mut map:Self = Self::with_hasher(S::default());
+        for (key, val) in iter {
+            map.insert(key, val);
+        }
+        map
+    }
+}
+
+impl<K, V, S> fmt::Display for ChainedHashMap<K, V, S>
+    where K: Hash + Eq + Clone + Debug, V: Clone + Debug, S: BuildHasher
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.to_vec()).finish()
+    }
+}
+
+impl<K, V, S> Visualize for ChainedHashMap<K, V, S>
+    where K: Clone + Debug, V: Clone + Debug
+{
+    /// Every non empty bucket with its chain below it
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("ChainedHashMap");
+        for (idx, bucket) in self.buckets.iter().enumerate() {
+            let entries:Vec<(K, V)> = bucket.clone().to_vec();
+            if entries.is_empty() { continue; }
+
+            let id:usize = diagram.add_root(format!("#{}", idx));
+            for (key, val) in entries {
+                diagram.add_child(id, format!("{:?}: {:?}", key, val));
+            }
+        }
+        diagram
+    }
+}
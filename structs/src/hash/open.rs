@@ -0,0 +1,305 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    collections::hash_map::RandomState,
+    fmt::Debug,
+    hash::{BuildHasher, Hash}
+};
+
+use crate::viz::{Diagram, Visualize};
+
+// grow once more than 7/8 of the slots are used
+const LOAD_NUM:usize = 7;
+const LOAD_DEN:usize = 8;
+const MIN_CAP:usize = 8;
+
+#[derive(Clone, Debug)]
+struct Slot<K, V> {
+    hash:u64,
+    // distance from the slot the hash points to
+    dist:usize,
+    key:K,
+    val:V,
+}
+
+/// Hash map with open addressing and Robin Hood linear probing.
+/// Entries far from their home slot steal the place of entries closer to theirs,
+/// keeping probe lengths short, and removal shifts the following entries back.
+#[derive(Clone)]
+pub struct OpenHashMap<K, V, S = RandomState> {
+    slots:Vec<Option<Slot<K, V>>>,
+    size:usize,
+    hasher:S,
+}
+
+impl<K, V> OpenHashMap<K, V, RandomState> {
+    /// Create an empty map using the std SipHash hasher
+    ///
+    /// #Example
+    /// ```
+    /// use structs::hash::open::OpenHashMap;
+    ///
+    /// let mut map:OpenHashMap<&str, i32> = OpenHashMap::new();
+    /// map.insert("one", 1);
+    /// map.insert("two", 2);
+    ///
+    /// assert_eq!(map.get("one"), Some(&1));
+    /// assert_eq!(map.insert("one", 10), Some(1));
+    /// assert_eq!(map.remove("two"), Some(2));
+    /// assert_eq!(map.size(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    pub fn with_capacity(cap:usize) -> Self {
+        Self::with_capacity_and_hasher(cap, RandomState::new())
+    }
+}
+
+impl<K, V> Default for OpenHashMap<K, V, RandomState> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> OpenHashMap<K, V, S> {
+    /// Create an empty map using a custom hasher
+    ///
+    /// #Example
+    /// ```
+    /// use structs::hash::{open::OpenHashMap, fnv::FnvBuildHasher};
+    ///
+    /// let mut map:OpenHashMap<u32, u32, FnvBuildHasher> = OpenHashMap::with_hasher(FnvBuildHasher::default());
+    /// map.insert(7, 49);
+    ///
+    /// assert!(map.contains_key(&7));
+    /// ```
+    pub fn with_hasher(hasher:S) -> Self {
+        Self { slots: Vec::new(), size: 0, hasher }
+    }
+
+    pub fn with_capacity_and_hasher(cap:usize, hasher:S) -> Self {
+        let mut map:Self = Self::with_hasher(hasher);
+        if cap > 0 {
+            let slots:usize = (cap * LOAD_DEN).div_ceil(LOAD_NUM).next_power_of_two().max(MIN_CAP);
+            map.slots = (0..slots).map(|_| None).collect();
+        }
+        map
+    }
+
+    /// Amount of entries
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Amount of slots
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Longest distance of an entry from its home slot
+    pub fn max_probe(&self) -> usize {
+        self.slots.iter().flatten().map(|s| s.dist).max().unwrap_or(0)
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|s| *s = None);
+        self.size = 0;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slots.iter().flatten().map(|s| (&s.key, &s.val))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.slots.iter_mut().flatten().map(|s| (&s.key, &mut s.val))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    fn mask(&self) -> usize {
+        self.slots.len() - 1
+    }
+
+    // place an entry known not to be in the map
+    fn insert_new(&mut self, mut slot:Slot<K, V>) {
+        let mask:usize = self.mask();
+        let mut idx:usize = slot.hash as usize & mask;
+        slot.dist = 0;
+
+        loop {
+            match &mut self.slots[idx] {
+                None => {
+                    self.slots[idx] = Some(slot);
+                    return;
+                },
+                Some(cur) => {
+                    if cur.dist < slot.dist {
+                        std::mem::swap(cur, &mut slot);
+                    }
+                }
+            }
+
+            idx = (idx + 1) & mask;
+            slot.dist += 1;
+        }
+    }
+
+    fn grow(&mut self) {
+        let cap:usize = (self.slots.len() * 2).max(MIN_CAP);
+        let old:Vec<Option<Slot<K, V>>> = std::mem::replace(&mut self.slots, (0..cap).map(|_| None).collect());
+
+        for slot in old.into_iter().flatten() {
+            self.insert_new(slot);
+        }
+    }
+}
+
+impl<K, V, S> OpenHashMap<K, V, S>
+    where K: Hash + Eq, S: BuildHasher
+{
+    /// Insert a value, returning the previous value of the key
+    pub fn insert(&mut self, key:K, val:V) -> Option<V> {
+        let hash:u64 = self.hasher.hash_one(&key);
+
+        if let Some(idx) = self.find(hash, &key) {
+            let slot:&mut Slot<K, V> = self.slots[idx].as_mut().unwrap();
+            return Some(std::mem::replace(&mut slot.val, val));
+        }
+
+        if (self.size + 1) * LOAD_DEN > self.slots.len() * LOAD_NUM {
+            self.grow();
+        }
+        self.insert_new(Slot { hash, dist: 0, key, val });
+        self.size += 1;
+
+        None
+    }
+
+    pub fn get<Q>(&self, key:&Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let idx:usize = self.find(self.hasher.hash_one(key), key)?;
+        self.slots[idx].as_ref().map(|s| &s.val)
+    }
+
+    pub fn get_mut<Q>(&mut self, key:&Q) -> Option<&mut V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let idx:usize = self.find(self.hasher.hash_one(key), key)?;
+        self.slots[idx].as_mut().map(|s| &mut s.val)
+    }
+
+    pub fn contains_key<Q>(&self, key:&Q) -> bool
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.find(self.hasher.hash_one(key), key).is_some()
+    }
+
+    /// Get the value of `key`, inserting `default()` first if it is missing
+    pub fn get_or_insert_with<F>(&mut self, key:K, default:F) -> &mut V
+        where F: FnOnce() -> V, K: Clone
+    {
+        if !self.contains_key(&key) {
+            self.insert(key.clone(), default());
+        }
+        self.get_mut(&key).unwrap()
+    }
+
+    /// Remove `key`, shifting the entries probed after it one slot back
+    pub fn remove<Q>(&mut self, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        let mut idx:usize = self.find(self.hasher.hash_one(key), key)?;
+        let removed:Slot<K, V> = self.slots[idx].take().unwrap();
+        self.size -= 1;
+
+        let mask:usize = self.mask();
+        loop {
+            let next:usize = (idx + 1) & mask;
+            match self.slots[next].take() {
+                Some(mut slot) if slot.dist > 0 => {
+                    slot.dist -= 1;
+                    self.slots[idx] = Some(slot);
+                    idx = next;
+                },
+                other => {
+                    self.slots[next] = other;
+                    break;
+                }
+            }
+        }
+
+        Some(removed.val)
+    }
+
+    fn find<Q>(&self, hash:u64, key:&Q) -> Option<usize>
+        where K: Borrow<Q>, Q: Eq + ?Sized
+    {
+        if self.slots.is_empty() { return None; }
+
+        let mask:usize = self.mask();
+        let mut idx:usize = hash as usize & mask;
+        let mut dist:usize = 0;
+
+        while let Some(slot) = &self.slots[idx] {
+            // a richer entry means the key would have been placed before it
+            if slot.dist < dist { return None; }
+            if slot.hash == hash && slot.key.borrow() == key { return Some(idx); }
+
+            idx = (idx + 1) & mask;
+            dist += 1;
+        }
+
+        None
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for OpenHashMap<K, V, S>
+    where K: Hash + Eq, S: BuildHasher + Default
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter:I) -> Self {
+        let mut map:Self = Self::with_hasher(S::default());
+        for (key, val) in iter {
+            map.insert(key, val);
+        }
+        map
+    }
+}
+
+impl<K, V, S> fmt::Display for OpenHashMap<K, V, S>
+    where K: Debug, V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S> Visualize for OpenHashMap<K, V, S>
+    where K: Debug, V: Debug
+{
+    /// One node per used slot with the distance to its home slot, the displaced entries highlighted
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("OpenHashMap");
+        for (idx, slot) in self.slots.iter().enumerate() {
+            let Some(slot) = slot else { continue; };
+
+            let id:usize = diagram.add_root(format!("#{} {:?}: {:?} (+{})", idx, slot.key, slot.val, slot.dist));
+            if slot.dist > 0 {
+                diagram.highlight(id);
+            }
+        }
+        diagram
+    }
+}
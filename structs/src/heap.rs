@@ -1,5 +1,7 @@
 use core::fmt;
-use std::{fmt::Debug, usize::MAX};
+use std::fmt::Debug;
+
+use crate::viz::{Diagram, Visualize};
 
 pub struct MaxHeap<T> {
     pub max_size:usize,
@@ -11,7 +13,7 @@ impl<T> MaxHeap<T>
     where T: PartialOrd + Clone
 {
     pub fn new() -> Self {
-        Self { vals: Vec::new(), min: None, max_size: MAX }
+        Self { vals: Vec::new(), min: None, max_size: usize::MAX }
     }
 
     /// Create new heap with fixed size
@@ -34,13 +36,12 @@ impl<T> MaxHeap<T>
 
     /// Get the root 
     pub fn get_max(&self) -> Option<&T> {
-        if self.vals.is_empty() { return None; }
-        return Some( &self.vals[0] )
+        self.vals.first()
     }
 
     /// Cahnge the root 
     pub fn set_max(&mut self, val:&T) {
-        if let None = self.get_max() {
+        if self.get_max().is_none() {
             self.push(val.clone());
             return; 
         };
@@ -115,7 +116,7 @@ impl<T> MaxHeap<T>
 
     fn bubble_up(&mut self, mut idx:usize) {
         while idx > 0 {
-            let parent_idx:usize = if idx % 2 == 0 { 
+            let parent_idx:usize = if idx.is_multiple_of(2) { 
                 (idx / 2) - 1
             } else { idx / 2 };
     
@@ -148,12 +149,8 @@ impl<T> MaxHeap<T>
                 break;
             }
             
-            if self.vals[rc] == self.vals[lc] && self.vals[idx] < self.vals[lc] {
-                self.swap(idx, lc);
-                idx = lc;
-            }
-            // left is bigger
-            else if self.vals[lc] > self.vals[rc] && self.vals[idx] < self.vals[lc] {
+            // left is bigger or equal
+            if self.vals[lc] >= self.vals[rc] && self.vals[idx] < self.vals[lc] {
                 self.swap(idx, lc);
                 idx = lc;
             }
@@ -173,6 +170,14 @@ impl<T> MaxHeap<T>
     }
 }
 
+impl<T> Default for MaxHeap<T> 
+    where T: PartialOrd + Clone
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub trait Heapify<T> {
     fn heapify(self) -> MaxHeap<T>;
 }
@@ -196,7 +201,7 @@ pub trait FindSmallest<T> {
     fn find_smallest(&self, k: usize) -> Vec<T>;
 }
 
-impl<T> FindSmallest<T> for Vec<T> 
+impl<T> FindSmallest<T> for [T] 
     where T: PartialOrd + Clone + Debug
 {
     fn find_smallest(&self, k: usize) -> Vec<T> {
@@ -205,14 +210,14 @@ impl<T> FindSmallest<T> for Vec<T>
 
         let mut heap:MaxHeap<T> = MaxHeap::with_capacity(k);
 
-        for i in 0..k { 
-            heap.push(self[i].clone()); 
+        for val in self.iter().take(k) { 
+            heap.push(val.clone()); 
         }
 
-        for i in k..self.len() {
-            if &self[i] >= heap.get_max().unwrap() { continue; }
+        for val in self.iter().skip(k) {
+            if val >= heap.get_max().unwrap() { continue; }
 
-            heap.set_max(&self[i]);
+            heap.set_max(val);
         }
 
         heap.sort();
@@ -221,10 +226,173 @@ impl<T> FindSmallest<T> for Vec<T>
     }
 }
 
+impl<T> Visualize for MaxHeap<T> 
+    where T: Debug
+{
+    /// The implicit binary tree of the heap, the root highlighted
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("MaxHeap");
+        if self.vals.is_empty() { return diagram; }
+
+        let len:usize = self.vals.len();
+        let root:usize = diagram.add_tree(0, |i| format!("{:?}", self.vals[i]), |i| {
+            [2 * i + 1, 2 * i + 2].map(|c| (c < len).then_some(c))
+        });
+        diagram.highlight(root);
+        diagram
+    }
+}
+
 impl<T> fmt::Display for MaxHeap<T> 
     where T: Debug
 {
+    /// Flat vector, or the heap as an indented tree with `{:#}`
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.to_ascii());
+        }
         write!(f, "{:?}", self.vals)
     }
 }
+
+/// Binary min-heap over the ids `0..capacity`, each holding a priority that can be
+/// lowered in place (decrease-key) instead of pushing a duplicate entry
+pub struct IndexedMinHeap<P> {
+    // ids in heap order
+    heap:Vec<usize>,
+    // position of every id in `heap`
+    pos:Vec<Option<usize>>,
+    prio:Vec<Option<P>>,
+}
+
+impl<P> IndexedMinHeap<P> 
+    where P: PartialOrd
+{
+    pub fn new(capacity:usize) -> Self {
+        Self {
+            heap: Vec::with_capacity(capacity),
+            pos: (0..capacity).map(|_| None).collect(),
+            prio: (0..capacity).map(|_| None).collect()
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, id:usize) -> bool {
+        self.pos.get(id).is_some_and(Option::is_some)
+    }
+
+    /// Priority of `id` while it is in the heap
+    pub fn priority(&self, id:usize) -> Option<&P> {
+        self.prio.get(id)?.as_ref()
+    }
+
+    /// Get the id with the lowest priority
+    pub fn peek(&self) -> Option<(usize, &P)> {
+        let id:usize = *self.heap.first()?;
+        Some((id, self.prio[id].as_ref().unwrap()))
+    }
+
+    /// Insert `id`, or lower its priority if it is already in the heap.
+    /// Returns false when `id` is already there with a priority not above `prio`
+    ///
+    /// #Example
+    /// ```
+    /// use structs::heap::IndexedMinHeap;
+    ///
+    /// let mut heap:IndexedMinHeap<u32> = IndexedMinHeap::new(4);
+    /// heap.push(0, 30);
+    /// heap.push(1, 20);
+    /// heap.push(2, 50);
+    ///
+    /// assert!(heap.push(2, 10));
+    /// assert!(!heap.push(1, 25));
+    ///
+    /// assert_eq!(heap.pop(), Some((2, 10)));
+    /// assert_eq!(heap.pop(), Some((1, 20)));
+    /// assert_eq!(heap.pop(), Some((0, 30)));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn push(&mut self, id:usize, prio:P) -> bool {
+        assert!(id < self.pos.len(), "id {} out of bounds for capacity {}", id, self.pos.len());
+
+        match self.pos[id] {
+            Some(_) => self.decrease_key(id, prio),
+            None => {
+                self.prio[id] = Some(prio);
+                self.pos[id] = Some(self.heap.len());
+                self.heap.push(id);
+                self.sift_up(self.heap.len() - 1);
+                true
+            }
+        }
+    }
+
+    /// Lower the priority of an id in the heap, returns false if it is missing
+    /// or `prio` is not lower than its current priority
+    pub fn decrease_key(&mut self, id:usize, prio:P) -> bool {
+        let Some(idx) = self.pos.get(id).copied().flatten() else {
+            return false;
+        };
+        if self.prio[id].as_ref().is_some_and(|cur| &prio >= cur) { return false; }
+
+        self.prio[id] = Some(prio);
+        self.sift_up(idx);
+        true
+    }
+
+    /// Remove the id with the lowest priority
+    pub fn pop(&mut self) -> Option<(usize, P)> {
+        if self.heap.is_empty() { return None; }
+
+        let last:usize = self.heap.len() - 1;
+        self.swap(0, last);
+        let id:usize = self.heap.pop().unwrap();
+        self.pos[id] = None;
+
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((id, self.prio[id].take().unwrap()))
+    }
+
+    fn less(&self, a:usize, b:usize) -> bool {
+        self.prio[self.heap[a]] < self.prio[self.heap[b]]
+    }
+
+    fn swap(&mut self, a:usize, b:usize) {
+        self.heap.swap(a, b);
+        self.pos[self.heap[a]] = Some(a);
+        self.pos[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, mut idx:usize) {
+        while idx > 0 {
+            let parent:usize = (idx - 1) / 2;
+            if !self.less(idx, parent) { break; }
+
+            self.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx:usize) {
+        loop {
+            let (left, right) = (2 * idx + 1, 2 * idx + 2);
+            let mut min:usize = idx;
+
+            if left < self.heap.len() && self.less(left, min) { min = left; }
+            if right < self.heap.len() && self.less(right, min) { min = right; }
+            if min == idx { break; }
+
+            self.swap(idx, min);
+            idx = min;
+        }
+    }
+}
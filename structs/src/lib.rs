@@ -1,7 +1,29 @@
 
 pub mod heap;
+pub mod disjoint_set;
 
 pub mod list {
+    #[allow(clippy::module_inception)]
     pub mod list;
     pub mod node;
-}
\ No newline at end of file
+}
+
+pub mod range {
+    pub mod ops;
+    pub mod fenwick;
+    pub mod segment;
+    pub mod sparse;
+}
+
+pub mod bits {
+    pub mod bitvec;
+    pub mod bitset;
+}
+
+pub mod hash {
+    pub mod fnv;
+    pub mod open;
+    pub mod chained;
+}
+
+pub mod viz;
@@ -2,12 +2,14 @@
 
 use core::fmt;
 use std::{
-    borrow::Borrow, 
     fmt::Debug, 
     rc::Rc
 };
 
-use crate::list::node::{Node, NodeRef};
+use crate::{
+    list::node::{Node, NodeRef},
+    viz::{Diagram, Visualize}
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct List<T> {
@@ -17,9 +19,10 @@ pub struct List<T> {
 }
 
 //Basic Operations for the Lists
+/// Positions count from 1: the head is at 1 and the tail at `size`, 0 is never a position
 pub trait BaseOperations<T> {
-    fn empty() -> List<T>;
-    fn new(val: T) -> List<T>;
+    fn empty() -> Self;
+    fn new(val: T) -> Self;
 
     fn get_head(&self) -> Option<NodeRef<T>>;
     fn get_tail(&self) -> Option<NodeRef<T>>;
@@ -48,7 +51,11 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
-    /// let list:List<T> = IndeList::empty();
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let list:List<u32> = List::empty();
+    /// assert_eq!(list.head(), None);
+    /// assert_eq!(list.size, 0);
     /// ```
     fn empty() -> Self {
         Self { size: 0, head: None, tail: None }
@@ -58,7 +65,10 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
-    /// let list:List<&str> = IndeList::new("This is a head");
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let list:List<&str> = List::new("This is a head");
+    /// assert_eq!(list.head(), Some("This is a head"));
     /// ```
     fn new(val: T) -> Self {
         Self { size: 1, 
@@ -81,6 +91,8 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
     /// let list = List::new(1);
     ///
     /// assert_eq!(list.head(), Some(1));
@@ -96,7 +108,9 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
-    /// let list = List::new(1);
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let mut list = List::new(1);
     /// list.append(2);
     ///
     /// assert_eq!(list.tail(), Some(2));
@@ -108,18 +122,25 @@ impl<T> BaseOperations<T> for List<T> {
         None
     }
 
-    /// Get the value in the specified position. Like in array start count at 0;
+    /// Get the value in the specified position. Start counting at 1.
     /// 
     /// #Example
     /// ```
-    /// let list:List<i32> = vec![6,8,10,12,14].to_list();
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let mut list:List<i32> = List::empty();
+    /// [6, 8, 10, 12, 14].into_iter().for_each(|x| list.append(x));
     /// 
     /// let value_at_position = list.get_value(2);
     /// 
     /// assert_eq!(value_at_position, Some(8));
+    /// assert_eq!(list.get_value(1), Some(6));
+    /// assert_eq!(list.get_value(0), None);
+    /// assert_eq!(list.get_value(6), None);
     /// ```
     fn get_value(&self, pos:usize) -> Option<T> where T:Clone {
-        if pos > self.size || pos == 0 { return None; }
+        if pos == 0 || pos > self.size { return None; }
+        let pos:usize = pos - 1;
 
         let Some(head) = &self.head.clone() else {
             return None;
@@ -168,17 +189,24 @@ impl<T> BaseOperations<T> for List<T> {
         None
     }
 
-    /// Find all the specified value in the list return a vector of positions
+    /// Find all the specified value in the list return a vector of positions. Start counting at 1.
+    /// 
+    /// #Example
+    /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let mut list:List<i32> = List::empty();
+    /// [4, 7, 4, 1].into_iter().for_each(|x| list.append(x));
+    ///
+    /// assert_eq!(list.find_value(4), vec![1, 3]);
+    /// assert_eq!(list.find_value(9), vec![]);
+    /// ```
     fn find_value(&self, value:T) -> Vec<usize> 
         where T: PartialEq 
     {
         let mut res:Vec<usize> = Vec::new();
 
-        let Some(head) = self.head.clone() else {
-            return res;
-        };
-
-        let mut cur = head.as_ref().borrow().next.clone();
+        let mut cur = self.head.clone();
 
         let mut count:usize = 0;
         while let Some(node) = cur {
@@ -197,18 +225,20 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
     /// let mut list = List::new(1);
     ///
     /// assert_eq!(list.head(), Some(1));
     /// assert_eq!(list.tail(), None);
-    /// assert_eq!(list.size(), 1);
+    /// assert_eq!(list.size, 1);
     /// 
     /// list.append(2);
     /// list.append(10);
     ///
     /// assert_eq!(list.head(), Some(1));
     /// assert_eq!(list.tail(), Some(10));
-    /// assert_eq!(list.size(), 3);
+    /// assert_eq!(list.size, 3);
     /// ```
     fn append(&mut self, val: T) {
         if let Some(head) = self.head.clone() {
@@ -240,6 +270,8 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
     /// let mut list = List::new(2);
     /// 
     /// list.append(4);
@@ -247,13 +279,13 @@ impl<T> BaseOperations<T> for List<T> {
     ///
     /// assert_eq!(list.head(), Some(2));
     /// assert_eq!(list.tail(), Some(6));
-    /// assert_eq!(list.size(), 3);
+    /// assert_eq!(list.size, 3);
     /// 
     /// list.detach();
     /// 
     /// assert_eq!(list.head(), Some(2));
     /// assert_eq!(list.tail(), Some(4));
-    /// assert_eq!(list.size(), 2);
+    /// assert_eq!(list.size, 2);
     /// ```
     fn detach(&mut self) -> Option<T> where T: Clone {
         if let Some(head) = self.head.clone() {
@@ -270,14 +302,14 @@ impl<T> BaseOperations<T> for List<T> {
                     tail.as_ref().borrow_mut().clone().prev.unwrap().as_ref().borrow_mut().next = None;
                 }
                 self.size -= 1;
-                return Some(tail_val)
+                Some(tail_val)
             } else { // head but no tail
                 self.head = None;
                 self.size -= 1;
-                return Some( head.as_ref().borrow().val.clone() );
+                Some( head.as_ref().borrow().val.clone() )
             }
         } else { // list is empty
-            return None
+            None
         }
     }
 
@@ -285,28 +317,30 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
     /// let mut list = List::new(10);
     /// 
     /// assert_eq!(list.head(), Some(10));
     /// assert_eq!(list.tail(), None);
-    /// assert_eq!(list.size(), 1);
+    /// assert_eq!(list.size, 1);
     /// 
     /// list.push(12);
     /// 
     /// assert_eq!(list.head(), Some(12));
     /// assert_eq!(list.tail(), Some(10));
-    /// assert_eq!(list.size(), 2);
+    /// assert_eq!(list.size, 2);
     /// ```
     fn push(&mut self, val: T) {
-        if let Some(_) = self.head.clone() {
+        if self.head.is_some() {
             let mut new_head:Node<T> = Node::new(val);
 
-            if let Some(_) = self.tail.clone() {
+            if self.tail.is_some() {
                 new_head.next = self.head.clone();
 
                 let new_head_ref:NodeRef<T> = Node::to_ref(new_head);
                 
-                self.head.as_ref().borrow().clone().unwrap().as_ref().borrow_mut().prev = Some(new_head_ref.clone());
+                self.head.as_ref().unwrap().as_ref().borrow_mut().prev = Some(new_head_ref.clone());
 
                 self.head = Some(new_head_ref);
 
@@ -315,7 +349,7 @@ impl<T> BaseOperations<T> for List<T> {
 
                 let new_head_ref:NodeRef<T> = Node::to_ref(new_head);
 
-                self.head.as_ref().borrow().clone().unwrap().as_ref().borrow_mut().prev = Some(new_head_ref.clone());
+                self.head.as_ref().unwrap().as_ref().borrow_mut().prev = Some(new_head_ref.clone());
 
                 self.tail = self.head.clone();
                 self.head = Some(new_head_ref);
@@ -331,6 +365,8 @@ impl<T> BaseOperations<T> for List<T> {
     /// 
     /// #Example
     /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
     /// let mut list:List<&str> = List::new("Head");
     /// 
     /// list.append("This is a node");
@@ -338,20 +374,20 @@ impl<T> BaseOperations<T> for List<T> {
     ///
     /// assert_eq!(list.head(), Some("Head"));
     /// assert_eq!(list.tail(), Some("This is another node"));
-    /// assert_eq!(list.size(), 3);
+    /// assert_eq!(list.size, 3);
     /// 
     /// list.pop();
     /// 
     /// assert_eq!(list.head(), Some("This is a node"));
     /// assert_eq!(list.tail(), Some("This is another node"));
-    /// assert_eq!(list.size(), 2);
+    /// assert_eq!(list.size, 2);
     /// 
     /// ```
     fn pop(&mut self) -> Option<T> where T: Clone {
         if let Some(head) = self.head.clone() {
             let head_val:T = head.as_ref().borrow().val.clone();
 
-            if let Some(_) = self.tail.clone() {
+            if self.tail.is_some() {
                 if self.size == 2 { // head and tail case
                     self.head = self.tail.clone();
                     self.tail = None;
@@ -359,99 +395,110 @@ impl<T> BaseOperations<T> for List<T> {
                 if self.size > 2 { // head tail and other nodes
                     self.head = head.as_ref().borrow().next.clone();
                 }
+                // unlink the old head both ways, the Rc cycle would leak it
+                if let Some(new_head) = &self.head {
+                    new_head.as_ref().borrow_mut().prev = None;
+                }
+                head.as_ref().borrow_mut().next = None;
 
             } else { // head but no tail
                 self.head = None;
             }
 
             self.size -= 1;
-            return Some(head_val);
+            Some(head_val)
         } 
-        else { return None; } // list is empty        
+        else { None } // list is empty        
     }
     
     /// Insert a value at the specified position. Start counting at 1.
     /// 
     /// #Example 
     /// ```
-    /// let mut list:List<i32> = vec![1,2,3,4,5].to_list();
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let mut list:List<i32> = List::empty();
+    /// [1, 2, 3, 4, 5].into_iter().for_each(|x| list.append(x));
     ///
     /// list.insert(2, 69);
+    /// list.insert(1, 0);
+    /// list.insert(8, 99);
     /// 
-    /// assert_eq!()
-    /// 
+    /// assert_eq!(list.size, 8);
+    /// assert_eq!(list.to_vec(), vec![0, 1, 69, 2, 3, 4, 5, 99]);
     /// ```
     fn insert(&mut self, pos: usize, val: T) where T: Clone {
-        if pos > self.size { return; }
-        if pos == self.size { Self::append(self, val.clone()) }
-       
-        let new_node: NodeRef<T> = Node::to_ref(Node::new(val.clone()));
-
-        if let Some(_) = self.head.clone() {
-            if let Some(_) = self.tail.clone() {
-                let new_node:NodeRef<T> = Node::to_ref(Node::new(val));
-                
-                let mut current:Option<NodeRef<T>> = self.head.clone();
+        if pos == 0 || pos > self.size + 1 { return; }
+        if pos == 1 { return Self::push(self, val); }
+        if pos == self.size + 1 { return Self::append(self, val); }
 
-                let mut i:usize = 0;
-                while let Some(node) = current {
-                    if i == pos - 1 {
-                        new_node.as_ref().borrow_mut().prev = node.as_ref().borrow().prev.clone();
-                        new_node.as_ref().borrow_mut().next = Some(node.clone());
-                        
-                        node.as_ref().borrow_mut().prev.clone().unwrap().as_ref().borrow_mut().next = Some(new_node.clone());
-                        node.as_ref().borrow_mut().prev = Some(new_node.clone());
-                        break;
-                    }
+        // 1 < pos <= size, so the list has a node before and after the new one
+        let new_node:NodeRef<T> = Node::to_ref(Node::new(val));
 
-                    i += 1;
-                    current = node.as_ref().borrow().next.clone();
-                }
+        let mut current:Option<NodeRef<T>> = self.head.clone();
+        let mut i:usize = 1;
+        while let Some(node) = current {
+            if i == pos {
+                let prev:NodeRef<T> = node.as_ref().borrow().prev.clone().unwrap();
+
+                new_node.as_ref().borrow_mut().prev = Some(prev.clone());
+                new_node.as_ref().borrow_mut().next = Some(node.clone());
 
-            } else { // Head but no tail
-                if pos == 0 { Self::push(self, val); }
-                else { Self::append(self, val) }
+                prev.as_ref().borrow_mut().next = Some(new_node.clone());
+                node.as_ref().borrow_mut().prev = Some(new_node.clone());
+                break;
             }
-        } else { // Empty list
-            self.head = Some(new_node);
+
+            i += 1;
+            current = node.as_ref().borrow().next.clone();
         }
         self.size += 1;
     }
     
     /// Delete value at the specified position. Start counting at 1.
+    /// 
+    /// #Example 
+    /// ```
+    /// use structs::list::list::{BaseOperations, List};
+    ///
+    /// let mut list:List<i32> = List::empty();
+    /// assert_eq!(list.delete(1), None);
+    ///
+    /// [1, 2, 3, 4, 5].into_iter().for_each(|x| list.append(x));
+    ///
+    /// assert_eq!(list.delete(3), Some(3));
+    /// assert_eq!(list.delete(1), Some(1));
+    /// assert_eq!(list.delete(3), Some(5));
+    /// assert_eq!(list.delete(0), None);
+    /// 
+    /// assert_eq!(list.size, 2);
+    /// assert_eq!(list.to_vec(), vec![2, 4]);
+    /// ```
     fn delete(&mut self, pos: usize) -> Option<T> where T: Clone {
-        if pos > self.size - 1 { return None; }
-        
-        if let Some(head) = self.head.clone() {
-            if let Some(_) = self.tail.clone() {
-                if pos == self.size - 1 { return Self::detach(self); }
-                if pos == 0 { return Self::pop(self); }
-                
-                let mut current:Option<NodeRef<T>> = self.head.clone();
-
-                let mut i:usize = 0;
-                while let Some(node) = current {
-                    if i == pos {
-                        node.as_ref().borrow().prev.clone().unwrap().as_ref().borrow_mut().next = node.as_ref().borrow().next.clone();
-                        node.as_ref().borrow().next.clone().unwrap().as_ref().borrow_mut().prev = node.as_ref().borrow().prev.clone();
-                        return Some(node.as_ref().borrow().val.clone());
-                    }
-                    i += 1;
-                    current = node.as_ref().borrow().next.clone();
-                }
-                self.size -= 1;
+        if pos == 0 || pos > self.size { return None; }
+        if pos == 1 { return Self::pop(self); }
+        if pos == self.size { return Self::detach(self); }
 
-                return None;
-            } else { // Head but no tail
-                let val = head.as_ref().borrow().val.clone();
-                self.head = None;
+        // 1 < pos < size, so the node has a node before and after it
+        let mut current:Option<NodeRef<T>> = self.head.clone();
+        let mut i:usize = 1;
+        while let Some(node) = current {
+            if i == pos {
+                let prev:NodeRef<T> = node.as_ref().borrow().prev.clone().unwrap();
+                let next:NodeRef<T> = node.as_ref().borrow().next.clone().unwrap();
+
+                prev.as_ref().borrow_mut().next = Some(next.clone());
+                next.as_ref().borrow_mut().prev = Some(prev);
                 self.size -= 1;
 
-                return Some(val);
+                return Some(node.as_ref().borrow().val.clone());
             }
-        } else { // Empty list
-            return None;
+
+            i += 1;
+            current = node.as_ref().borrow().next.clone();
         }
+
+        None
     }
     
     fn sort(&mut self) {
@@ -511,29 +558,25 @@ impl<T> List<T> {
             return (self, List::empty());
         };
 
-        let is_closed:bool = if let Some(_) = head.as_ref().borrow().prev {
-            true
-        } else { false };
+        let is_closed:bool = head.as_ref().borrow().prev.is_some();
 
         let mut new_list:List<T> = List::empty();
 
         let mut cur_next = Some(head.clone());
         let mut cur = Some(head.clone());
-        loop {
-            if let Some(node) = cur_next.clone() {
-                let Some(ref node_next) = node.as_ref().borrow().next else {
-                    break;
-                };
-                if Rc::ptr_eq(&head, &node_next) { break; }
-
-                let Some(ref node_ne_next) = node_next.as_ref().borrow().next else {
-                    break;
-                };
-                if Rc::ptr_eq(&head, &node_ne_next) { break; }
-
-                cur_next = Some(node_ne_next.clone());
-                cur = cur.unwrap().as_ref().borrow().next.clone();
-            } else { break; }
+        while let Some(node) = cur_next.clone() {
+            let Some(ref node_next) = node.as_ref().borrow().next else {
+                break;
+            };
+            if Rc::ptr_eq(&head, node_next) { break; }
+
+            let Some(ref node_ne_next) = node_next.as_ref().borrow().next else {
+                break;
+            };
+            if Rc::ptr_eq(&head, node_ne_next) { break; }
+
+            cur_next = Some(node_ne_next.clone());
+            cur = cur.unwrap().as_ref().borrow().next.clone();
         }
         
         new_list.tail = self.tail.clone();
@@ -592,7 +635,7 @@ pub trait FindSmallest<T> {
     fn find_smallest(&self, k:usize) -> List<T>;
 }
 
-impl<T> FindSmallest<T> for Vec<T> 
+impl<T> FindSmallest<T> for [T] 
     where T: Clone + PartialOrd<T> + std::fmt::Debug
 {
     fn find_smallest(&self, k:usize) -> List<T> {
@@ -601,9 +644,11 @@ impl<T> FindSmallest<T> for Vec<T>
         if self[1] < list.head().unwrap() {
             list.push(self[1].clone());
         } else { list.append(self[1].clone()); }
+        if list.size > k { list.detach(); }
 
         for num in self.iter().skip(2) {
-            if num > &list.tail().unwrap() { 
+            // a single value is kept as a head without a tail
+            if num > &list.tail().or_else(|| list.head()).unwrap() { 
                 if list.size >= k { continue; }
                 
                 list.append(num.clone());
@@ -630,7 +675,7 @@ impl<T> FindSmallest<T> for Vec<T>
                     }
                     cur = node.as_ref().borrow().next.clone();
                 }
-                if list.size >= k { list.detach(); }
+                if list.size > k { list.detach(); }
             }
             // println!("{}", list);
         }
@@ -645,32 +690,158 @@ impl<T> fmt::Display for List<T>
         let Some(head) = self.head.clone() else {
             return write!(f, "[]");
         };
-        if let Some(head_prev) = head.clone().as_ref().borrow().prev.clone() {
-            print!("T[{:?}] <=> ", head_prev.as_ref().borrow().val);
-        } else { print!("[{}]: None <=> ", self.size)}
 
-        let Some(_) = &self.tail else {
-            return write!(f, "None <=> [{:?}] <=> None", head.clone().as_ref().borrow().val);
+        let head_prev:Option<NodeRef<T>> = head.as_ref().borrow().prev.clone();
+        if let Some(head_prev) = &head_prev {
+            write!(f, "T[{:?}] <=> ", head_prev.as_ref().borrow().val)?;
+        } else { write!(f, "[{}]: None <=> ", self.size)?; }
+
+        write!(f, "H[{:?}]", head.as_ref().borrow().val)?;
+
+        let mut current:Option<NodeRef<T>> = head.as_ref().borrow().next.clone();
+        while let Some(node) = current {
+            if Rc::ptr_eq(&head, &node) { break; }
+
+            write!(f, " <=> [{:?}]", node.as_ref().borrow().val)?;
+            current = node.as_ref().borrow().next.clone();
+        }
+
+        if head_prev.is_none() {
+            write!(f, " <=> None")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Visualize for List<T> 
+    where T: Debug 
+{
+    /// The nodes as a chain from the head, a closed list links its tail back to the head
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("List").horizontal();
+        let Some(head) = self.head.clone() else {
+            return diagram;
         };
 
-        let mut current= head.as_ref().borrow().next.clone();
-        print!("H[{:?}] <=> ", head.as_ref().borrow().val);
-
-        loop {
-            if let Some(node) = current.clone() {
-                print!("[{:?}] <=> ", node.as_ref().borrow().val);
-                current = node.as_ref().borrow().next.clone();
-
-                let Some(current_next) = current.clone() else {
-                    println!("None");
-                    break;   
-                };  
-                if Rc::ptr_eq(&head, &current_next) { break; }
-            } else { 
-                println!("None");
+        let mut id:usize = diagram.add_root(format!("{:?}", head.as_ref().borrow().val));
+        diagram.highlight(id);
+
+        let mut current:Option<NodeRef<T>> = head.as_ref().borrow().next.clone();
+        while let Some(node) = current {
+            if Rc::ptr_eq(&head, &node) {
+                diagram.add_child(id, "↺ head");
                 break;
             }
+
+            id = diagram.add_child(id, format!("{:?}", node.as_ref().borrow().val));
+            current = node.as_ref().borrow().next.clone();
+        }
+
+        diagram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::{BaseOperations, List};
+    use crate::list::node::NodeRef;
+
+    fn list_of(vals:&[i32]) -> List<i32> {
+        let mut list:List<i32> = List::empty();
+        vals.iter().for_each(|&x| list.append(x));
+        list
+    }
+
+    fn values(list:&List<i32>) -> Vec<i32> {
+        list.clone().to_vec()
+    }
+
+    #[test]
+    fn get_value_counts_from_one() {
+        let empty:List<i32> = List::empty();
+        assert_eq!(empty.get_value(0), None);
+        assert_eq!(empty.get_value(1), None);
+
+        let list:List<i32> = list_of(&[6, 8, 10, 12, 14]);
+        assert_eq!(list.get_value(0), None);
+        assert_eq!(list.get_value(1), Some(6));
+        assert_eq!(list.get_value(3), Some(10));
+        assert_eq!(list.get_value(4), Some(12));
+        assert_eq!(list.get_value(5), Some(14));
+        assert_eq!(list.get_value(6), None);
+
+        assert_eq!(List::new(7).get_value(1), Some(7));
+    }
+
+    #[test]
+    fn insert_counts_from_one() {
+        let mut list:List<i32> = List::empty();
+        list.insert(0, 1);
+        list.insert(2, 1);
+        assert_eq!(list.size, 0);
+
+        list.insert(1, 2);
+        assert_eq!(values(&list), vec![2]);
+
+        // head, tail, middle
+        list.insert(1, 1);
+        list.insert(3, 4);
+        list.insert(3, 3);
+        assert_eq!(values(&list), vec![1, 2, 3, 4]);
+        assert_eq!(list.size, 4);
+        assert_eq!((list.head(), list.tail()), (Some(1), Some(4)));
+
+        list.insert(6, 9);
+        assert_eq!(list.size, 4);
+    }
+
+    #[test]
+    fn delete_counts_from_one() {
+        let mut list:List<i32> = List::empty();
+        assert_eq!(list.delete(0), None);
+        assert_eq!(list.delete(1), None);
+
+        let mut list:List<i32> = list_of(&[1, 2, 3, 4, 5]);
+        assert_eq!(list.delete(0), None);
+        assert_eq!(list.delete(6), None);
+
+        // middle, head, tail
+        assert_eq!(list.delete(3), Some(3));
+        assert_eq!(list.delete(1), Some(1));
+        assert_eq!(list.delete(list.size), Some(5));
+        assert_eq!(values(&list), vec![2, 4]);
+        assert_eq!(list.size, 2);
+
+        assert_eq!(list.delete(2), Some(4));
+        assert_eq!(list.delete(1), Some(2));
+        assert_eq!(list.size, 0);
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn find_value_counts_from_one() {
+        let list:List<i32> = list_of(&[4, 7, 4, 1]);
+        assert_eq!(list.find_value(4), vec![1, 3]);
+        assert_eq!(list.find_value(1), vec![4]);
+        assert!(List::<i32>::empty().find_value(4).is_empty());
+    }
+
+    #[test]
+    fn pop_unlinks_the_old_head() {
+        let mut list:List<i32> = list_of(&[1, 2, 3]);
+
+        let mut popped:Vec<NodeRef<i32>> = Vec::new();
+        while let Some(head) = list.get_head() {
+            popped.push(head);
+            list.pop();
+        }
+
+        // nothing but this test holds the nodes any more, so they are freed once it lets go
+        assert_eq!(list.size, 0);
+        for node in popped.iter() {
+            assert_eq!(Rc::strong_count(node), 1);
         }
-        write!(f, "")
     }
-}
\ No newline at end of file
+}
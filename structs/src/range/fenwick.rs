@@ -0,0 +1,134 @@
+use core::fmt;
+use std::fmt::Debug;
+
+use crate::range::ops::Numeric;
+
+/// Binary Indexed Tree for prefix sums with point updates in O(log n)
+#[derive(Clone, Debug, PartialEq)]
+pub struct FenwickTree<T> {
+    tree:Vec<T>,
+}
+
+impl<T> FenwickTree<T>
+    where T: Numeric
+{
+    /// Create a tree of `size` zeros
+    pub fn new(size:usize) -> Self {
+        Self { tree: vec![T::ZERO; size] }
+    }
+
+    /// Build the tree from the values in O(n)
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::fenwick::FenwickTree;
+    ///
+    /// let mut tree = FenwickTree::from_vec(vec![5, 1, 4, 2, 3]);
+    ///
+    /// assert_eq!(tree.query(1, 4), 7);
+    /// tree.add(2, 10);
+    /// assert_eq!(tree.query(1, 4), 17);
+    /// assert_eq!(tree.get(2), 14);
+    /// ```
+    pub fn from_vec(vals:Vec<T>) -> Self {
+        let mut tree:Vec<T> = vals;
+
+        for i in 0..tree.len() {
+            let parent:usize = i | (i + 1);
+            if parent < tree.len() {
+                let val:T = tree[i];
+                tree[parent] = tree[parent] + val;
+            }
+        }
+
+        Self { tree }
+    }
+
+    pub fn size(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Add `delta` to the value at `idx`
+    pub fn add(&mut self, mut idx:usize, delta:T) {
+        while idx < self.tree.len() {
+            self.tree[idx] = self.tree[idx] + delta;
+            idx |= idx + 1;
+        }
+    }
+
+    /// Replace the value at `idx`
+    pub fn set(&mut self, idx:usize, val:T) {
+        let cur:T = self.get(idx);
+        self.add(idx, val - cur);
+    }
+
+    /// Get the value at `idx`
+    pub fn get(&self, idx:usize) -> T {
+        self.query(idx, idx + 1)
+    }
+
+    /// Sum of the first `end` values
+    pub fn prefix(&self, mut end:usize) -> T {
+        let mut res:T = T::ZERO;
+
+        while end > 0 {
+            res = res + self.tree[end - 1];
+            end &= end - 1;
+        }
+
+        res
+    }
+
+    /// Sum of the values in `from..to`
+    pub fn query(&self, from:usize, to:usize) -> T {
+        if to <= from { return T::ZERO; }
+        self.prefix(to) - self.prefix(from)
+    }
+
+    /// Smallest `end` such that `prefix(end) >= target`, or `None` if the total is smaller.
+    /// All values must be non-negative
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::fenwick::FenwickTree;
+    ///
+    /// let tree = FenwickTree::from_vec(vec![2, 0, 3, 1]);
+    ///
+    /// assert_eq!(tree.lower_bound(0), Some(0));
+    /// assert_eq!(tree.lower_bound(3), Some(3));
+    /// assert_eq!(tree.lower_bound(6), Some(4));
+    /// assert_eq!(tree.lower_bound(7), None);
+    /// ```
+    pub fn lower_bound(&self, target:T) -> Option<usize> {
+        if target <= T::ZERO { return Some(0); }
+
+        let mut pos:usize = 0;
+        let mut rest:T = target;
+        let mut step:usize = if self.tree.is_empty() { 0 } else { 1 << self.tree.len().ilog2() };
+
+        while step > 0 {
+            let next:usize = pos + step;
+            if next <= self.tree.len() && self.tree[next - 1] < rest {
+                pos = next;
+                rest = rest - self.tree[next - 1];
+            }
+            step >>= 1;
+        }
+
+        if pos == self.tree.len() { return None; }
+        Some(pos + 1)
+    }
+
+    /// Get the original values
+    pub fn to_vec(&self) -> Vec<T> {
+        (0..self.tree.len()).map(|i| self.get(i)).collect()
+    }
+}
+
+impl<T> fmt::Display for FenwickTree<T>
+    where T: Numeric + Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.to_vec())
+    }
+}
@@ -0,0 +1,118 @@
+use std::ops::{Add, Mul, Sub};
+
+/// Numbers the range structures can work with
+pub trait Numeric: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO:Self;
+    const MIN:Self;
+    const MAX:Self;
+
+    fn from_usize(n:usize) -> Self;
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            const ZERO:Self = 0 as $t;
+            const MIN:Self = <$t>::MIN;
+            const MAX:Self = <$t>::MAX;
+
+            fn from_usize(n:usize) -> Self { n as $t }
+        }
+    )*};
+}
+
+impl_numeric!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Numeric for f32 {
+    const ZERO:Self = 0.0;
+    const MIN:Self = f32::NEG_INFINITY;
+    const MAX:Self = f32::INFINITY;
+
+    fn from_usize(n:usize) -> Self { n as f32 }
+}
+
+impl Numeric for f64 {
+    const ZERO:Self = 0.0;
+    const MIN:Self = f64::NEG_INFINITY;
+    const MAX:Self = f64::INFINITY;
+
+    fn from_usize(n:usize) -> Self { n as f64 }
+}
+
+/// Associative operation with an identity element
+pub trait Monoid<T> {
+    fn identity() -> T;
+    fn combine(a:&T, b:&T) -> T;
+}
+
+/// Monoid whose values can be updated lazily over a whole range at once
+pub trait LazyMonoid<T>: Monoid<T> {
+    type Update: Clone;
+
+    /// The update that changes nothing
+    fn no_update() -> Self::Update;
+
+    /// Apply `upd` to the combined value `val` of `len` elements
+    fn apply(upd:&Self::Update, val:&T, len:usize) -> T;
+
+    /// Update equivalent to applying `older` then `newer`
+    fn compose(newer:&Self::Update, older:&Self::Update) -> Self::Update;
+}
+
+/// Monoid where `combine(a, a) == a`, so overlapping ranges can be combined
+pub trait Idempotent<T>: Monoid<T> {}
+
+/// Sum with range add
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sum;
+
+/// Minimum with range add
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Min;
+
+/// Maximum with range add
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Max;
+
+impl<T: Numeric> Monoid<T> for Sum {
+    fn identity() -> T { T::ZERO }
+    fn combine(a:&T, b:&T) -> T { *a + *b }
+}
+
+impl<T: Numeric> LazyMonoid<T> for Sum {
+    type Update = T;
+
+    fn no_update() -> T { T::ZERO }
+    fn apply(upd:&T, val:&T, len:usize) -> T { *val + *upd * T::from_usize(len) }
+    fn compose(newer:&T, older:&T) -> T { *newer + *older }
+}
+
+impl<T: Numeric> Monoid<T> for Min {
+    fn identity() -> T { T::MAX }
+    fn combine(a:&T, b:&T) -> T { if b < a { *b } else { *a } }
+}
+
+impl<T: Numeric> LazyMonoid<T> for Min {
+    type Update = T;
+
+    fn no_update() -> T { T::ZERO }
+    fn apply(upd:&T, val:&T, _len:usize) -> T { *val + *upd }
+    fn compose(newer:&T, older:&T) -> T { *newer + *older }
+}
+
+impl<T: Numeric> Idempotent<T> for Min {}
+
+impl<T: Numeric> Monoid<T> for Max {
+    fn identity() -> T { T::MIN }
+    fn combine(a:&T, b:&T) -> T { if b > a { *b } else { *a } }
+}
+
+impl<T: Numeric> LazyMonoid<T> for Max {
+    type Update = T;
+
+    fn no_update() -> T { T::ZERO }
+    fn apply(upd:&T, val:&T, _len:usize) -> T { *val + *upd }
+    fn compose(newer:&T, older:&T) -> T { *newer + *older }
+}
+
+impl<T: Numeric> Idempotent<T> for Max {}
@@ -0,0 +1,287 @@
+use core::fmt;
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{range::ops::LazyMonoid, viz::{Diagram, Visualize}};
+
+/// Segment tree over a monoid `Op` with lazy range updates.
+/// Queries and updates take O(log n)
+pub struct SegmentTree<T, Op>
+    where Op: LazyMonoid<T>
+{
+    size:usize,
+    vals:Vec<T>,
+    lazy:Vec<Op::Update>,
+    op:PhantomData<Op>,
+}
+
+impl<T, Op> SegmentTree<T, Op>
+    where T: Clone, Op: LazyMonoid<T>
+{
+    /// Create a tree of `size` copies of `val`
+    pub fn new(size:usize, val:T) -> Self {
+        Self::from_vec(vec![val; size])
+    }
+
+    /// Build the tree from the values in O(n)
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::{segment::SegmentTree, ops::{Sum, Min}};
+    ///
+    /// let mut sums:SegmentTree<i64, Sum> = SegmentTree::from_vec(vec![5, 1, 4, 2, 3]);
+    /// sums.update(1, 4, 10);
+    ///
+    /// assert_eq!(sums.query(0, 5), 45);
+    /// assert_eq!(sums.get(3), 12);
+    ///
+    /// let mut mins:SegmentTree<i64, Min> = SegmentTree::from_vec(vec![5, 1, 4, 2, 3]);
+    /// mins.update(0, 2, -3);
+    ///
+    /// assert_eq!(mins.query(0, 5), -2);
+    /// assert_eq!(mins.query(2, 5), 2);
+    /// ```
+    pub fn from_vec(vals:Vec<T>) -> Self {
+        let size:usize = vals.len();
+        let nodes:usize = if size == 0 { 0 } else { 4 * size };
+
+        let mut tree:Self = Self {
+            size,
+            vals: vec![Op::identity(); nodes],
+            lazy: vec![Op::no_update(); nodes],
+            op: PhantomData
+        };
+        if size > 0 {
+            tree.build(&vals, 1, 0, size);
+        }
+
+        tree
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the value at `idx`
+    pub fn get(&mut self, idx:usize) -> T {
+        self.query(idx, idx + 1)
+    }
+
+    /// Replace the value at `idx`
+    pub fn set(&mut self, idx:usize, val:T) {
+        assert!(idx < self.size, "index {} out of bounds for size {}", idx, self.size);
+        self.set_rec(1, 0, self.size, idx, val);
+    }
+
+    /// Combine the values in `from..to`
+    pub fn query(&mut self, from:usize, to:usize) -> T {
+        let to:usize = to.min(self.size);
+        if from >= to { return Op::identity(); }
+
+        self.query_rec(1, 0, self.size, from, to)
+    }
+
+    /// Apply `upd` to every value in `from..to`
+    pub fn update(&mut self, from:usize, to:usize, upd:Op::Update) {
+        let to:usize = to.min(self.size);
+        if from >= to { return; }
+
+        self.update_rec(1, 0, self.size, from, to, &upd);
+    }
+
+    /// Largest `to` such that `pred(query(from, to))` holds.
+    /// `pred` must hold for the identity and be monotone (true then false) as `to` grows
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::{segment::SegmentTree, ops::Sum};
+    ///
+    /// let mut tree:SegmentTree<i32, Sum> = SegmentTree::from_vec(vec![3, 1, 4, 1, 5]);
+    ///
+    /// // longest run starting at 1 with sum <= 6
+    /// assert_eq!(tree.max_right(1, |sum| *sum <= 6), 4);
+    /// assert_eq!(tree.max_right(0, |_| true), 5);
+    /// ```
+    pub fn max_right<F>(&mut self, from:usize, pred:F) -> usize
+        where F: Fn(&T) -> bool
+    {
+        if from >= self.size { return self.size; }
+
+        let mut acc:T = Op::identity();
+        self.max_right_rec(1, 0, self.size, from, &pred, &mut acc)
+    }
+
+    /// Smallest `from` such that `pred(query(from, to))` holds.
+    /// `pred` must hold for the identity and be monotone (true then false) as `from` shrinks
+    pub fn min_left<F>(&mut self, to:usize, pred:F) -> usize
+        where F: Fn(&T) -> bool
+    {
+        let to:usize = to.min(self.size);
+        if to == 0 { return 0; }
+
+        let mut acc:T = Op::identity();
+        self.min_left_rec(1, 0, self.size, to, &pred, &mut acc)
+    }
+
+    /// Get all the values
+    pub fn to_vec(&mut self) -> Vec<T> {
+        (0..self.size).map(|i| self.get(i)).collect()
+    }
+
+    fn build(&mut self, vals:&[T], node:usize, lo:usize, hi:usize) {
+        if hi - lo == 1 {
+            self.vals[node] = vals[lo].clone();
+            return;
+        }
+
+        let mid:usize = (lo + hi) / 2;
+        self.build(vals, 2 * node, lo, mid);
+        self.build(vals, 2 * node + 1, mid, hi);
+        self.pull(node);
+    }
+
+    fn pull(&mut self, node:usize) {
+        self.vals[node] = Op::combine(&self.vals[2 * node], &self.vals[2 * node + 1]);
+    }
+
+    fn apply_node(&mut self, node:usize, len:usize, upd:&Op::Update) {
+        self.vals[node] = Op::apply(upd, &self.vals[node], len);
+        if len > 1 {
+            self.lazy[node] = Op::compose(upd, &self.lazy[node]);
+        }
+    }
+
+    fn push(&mut self, node:usize, lo:usize, hi:usize) {
+        let upd:Op::Update = std::mem::replace(&mut self.lazy[node], Op::no_update());
+        let mid:usize = (lo + hi) / 2;
+
+        self.apply_node(2 * node, mid - lo, &upd);
+        self.apply_node(2 * node + 1, hi - mid, &upd);
+    }
+
+    fn set_rec(&mut self, node:usize, lo:usize, hi:usize, idx:usize, val:T) {
+        if hi - lo == 1 {
+            self.vals[node] = val;
+            return;
+        }
+        self.push(node, lo, hi);
+
+        let mid:usize = (lo + hi) / 2;
+        if idx < mid {
+            self.set_rec(2 * node, lo, mid, idx, val);
+        } else {
+            self.set_rec(2 * node + 1, mid, hi, idx, val);
+        }
+        self.pull(node);
+    }
+
+    fn query_rec(&mut self, node:usize, lo:usize, hi:usize, from:usize, to:usize) -> T {
+        if from <= lo && hi <= to { return self.vals[node].clone(); }
+        self.push(node, lo, hi);
+
+        let mid:usize = (lo + hi) / 2;
+        if to <= mid { return self.query_rec(2 * node, lo, mid, from, to); }
+        if from >= mid { return self.query_rec(2 * node + 1, mid, hi, from, to); }
+
+        let left:T = self.query_rec(2 * node, lo, mid, from, to);
+        let right:T = self.query_rec(2 * node + 1, mid, hi, from, to);
+        Op::combine(&left, &right)
+    }
+
+    fn update_rec(&mut self, node:usize, lo:usize, hi:usize, from:usize, to:usize, upd:&Op::Update) {
+        if to <= lo || hi <= from { return; }
+        if from <= lo && hi <= to {
+            self.apply_node(node, hi - lo, upd);
+            return;
+        }
+        self.push(node, lo, hi);
+
+        let mid:usize = (lo + hi) / 2;
+        self.update_rec(2 * node, lo, mid, from, to, upd);
+        self.update_rec(2 * node + 1, mid, hi, from, to, upd);
+        self.pull(node);
+    }
+
+    fn max_right_rec<F>(&mut self, node:usize, lo:usize, hi:usize, from:usize, pred:&F, acc:&mut T) -> usize
+        where F: Fn(&T) -> bool
+    {
+        if hi <= from { return hi; }
+        if from <= lo {
+            let next:T = Op::combine(acc, &self.vals[node]);
+            if pred(&next) {
+                *acc = next;
+                return hi;
+            }
+            if hi - lo == 1 { return lo; }
+        }
+        self.push(node, lo, hi);
+
+        let mid:usize = (lo + hi) / 2;
+        let res:usize = self.max_right_rec(2 * node, lo, mid, from, pred, acc);
+        if res < mid { return res; }
+        self.max_right_rec(2 * node + 1, mid, hi, from, pred, acc)
+    }
+
+    fn min_left_rec<F>(&mut self, node:usize, lo:usize, hi:usize, to:usize, pred:&F, acc:&mut T) -> usize
+        where F: Fn(&T) -> bool
+    {
+        if lo >= to { return lo; }
+        if hi <= to {
+            let next:T = Op::combine(&self.vals[node], acc);
+            if pred(&next) {
+                *acc = next;
+                return lo;
+            }
+            if hi - lo == 1 { return hi; }
+        }
+        self.push(node, lo, hi);
+
+        let mid:usize = (lo + hi) / 2;
+        let res:usize = self.min_left_rec(2 * node + 1, mid, hi, to, pred, acc);
+        if res > mid { return res; }
+        self.min_left_rec(2 * node, lo, mid, to, pred, acc)
+    }
+}
+
+impl<T, Op> Clone for SegmentTree<T, Op>
+    where T: Clone, Op: LazyMonoid<T>
+{
+    fn clone(&self) -> Self {
+        Self {
+            size: self.size,
+            vals: self.vals.clone(),
+            lazy: self.lazy.clone(),
+            op: PhantomData
+        }
+    }
+}
+
+impl<T, Op> Visualize for SegmentTree<T, Op>
+    where T: Debug, Op: LazyMonoid<T>, Op::Update: Debug
+{
+    /// Every node with the range it covers, its value and the update it still owes its children
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("SegmentTree");
+        if self.size == 0 { return diagram; }
+
+        let label = |(node, lo, hi):(usize, usize, usize)| {
+            if hi - lo == 1 {
+                format!("[{}] {:?}", lo, self.vals[node])
+            } else {
+                format!("[{}, {}) {:?} lazy {:?}", lo, hi, self.vals[node], self.lazy[node])
+            }
+        };
+        diagram.add_tree((1, 0, self.size), label, |(node, lo, hi)| {
+            let mid:usize = (lo + hi) / 2;
+            if hi - lo == 1 { Vec::new() } else { vec![Some((2 * node, lo, mid)), Some((2 * node + 1, mid, hi))] }
+        });
+        diagram
+    }
+}
+
+impl<T, Op> fmt::Display for SegmentTree<T, Op>
+    where T: Clone + Debug, Op: LazyMonoid<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.clone().to_vec())
+    }
+}
@@ -0,0 +1,196 @@
+use std::marker::PhantomData;
+
+use crate::range::ops::{Idempotent, Monoid};
+
+/// Static range queries in O(1) after O(n log n) preprocessing, for idempotent operations
+pub struct SparseTable<T, Op>
+    where Op: Idempotent<T>
+{
+    // levels[k][i] combines the values in i..i + 2^k
+    levels:Vec<Vec<T>>,
+    op:PhantomData<Op>,
+}
+
+impl<T, Op> SparseTable<T, Op>
+    where T: Clone, Op: Idempotent<T>
+{
+    /// Build the table from the values
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::{sparse::SparseTable, ops::{Min, Max}};
+    ///
+    /// let mins:SparseTable<u32, Min> = SparseTable::new(vec![7, 2, 9, 4, 1, 8]);
+    /// let maxs:SparseTable<u32, Max> = SparseTable::new(vec![7, 2, 9, 4, 1, 8]);
+    ///
+    /// assert_eq!(mins.query(0, 4), 2);
+    /// assert_eq!(mins.query(3, 6), 1);
+    /// assert_eq!(maxs.query(3, 6), 8);
+    /// ```
+    pub fn new(vals:Vec<T>) -> Self {
+        let size:usize = vals.len();
+        let mut levels:Vec<Vec<T>> = vec![vals];
+
+        let mut width:usize = 1;
+        while 2 * width <= size {
+            let prev:&Vec<T> = &levels[levels.len() - 1];
+            let level:Vec<T> = (0..=size - 2 * width)
+                .map(|i| Op::combine(&prev[i], &prev[i + width]))
+                .collect();
+
+            levels.push(level);
+            width *= 2;
+        }
+
+        Self { levels, op: PhantomData }
+    }
+
+    pub fn size(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Combine the values in `from..to`
+    pub fn query(&self, from:usize, to:usize) -> T {
+        let to:usize = to.min(self.size());
+        if from >= to { return Op::identity(); }
+
+        let k:usize = (to - from).ilog2() as usize;
+        Op::combine(&self.levels[k][from], &self.levels[k][to - (1 << k)])
+    }
+
+    /// Get the value at `idx`
+    pub fn get(&self, idx:usize) -> &T {
+        &self.levels[0][idx]
+    }
+}
+
+impl<T, Op> Clone for SparseTable<T, Op>
+    where T: Clone, Op: Idempotent<T>
+{
+    fn clone(&self) -> Self {
+        Self { levels: self.levels.clone(), op: PhantomData }
+    }
+}
+
+/// Minimum over (depth, node) pairs, used for the Euler tour
+struct MinDepth;
+
+impl Monoid<(usize, usize)> for MinDepth {
+    fn identity() -> (usize, usize) { (usize::MAX, usize::MAX) }
+    fn combine(a:&(usize, usize), b:&(usize, usize)) -> (usize, usize) { *a.min(b) }
+}
+
+impl Idempotent<(usize, usize)> for MinDepth {}
+
+/// Lowest common ancestor of a rooted tree with nodes `0..size`, answered in O(1)
+/// through a sparse table over the Euler tour
+pub struct Lca {
+    root:usize,
+    depth:Vec<usize>,
+    first:Vec<usize>,
+    tour:SparseTable<(usize, usize), MinDepth>,
+}
+
+impl Lca {
+    /// Build from an adjacency list. Edges may be listed in one or both directions.
+    /// Nodes not reachable from `root` have no ancestor in common with it. Panics if `root`
+    /// is not a node of `adj`, so there must be at least one
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::sparse::Lca;
+    ///
+    /// //      0
+    /// //     / \
+    /// //    1   2
+    /// //   / \   \
+    /// //  3   4   5
+    /// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0, 5], vec![1], vec![1], vec![2]];
+    /// let lca = Lca::new(&adj, 0);
+    ///
+    /// assert_eq!(lca.lca(3, 4), Some(1));
+    /// assert_eq!(lca.lca(3, 5), Some(0));
+    /// assert_eq!(lca.lca(2, 5), Some(2));
+    /// assert_eq!(lca.distance(4, 5), Some(4));
+    /// ```
+    pub fn new(adj:&[Vec<usize>], root:usize) -> Self {
+        let size:usize = adj.len();
+        assert!(root < size, "root {} out of bounds for {} nodes", root, size);
+
+        let mut depth:Vec<usize> = vec![usize::MAX; size];
+        let mut first:Vec<usize> = vec![usize::MAX; size];
+        let mut euler:Vec<(usize, usize)> = Vec::with_capacity(2 * size);
+
+        // (node, index of the next child to visit)
+        let mut stack:Vec<(usize, usize)> = vec![(root, 0)];
+        depth[root] = 0;
+
+        while let Some((node, next)) = stack.pop() {
+            if next == 0 {
+                first[node] = euler.len();
+            }
+            euler.push((depth[node], node));
+
+            let child:Option<usize> = adj[node][next..].iter()
+                .position(|&c| depth[c] == usize::MAX)
+                .map(|p| next + p);
+
+            if let Some(c) = child {
+                let child_node:usize = adj[node][c];
+                stack.push((node, c + 1));
+
+                depth[child_node] = depth[node] + 1;
+                stack.push((child_node, 0));
+            }
+        }
+
+        Self { root, depth, first, tour: SparseTable::new(euler) }
+    }
+
+    /// Build from a parent array where the root is the only node without a parent.
+    /// Panics if `parents` is empty
+    pub fn from_parents(parents:&[Option<usize>]) -> Self {
+        assert!(!parents.is_empty(), "a tree needs at least one node");
+
+        let mut adj:Vec<Vec<usize>> = vec![Vec::new(); parents.len()];
+        let mut root:usize = 0;
+
+        for (node, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(p) => adj[*p].push(node),
+                None => root = node
+            }
+        }
+
+        Self::new(&adj, root)
+    }
+
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Depth of `node`, `None` if it is not connected to the root
+    pub fn depth(&self, node:usize) -> Option<usize> {
+        if self.depth[node] == usize::MAX { return None; }
+        Some(self.depth[node])
+    }
+
+    pub fn lca(&self, a:usize, b:usize) -> Option<usize> {
+        let (fa, fb) = (self.first[a], self.first[b]);
+        if fa == usize::MAX || fb == usize::MAX { return None; }
+
+        let (from, to) = if fa <= fb { (fa, fb) } else { (fb, fa) };
+        Some(self.tour.query(from, to + 1).1)
+    }
+
+    /// Amount of edges on the path between `a` and `b`
+    pub fn distance(&self, a:usize, b:usize) -> Option<usize> {
+        let anc:usize = self.lca(a, b)?;
+        Some(self.depth[a] + self.depth[b] - 2 * self.depth[anc])
+    }
+
+    /// Check if `anc` lies on the path from `node` to the root
+    pub fn is_ancestor(&self, anc:usize, node:usize) -> bool {
+        self.lca(anc, node) == Some(anc)
+    }
+}
@@ -0,0 +1,259 @@
+use std::fmt::Write;
+
+use colored::Colorize;
+
+/// Drawing of a structure as a forest of labelled nodes. Every node keeps an ordered list
+/// of child slots, empty slots hold the place of missing children in binary trees
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Diagram {
+    name:String,
+    labels:Vec<String>,
+    children:Vec<Vec<Option<usize>>>,
+    highlighted:Vec<bool>,
+    roots:Vec<usize>,
+    // lay the DOT graph out from left to right, better for chains
+    horizontal:bool,
+}
+
+impl Diagram {
+    pub fn new(name:&str) -> Self {
+        Self { name: name.to_string(), ..Self::default() }
+    }
+
+    /// Draw the DOT graph from left to right instead of top to bottom
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Amount of nodes
+    pub fn size(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    pub fn label(&self, node:usize) -> &str {
+        &self.labels[node]
+    }
+
+    pub fn children(&self, node:usize) -> &[Option<usize>] {
+        &self.children[node]
+    }
+
+    fn add_node(&mut self, label:String) -> usize {
+        self.labels.push(label);
+        self.children.push(Vec::new());
+        self.highlighted.push(false);
+        self.labels.len() - 1
+    }
+
+    /// Add a node without parent and return its id
+    pub fn add_root(&mut self, label:impl Into<String>) -> usize {
+        let id:usize = self.add_node(label.into());
+        self.roots.push(id);
+        id
+    }
+
+    /// Add a node in the next child slot of `parent` and return its id
+    pub fn add_child(&mut self, parent:usize, label:impl Into<String>) -> usize {
+        let id:usize = self.add_node(label.into());
+        self.children[parent].push(Some(id));
+        id
+    }
+
+    /// Leave the next child slot of `parent` empty
+    pub fn add_empty(&mut self, parent:usize) {
+        self.children[parent].push(None);
+    }
+
+    /// Mark a node to stand out when rendered
+    pub fn highlight(&mut self, node:usize) {
+        self.highlighted[node] = true;
+    }
+
+    /// Add `root` and everything below it, walking the structure with `children`,
+    /// and return the id of the root. `None` children become empty slots
+    ///
+    /// #Example
+    /// ```
+    /// use structs::viz::Diagram;
+    ///
+    /// // implicit binary tree stored in a slice
+    /// let vals:Vec<i32> = vec![9, 5, 7, 1];
+    ///
+    /// let mut diagram:Diagram = Diagram::new("tree");
+    /// diagram.add_tree(0, |i| vals[i].to_string(), |i| {
+    ///     [2 * i + 1, 2 * i + 2].map(|c| (c < vals.len()).then_some(c))
+    /// });
+    ///
+    /// assert_eq!(diagram.to_ascii(), "9\n├── 5\n│   ├── 1\n│   └── ∅\n└── 7\n");
+    /// ```
+    pub fn add_tree<N, L, C, I>(&mut self, root:N, label:L, children:C) -> usize
+        where N: Copy, L: Fn(N) -> String, C: Fn(N) -> I, I: IntoIterator<Item = Option<N>>
+    {
+        let root_id:usize = self.add_root(label(root));
+
+        // iterative so that degenerate trees and long chains do not overflow the stack
+        let mut stack:Vec<(N, usize)> = vec![(root, root_id)];
+        while let Some((node, id)) = stack.pop() {
+            for child in children(node) {
+                match child {
+                    Some(child) => {
+                        let child_id:usize = self.add_child(id, label(child));
+                        stack.push((child, child_id));
+                    },
+                    None => self.add_empty(id)
+                }
+            }
+        }
+
+        root_id
+    }
+
+    /// Render as an indented ASCII tree
+    pub fn to_ascii(&self) -> String {
+        self.render(false)
+    }
+
+    /// Render as an indented ASCII tree with terminal colours, highlighted nodes in yellow
+    pub fn to_colored(&self) -> String {
+        self.render(true)
+    }
+
+    fn render(&self, color:bool) -> String {
+        let mut out:String = String::new();
+
+        for &root in self.roots.iter() {
+            // (slot, indentation, whether it is the last of its siblings, depth)
+            let mut stack:Vec<(Option<usize>, String, bool, usize)> = vec![(Some(root), String::new(), true, 0)];
+
+            while let Some((slot, prefix, last, depth)) = stack.pop() {
+                let branch:&str = match (depth, last) {
+                    (0, _) => "",
+                    (_, true) => "└── ",
+                    (_, false) => "├── "
+                };
+                let lead:String = format!("{}{}", prefix, branch);
+
+                let text:String = match (slot, color) {
+                    (None, false) => "∅".to_string(),
+                    (None, true) => "∅".red().dimmed().to_string(),
+                    (Some(id), false) => self.labels[id].clone(),
+                    (Some(id), true) if self.highlighted[id] => self.labels[id].yellow().bold().to_string(),
+                    (Some(id), true) if depth == 0 => self.labels[id].cyan().bold().to_string(),
+                    (Some(id), true) => self.labels[id].green().to_string()
+                };
+
+                if color {
+                    let _ = writeln!(out, "{}{}", lead.dimmed(), text);
+                } else {
+                    let _ = writeln!(out, "{}{}", lead, text);
+                }
+
+                let Some(id) = slot else { continue; };
+                // leaves keep their empty slots to themselves
+                if self.children[id].iter().all(Option::is_none) { continue; }
+
+                let child_prefix:String = match (depth, last) {
+                    (0, _) => String::new(),
+                    (_, true) => format!("{}    ", prefix),
+                    (_, false) => format!("{}│   ", prefix)
+                };
+                let count:usize = self.children[id].len();
+                for (i, child) in self.children[id].iter().enumerate().rev() {
+                    stack.push((*child, child_prefix.clone(), i + 1 == count, depth + 1));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Export as a Graphviz DOT digraph, render it with `dot -Tsvg`
+    ///
+    /// #Example
+    /// ```
+    /// use structs::viz::Diagram;
+    ///
+    /// let mut diagram:Diagram = Diagram::new("pair");
+    /// let root:usize = diagram.add_root("a");
+    /// diagram.add_child(root, "b \"quoted\"");
+    /// diagram.highlight(root);
+    ///
+    /// let dot:String = diagram.to_dot();
+    /// assert!(dot.starts_with("digraph \"pair\" {"));
+    /// assert!(dot.contains("n0 [label=\"a\", style=filled, fillcolor=gold];"));
+    /// assert!(dot.contains("n1 [label=\"b \\\"quoted\\\"\"];"));
+    /// assert!(dot.contains("n0 -> n1;"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut out:String = String::new();
+
+        let _ = writeln!(out, "digraph \"{}\" {{", escape(&self.name));
+        if self.horizontal {
+            let _ = writeln!(out, "    rankdir=LR;");
+        }
+        let _ = writeln!(out, "    node [fontname=\"monospace\"];");
+
+        for (id, label) in self.labels.iter().enumerate() {
+            if self.highlighted[id] {
+                let _ = writeln!(out, "    n{} [label=\"{}\", style=filled, fillcolor=gold];", id, escape(label));
+            } else {
+                let _ = writeln!(out, "    n{} [label=\"{}\"];", id, escape(label));
+            }
+        }
+
+        let mut empty:usize = 0;
+        for (id, slots) in self.children.iter().enumerate() {
+            if slots.iter().all(Option::is_none) { continue; }
+
+            for slot in slots {
+                match slot {
+                    Some(child) => {
+                        let _ = writeln!(out, "    n{} -> n{};", id, child);
+                    },
+                    None => {
+                        // invisible placeholder keeps left and right children apart
+                        let _ = writeln!(out, "    e{} [shape=point, style=invis];", empty);
+                        let _ = writeln!(out, "    n{} -> e{} [style=invis];", id, empty);
+                        empty += 1;
+                    }
+                }
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn escape(label:&str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Structures that can be drawn as a `Diagram`
+pub trait Visualize {
+    fn diagram(&self) -> Diagram;
+
+    /// Indented ASCII tree
+    fn to_ascii(&self) -> String {
+        self.diagram().to_ascii()
+    }
+
+    /// Indented ASCII tree with terminal colours
+    fn to_colored(&self) -> String {
+        self.diagram().to_colored()
+    }
+
+    /// Graphviz DOT digraph
+    fn to_dot(&self) -> String {
+        self.diagram().to_dot()
+    }
+}
@@ -0,0 +1,413 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Bound, RangeBounds}
+};
+
+use structs::viz::{Diagram, Visualize};
+
+use crate::avl::node::{self, Link, Node};
+
+/// Ordered map backed by an AVL tree. Every operation is O(log n)
+#[derive(Clone, Debug)]
+pub struct AvlMap<K, V> {
+    root:Link<K, V>,
+    size:usize,
+}
+
+impl<K, V> Default for AvlMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> AvlMap<K, V> {
+    pub fn new() -> Self {
+        Self { root: None, size: 0 }
+    }
+
+    /// Amount of entries
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn height(&self) -> usize {
+        node::height(&self.root)
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.size = 0;
+    }
+
+    pub fn root(&self) -> Option<&Node<K, V>> {
+        self.root.as_deref()
+    }
+
+    /// Entry with the smallest key
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut cur:&Node<K, V> = self.root.as_deref()?;
+        while let Some(left) = cur.left.as_deref() {
+            cur = left;
+        }
+        Some((&cur.key, &cur.val))
+    }
+
+    /// Entry with the largest key
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut cur:&Node<K, V> = self.root.as_deref()?;
+        while let Some(right) = cur.right.as_deref() {
+            cur = right;
+        }
+        Some((&cur.key, &cur.val))
+    }
+
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let (root, min) = node::remove_min(self.root.take()?);
+        self.root = root;
+        self.size -= 1;
+
+        Some((min.key, min.val))
+    }
+
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let (root, max) = node::remove_max(self.root.take()?);
+        self.root = root;
+        self.size -= 1;
+
+        Some((max.key, max.val))
+    }
+
+    /// Iterate over the entries in key order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter:Iter<'_, K, V> = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+impl<K, V> AvlMap<K, V>
+    where K: Ord
+{
+    /// Insert a value, returning the previous value of the key
+    ///
+    /// #Example
+    /// ```
+    /// use trees::avl::map::AvlMap;
+    ///
+    /// let mut map:AvlMap<u32, &str> = AvlMap::new();
+    /// map.insert(20, "twenty");
+    /// map.insert(10, "ten");
+    /// map.insert(30, "thirty");
+    ///
+    /// assert_eq!(map.insert(10, "TEN"), Some("ten"));
+    /// assert_eq!(map.get(&10), Some(&"TEN"));
+    /// assert_eq!(map.keys().collect::<Vec<&u32>>(), vec![&10, &20, &30]);
+    /// assert_eq!(map.remove(&20), Some("twenty"));
+    /// assert_eq!(map.size(), 2);
+    /// ```
+    pub fn insert(&mut self, key:K, val:V) -> Option<V> {
+        let (root, old) = node::insert(self.root.take(), key, val);
+        self.root = Some(root);
+
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    pub fn remove<Q>(&mut self, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    pub fn remove_entry<Q>(&mut self, key:&Q) -> Option<(K, V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let (root, removed) = node::remove(self.root.take(), key);
+        self.root = root;
+
+        if removed.is_some() {
+            self.size -= 1;
+        }
+        removed
+    }
+
+    pub fn get<Q>(&self, key:&Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let mut cur:Option<&Node<K, V>> = self.root.as_deref();
+
+        while let Some(node) = cur {
+            match key.cmp(node.key.borrow()) {
+                Ordering::Equal => return Some(&node.val),
+                Ordering::Less => cur = node.left.as_deref(),
+                Ordering::Greater => cur = node.right.as_deref()
+            }
+        }
+
+        None
+    }
+
+    pub fn get_mut<Q>(&mut self, key:&Q) -> Option<&mut V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let mut cur:Option<&mut Box<Node<K, V>>> = self.root.as_mut();
+
+        while let Some(node) = cur {
+            match key.cmp(node.key.borrow()) {
+                Ordering::Equal => return Some(&mut node.val),
+                Ordering::Less => cur = node.left.as_mut(),
+                Ordering::Greater => cur = node.right.as_mut()
+            }
+        }
+
+        None
+    }
+
+    pub fn contains_key<Q>(&self, key:&Q) -> bool
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.get(key).is_some()
+    }
+
+    /// Entry with the largest key less than or equal to `key`
+    ///
+    /// #Example
+    /// ```
+    /// use trees::avl::map::AvlMap;
+    ///
+    /// let map:AvlMap<i32, ()> = [10, 20, 30].into_iter().map(|k| (k, ())).collect();
+    ///
+    /// assert_eq!(map.floor(&25).map(|(k, _)| *k), Some(20));
+    /// assert_eq!(map.floor(&20).map(|(k, _)| *k), Some(20));
+    /// assert_eq!(map.ceiling(&25).map(|(k, _)| *k), Some(30));
+    /// assert_eq!(map.ceiling(&31), None);
+    /// ```
+    pub fn floor<Q>(&self, key:&Q) -> Option<(&K, &V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.bound(key, true, true)
+    }
+
+    /// Entry with the smallest key greater than or equal to `key`
+    pub fn ceiling<Q>(&self, key:&Q) -> Option<(&K, &V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.bound(key, false, true)
+    }
+
+    /// Entry with the largest key strictly less than `key`
+    pub fn lower<Q>(&self, key:&Q) -> Option<(&K, &V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.bound(key, true, false)
+    }
+
+    /// Entry with the smallest key strictly greater than `key`
+    pub fn higher<Q>(&self, key:&Q) -> Option<(&K, &V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.bound(key, false, false)
+    }
+
+    /// Iterate in key order over the entries with a key inside `range`
+    ///
+    /// #Example
+    /// ```
+    /// use trees::avl::map::AvlMap;
+    ///
+    /// let map:AvlMap<i32, char> = (0..10).zip('a'..='j').collect();
+    ///
+    /// let vals:String = map.range(3..6).map(|(_, v)| *v).collect();
+    /// assert_eq!(vals, "def");
+    /// assert_eq!(map.range(8..).count(), 2);
+    /// ```
+    pub fn range<R>(&self, range:R) -> Range<'_, K, V>
+        where R: RangeBounds<K>, K: Clone
+    {
+        let mut stack:Vec<&Node<K, V>> = Vec::new();
+        let mut cur:Option<&Node<K, V>> = self.root.as_deref();
+
+        while let Some(node) = cur {
+            let after_start:bool = match range.start_bound() {
+                Bound::Included(start) => &node.key >= start,
+                Bound::Excluded(start) => &node.key > start,
+                Bound::Unbounded => true
+            };
+
+            if after_start {
+                stack.push(node);
+                cur = node.left.as_deref();
+            } else {
+                cur = node.right.as_deref();
+            }
+        }
+
+        Range { iter: Iter { stack }, end: range.end_bound().cloned() }
+    }
+
+    // closest entry below (or above) `key`, optionally allowing `key` itself
+    fn bound<Q>(&self, key:&Q, below:bool, inclusive:bool) -> Option<(&K, &V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let mut best:Option<&Node<K, V>> = None;
+        let mut cur:Option<&Node<K, V>> = self.root.as_deref();
+
+        while let Some(node) = cur {
+            let ord:Ordering = node.key.borrow().cmp(key);
+            if ord == Ordering::Equal && inclusive {
+                return Some((&node.key, &node.val));
+            }
+
+            if below {
+                if ord == Ordering::Less {
+                    best = Some(node);
+                    cur = node.right.as_deref();
+                } else { cur = node.left.as_deref(); }
+            } else if ord == Ordering::Greater {
+                best = Some(node);
+                cur = node.left.as_deref();
+            } else { cur = node.right.as_deref(); }
+        }
+
+        best.map(|n| (&n.key, &n.val))
+    }
+}
+
+/// In-order iterator over an `AvlMap`
+pub struct Iter<'a, K, V> {
+    stack:Vec<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, mut cur:Option<&'a Node<K, V>>) {
+        while let Some(node) = cur {
+            self.stack.push(node);
+            cur = node.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node:&'a Node<K, V> = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+
+        Some((&node.key, &node.val))
+    }
+}
+
+/// In-order iterator over the entries of an `AvlMap` inside a range
+pub struct Range<'a, K, V> {
+    iter:Iter<'a, K, V>,
+    end:Bound<K>,
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V>
+    where K: Ord
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, val) = self.iter.next()?;
+
+        let inside:bool = match &self.end {
+            Bound::Included(end) => key <= end,
+            Bound::Excluded(end) => key < end,
+            Bound::Unbounded => true
+        };
+        if !inside {
+            self.iter.stack.clear();
+            return None;
+        }
+
+        Some((key, val))
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a AvlMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for AvlMap<K, V>
+    where K: Ord
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter:I) -> Self {
+        let mut map:AvlMap<K, V> = AvlMap::new();
+        for (key, val) in iter {
+            map.insert(key, val);
+        }
+        map
+    }
+}
+
+impl<K, V> PartialEq for AvlMap<K, V>
+    where K: PartialEq, V: PartialEq
+{
+    /// Same entries in the same order, however the trees are shaped
+    ///
+    /// #Example
+    /// ```
+    /// use trees::avl::map::AvlMap;
+    ///
+    /// let a:AvlMap<u32, char> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    /// let b:AvlMap<u32, char> = [(3, 'c'), (2, 'b'), (1, 'a')].into_iter().collect();
+    /// let c:AvlMap<u32, char> = [(1, 'a'), (2, 'b')].into_iter().collect();
+    ///
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, c);
+    /// ```
+    fn eq(&self, other:&Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<K, V> Eq for AvlMap<K, V>
+    where K: Eq, V: Eq
+{}
+
+impl<K, V> Visualize for AvlMap<K, V>
+    where K: Debug, V: Debug
+{
+    /// Every entry with the height of its node
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("AvlMap");
+        if let Some(root) = self.root() {
+            diagram.add_tree(root, |n| format!("{:?}: {:?} (h{})", n.key, n.val, n.height), |n| {
+                [n.left.as_deref(), n.right.as_deref()]
+            });
+        }
+        diagram
+    }
+}
+
+impl<K, V> fmt::Display for AvlMap<K, V>
+    where K: Debug, V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,188 @@
+use std::{borrow::Borrow, cmp::Ordering};
+
+pub type Link<K, V, A = ()> = Option<Box<Node<K, V, A>>>;
+// a non-empty subtree
+pub type Tree<K, V, A = ()> = Box<Node<K, V, A>>;
+
+#[derive(Clone, Debug)]
+pub struct Node<K, V, A = ()> {
+    pub key: K,
+    pub val: V,
+    // whatever the tree keeps about the subtree, e.g. its size
+    pub aug: A,
+    pub height: usize,
+    pub left: Link<K, V, A>,
+    pub right: Link<K, V, A>,
+}
+
+/// Data a node keeps about its whole subtree, recomputed every time the subtree changes shape.
+/// Lets trees built on the AVL balancing reuse its rotations
+pub trait Augment<K, V>: Sized {
+    /// Data of a node without children
+    fn leaf(key:&K, val:&V) -> Self;
+
+    /// Recompute `node.aug`, the children are already up to date
+    fn update(node:&mut Node<K, V, Self>);
+}
+
+impl<K, V> Augment<K, V> for () {
+    fn leaf(_:&K, _:&V) -> Self {}
+
+    fn update(_:&mut Node<K, V, Self>) {}
+}
+
+impl<K, V, A> Node<K, V, A>
+    where A: Augment<K, V>
+{
+    pub fn new(key:K, val:V) -> Self {
+        let aug:A = A::leaf(&key, &val);
+        Self { key, val, aug, height: 1, left: None, right: None }
+    }
+
+    /// Height of the left subtree minus height of the right subtree
+    pub fn balance(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+
+    pub fn update(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+        A::update(self);
+    }
+}
+
+pub fn height<K, V, A>(link:&Link<K, V, A>) -> usize {
+    link.as_ref().map_or(0, |n| n.height)
+}
+
+fn rotate_right<K, V, A: Augment<K, V>>(mut node:Box<Node<K, V, A>>) -> Box<Node<K, V, A>> {
+    let mut left:Box<Node<K, V, A>> = node.left.take().unwrap();
+    node.left = left.right.take();
+    node.update();
+
+    left.right = Some(node);
+    left.update();
+    left
+}
+
+fn rotate_left<K, V, A: Augment<K, V>>(mut node:Box<Node<K, V, A>>) -> Box<Node<K, V, A>> {
+    let mut right:Box<Node<K, V, A>> = node.right.take().unwrap();
+    node.right = right.left.take();
+    node.update();
+
+    right.left = Some(node);
+    right.update();
+    right
+}
+
+/// Restore the AVL invariant of `node` after one of its subtrees changed height by one
+pub fn rebalance<K, V, A: Augment<K, V>>(mut node:Box<Node<K, V, A>>) -> Box<Node<K, V, A>> {
+    node.update();
+
+    let balance:isize = node.balance();
+    if balance > 1 {
+        if node.left.as_ref().unwrap().balance() < 0 {
+            node.left = Some(rotate_left(node.left.take().unwrap()));
+        }
+        return rotate_right(node);
+    }
+    if balance < -1 {
+        if node.right.as_ref().unwrap().balance() > 0 {
+            node.right = Some(rotate_right(node.right.take().unwrap()));
+        }
+        return rotate_left(node);
+    }
+
+    node
+}
+
+/// Insert into the subtree, returns the new subtree root and the replaced value
+pub fn insert<K, V, A>(link:Link<K, V, A>, key:K, val:V) -> (Box<Node<K, V, A>>, Option<V>)
+    where K: Ord, A: Augment<K, V>
+{
+    let Some(mut node) = link else {
+        return (Box::new(Node::new(key, val)), None);
+    };
+
+    let old:Option<V> = match key.cmp(&node.key) {
+        Ordering::Equal => {
+            let old:V = std::mem::replace(&mut node.val, val);
+            return (node, Some(old));
+        },
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), key, val);
+            node.left = Some(left);
+            old
+        },
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), key, val);
+            node.right = Some(right);
+            old
+        }
+    };
+
+    (rebalance(node), old)
+}
+
+/// Detach the smallest node of the subtree, returns the remaining subtree and that node
+pub fn remove_min<K, V, A: Augment<K, V>>(mut node:Tree<K, V, A>) -> (Link<K, V, A>, Tree<K, V, A>) {
+    let Some(left) = node.left.take() else {
+        let right:Link<K, V, A> = node.right.take();
+        return (right, node);
+    };
+
+    let (left, min) = remove_min(left);
+    node.left = left;
+
+    (Some(rebalance(node)), min)
+}
+
+/// Detach the largest node of the subtree, returns the remaining subtree and that node
+pub fn remove_max<K, V, A: Augment<K, V>>(mut node:Tree<K, V, A>) -> (Link<K, V, A>, Tree<K, V, A>) {
+    let Some(right) = node.right.take() else {
+        let left:Link<K, V, A> = node.left.take();
+        return (left, node);
+    };
+
+    let (right, max) = remove_max(right);
+    node.right = right;
+
+    (Some(rebalance(node)), max)
+}
+
+/// Remove `key` from the subtree, returns the new subtree and the removed entry
+pub fn remove<K, V, A, Q>(link:Link<K, V, A>, key:&Q) -> (Link<K, V, A>, Option<(K, V)>)
+    where K: Borrow<Q>, Q: Ord + ?Sized, A: Augment<K, V>
+{
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    let removed:Option<(K, V)> = match key.cmp(node.key.borrow()) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), key);
+            node.left = left;
+            removed
+        },
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), key);
+            node.right = right;
+            removed
+        },
+        Ordering::Equal => {
+            let (left, right) = (node.left.take(), node.right.take());
+
+            let Some(right) = right else {
+                return (left, Some((node.key, node.val)));
+            };
+
+            // replace the node by its successor
+            let (right, mut succ) = remove_min(right);
+            succ.left = left;
+            succ.right = right;
+
+            return (Some(rebalance(succ)), Some((node.key, node.val)));
+        }
+    };
+
+    (Some(rebalance(node)), removed)
+}
@@ -0,0 +1,182 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    ops::RangeBounds
+};
+
+use structs::viz::{Diagram, Visualize};
+
+use crate::avl::map::AvlMap;
+
+/// Ordered set backed by an `AvlMap` with unit values
+#[derive(Clone, Debug)]
+pub struct AvlSet<T> {
+    map:AvlMap<T, ()>,
+}
+
+impl<T> Default for AvlSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> AvlSet<T> {
+    pub fn new() -> Self {
+        Self { map: AvlMap::new() }
+    }
+
+    pub fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(k, _)| k)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(k, _)| k)
+    }
+
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.map.pop_first().map(|(k, _)| k)
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.map.pop_last().map(|(k, _)| k)
+    }
+
+    /// Iterate over the values in order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.map.keys()
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+        where T: Clone
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> AvlSet<T>
+    where T: Ord
+{
+    /// Add `val`, returns false if it was already present
+    ///
+    /// #Example
+    /// ```
+    /// use trees::avl::set::AvlSet;
+    ///
+    /// let mut set:AvlSet<i32> = AvlSet::new();
+    /// assert!(set.insert(5));
+    /// assert!(set.insert(1));
+    /// assert!(!set.insert(5));
+    ///
+    /// assert_eq!(set.to_vec(), vec![1, 5]);
+    /// assert_eq!(set.ceiling(&2), Some(&5));
+    /// ```
+    pub fn insert(&mut self, val:T) -> bool {
+        self.map.insert(val, ()).is_none()
+    }
+
+    /// Remove `val`, returns false if it was not present
+    pub fn remove<Q>(&mut self, val:&Q) -> bool
+        where T: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.map.remove(val).is_some()
+    }
+
+    pub fn contains<Q>(&self, val:&Q) -> bool
+        where T: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.map.contains_key(val)
+    }
+
+    /// Largest value less than or equal to `val`
+    pub fn floor<Q>(&self, val:&Q) -> Option<&T>
+        where T: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.map.floor(val).map(|(k, _)| k)
+    }
+
+    /// Smallest value greater than or equal to `val`
+    pub fn ceiling<Q>(&self, val:&Q) -> Option<&T>
+        where T: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.map.ceiling(val).map(|(k, _)| k)
+    }
+
+    /// Largest value strictly less than `val`
+    pub fn lower<Q>(&self, val:&Q) -> Option<&T>
+        where T: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.map.lower(val).map(|(k, _)| k)
+    }
+
+    /// Smallest value strictly greater than `val`
+    pub fn higher<Q>(&self, val:&Q) -> Option<&T>
+        where T: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.map.higher(val).map(|(k, _)| k)
+    }
+
+    /// Iterate in order over the values inside `range`
+    pub fn range<R>(&self, range:R) -> impl Iterator<Item = &T>
+        where R: RangeBounds<T>, T: Clone
+    {
+        self.map.range(range).map(|(k, _)| k)
+    }
+}
+
+impl<T> FromIterator<T> for AvlSet<T>
+    where T: Ord
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter:I) -> Self {
+        let mut set:AvlSet<T> = AvlSet::new();
+        for val in iter {
+            set.insert(val);
+        }
+        set
+    }
+}
+
+impl<T> PartialEq for AvlSet<T>
+    where T: PartialEq
+{
+    /// Same values, however the trees are shaped
+    fn eq(&self, other:&Self) -> bool {
+        self.size() == other.size() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for AvlSet<T>
+    where T: Eq
+{}
+
+impl<T> Visualize for AvlSet<T>
+    where T: Debug
+{
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("AvlSet");
+        if let Some(root) = self.map.root() {
+            diagram.add_tree(root, |n| format!("{:?}", n.key), |n| [n.left.as_deref(), n.right.as_deref()]);
+        }
+        diagram
+    }
+}
+
+impl<T> fmt::Display for AvlSet<T>
+    where T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
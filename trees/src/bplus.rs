@@ -0,0 +1,650 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    ops::{Bound, RangeBounds}
+};
+
+use structs::viz::{Diagram, Visualize};
+
+pub const DEFAULT_ORDER:usize = 16;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node<K, V> {
+    Leaf {
+        keys:Vec<K>,
+        vals:Vec<V>,
+        // the leaf holding the following keys
+        next:Option<usize>,
+    },
+    Internal {
+        // keys[i] is the smallest key reachable through children[i + 1]
+        keys:Vec<K>,
+        children:Vec<usize>,
+    },
+}
+
+impl<K, V> Node<K, V> {
+    fn keys(&self) -> &Vec<K> {
+        match self {
+            Node::Leaf { keys, .. } | Node::Internal { keys, .. } => keys
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Node::Leaf { keys, .. } => keys.len(),
+            Node::Internal { children, .. } => children.len()
+        }
+    }
+}
+
+/// B+tree map: entries live only in the leaves, which are linked in key order so range
+/// scans walk the leaves without going back up the tree. Nodes are kept in an arena
+/// and addressed by index
+#[derive(Clone, Debug, PartialEq)]
+pub struct BPlusTree<K, V> {
+    nodes:Vec<Node<K, V>>,
+    free:Vec<usize>,
+    root:usize,
+    order:usize,
+    size:usize,
+}
+
+impl<K, V> Default for BPlusTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> BPlusTree<K, V> {
+    pub fn new() -> Self {
+        Self::with_order(DEFAULT_ORDER)
+    }
+
+    /// Create an empty tree whose nodes have at most `order` children
+    /// and whose leaves hold at most `order - 1` entries
+    pub fn with_order(order:usize) -> Self {
+        assert!(order >= 3, "B+tree order must be at least 3, got {}", order);
+
+        let root:Node<K, V> = Node::Leaf { keys: Vec::new(), vals: Vec::new(), next: None };
+        Self { nodes: vec![root], free: Vec::new(), root: 0, order, size: 0 }
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Amount of entries
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Amount of levels, 0 for an empty tree
+    pub fn height(&self) -> usize {
+        if self.size == 0 { return 0; }
+
+        let mut height:usize = 1;
+        let mut cur:usize = self.root;
+        while let Node::Internal { children, .. } = &self.nodes[cur] {
+            height += 1;
+            cur = children[0];
+        }
+        height
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::with_order(self.order);
+    }
+
+    /// Amount of leaves, each holding a contiguous run of keys
+    pub fn leaves(&self) -> usize {
+        let mut count:usize = 0;
+        let mut cur:Option<usize> = Some(self.first_leaf());
+        while let Some(leaf) = cur {
+            count += 1;
+            cur = match &self.nodes[leaf] {
+                Node::Leaf { next, .. } => *next,
+                Node::Internal { .. } => None
+            };
+        }
+        count
+    }
+
+    fn min_children(&self) -> usize {
+        self.order.div_ceil(2)
+    }
+
+    fn min_entries(&self) -> usize {
+        self.order / 2
+    }
+
+    fn alloc(&mut self, node:Node<K, V>) -> usize {
+        if let Some(idx) = self.free.pop() {
+            self.nodes[idx] = node;
+            return idx;
+        }
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn release(&mut self, idx:usize) {
+        self.nodes[idx] = Node::Internal { keys: Vec::new(), children: Vec::new() };
+        self.free.push(idx);
+    }
+
+    fn first_leaf(&self) -> usize {
+        let mut cur:usize = self.root;
+        while let Node::Internal { children, .. } = &self.nodes[cur] {
+            cur = children[0];
+        }
+        cur
+    }
+
+    /// Iterate over the entries in key order by walking the leaf chain
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { tree: self, leaf: Some(self.first_leaf()), idx: 0 }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut cur:usize = self.root;
+        loop {
+            match &self.nodes[cur] {
+                Node::Internal { children, .. } => cur = *children.last().unwrap(),
+                Node::Leaf { keys, vals, .. } => return Some((keys.last()?, vals.last()?))
+            }
+        }
+    }
+
+    /// Build a tree from entries sorted by key in O(n), filling the leaves and linking them in order.
+    /// Entries with equal keys keep the last value. Panics if the keys are not sorted
+    ///
+    /// #Example
+    /// ```
+    /// use trees::bplus::BPlusTree;
+    ///
+    /// let tree:BPlusTree<u32, char> = BPlusTree::from_sorted(4, (0..26).zip('a'..='z'));
+    ///
+    /// assert_eq!(tree.get(&25), Some(&'z'));
+    /// assert_eq!(tree.leaves(), 9);
+    /// assert_eq!(tree.range(3..7).map(|(_, c)| *c).collect::<String>(), "defg");
+    /// ```
+    pub fn from_sorted<I>(order:usize, entries:I) -> Self
+        where I: IntoIterator<Item = (K, V)>, K: Ord + Clone
+    {
+        let mut tree:Self = Self::with_order(order);
+
+        let mut keys:Vec<K> = Vec::new();
+        let mut vals:Vec<V> = Vec::new();
+        for (key, val) in entries {
+            if let Some(last) = keys.last() {
+                assert!(*last <= key, "from_sorted() needs entries sorted by key");
+                if *last == key {
+                    *vals.last_mut().unwrap() = val;
+                    continue;
+                }
+            }
+            keys.push(key);
+            vals.push(val);
+        }
+        if keys.is_empty() { return tree; }
+
+        tree.size = keys.len();
+        tree.nodes.clear();
+
+        // leaves, each paired with its smallest key
+        let leaves:usize = keys.len().div_ceil(order - 1);
+        let mut level:Vec<(K, usize)> = Vec::with_capacity(leaves);
+        let (mut keys, mut vals) = (keys.into_iter(), vals.into_iter());
+        for (i, count) in split_evenly(tree.size, leaves).into_iter().enumerate() {
+            let leaf_keys:Vec<K> = keys.by_ref().take(count).collect();
+            let next:Option<usize> = if i + 1 < leaves { Some(i + 1) } else { None };
+
+            level.push((leaf_keys[0].clone(), i));
+            tree.nodes.push(Node::Leaf { keys: leaf_keys, vals: vals.by_ref().take(count).collect(), next });
+        }
+
+        while level.len() > 1 {
+            let parents:usize = level.len().div_ceil(order);
+            let mut children = level.into_iter();
+
+            level = Vec::with_capacity(parents);
+            for count in split_evenly(children.len(), parents) {
+                let group:Vec<(K, usize)> = children.by_ref().take(count).collect();
+                let min:K = group[0].0.clone();
+
+                let mut keys:Vec<K> = Vec::with_capacity(count - 1);
+                let mut idxs:Vec<usize> = Vec::with_capacity(count);
+                for (i, (key, idx)) in group.into_iter().enumerate() {
+                    if i > 0 { keys.push(key); }
+                    idxs.push(idx);
+                }
+
+                tree.nodes.push(Node::Internal { keys, children: idxs });
+                level.push((min, tree.nodes.len() - 1));
+            }
+        }
+        tree.root = level[0].1;
+
+        tree
+    }
+}
+
+// sizes of `parts` groups as equal as possible summing to `total`
+fn split_evenly(total:usize, parts:usize) -> Vec<usize> {
+    (0..parts).map(|i| total / parts + usize::from(i < total % parts)).collect()
+}
+
+impl<K, V> BPlusTree<K, V>
+    where K: Ord + Clone
+{
+    // leaf that would hold `key`
+    fn find_leaf<Q>(&self, key:&Q) -> usize
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let mut cur:usize = self.root;
+        while let Node::Internal { keys, children } = &self.nodes[cur] {
+            cur = children[keys.partition_point(|k| k.borrow() <= key)];
+        }
+        cur
+    }
+
+    pub fn get<Q>(&self, key:&Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let Node::Leaf { keys, vals, .. } = &self.nodes[self.find_leaf(key)] else {
+            return None;
+        };
+        let idx:usize = keys.binary_search_by(|k| k.borrow().cmp(key)).ok()?;
+        Some(&vals[idx])
+    }
+
+    pub fn get_mut<Q>(&mut self, key:&Q) -> Option<&mut V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let leaf:usize = self.find_leaf(key);
+        let Node::Leaf { keys, vals, .. } = &mut self.nodes[leaf] else {
+            return None;
+        };
+        let idx:usize = keys.binary_search_by(|k| k.borrow().cmp(key)).ok()?;
+        Some(&mut vals[idx])
+    }
+
+    pub fn contains_key<Q>(&self, key:&Q) -> bool
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.get(key).is_some()
+    }
+
+    /// Insert a value, returning the previous value of the key
+    ///
+    /// #Example
+    /// ```
+    /// use trees::bplus::BPlusTree;
+    ///
+    /// let mut tree:BPlusTree<i32, i32> = BPlusTree::with_order(3);
+    /// for k in (0..20).rev() {
+    ///     tree.insert(k, -k);
+    /// }
+    ///
+    /// assert_eq!(tree.insert(5, 50), Some(-5));
+    /// assert_eq!(tree.remove(&6), Some(-6));
+    /// assert_eq!(tree.range(4..=7).map(|(k, _)| *k).collect::<Vec<i32>>(), vec![4, 5, 7]);
+    /// ```
+    pub fn insert(&mut self, key:K, val:V) -> Option<V> {
+        let (old, split) = self.insert_rec(self.root, key, val);
+
+        if let Some((sep, right)) = split {
+            let left:usize = self.root;
+            self.root = self.alloc(Node::Internal { keys: vec![sep], children: vec![left, right] });
+        }
+        if old.is_none() {
+            self.size += 1;
+        }
+
+        old
+    }
+
+    // returns the replaced value and, if the node split, the new right node with its smallest key
+    fn insert_rec(&mut self, node:usize, key:K, val:V) -> (Option<V>, Option<(K, usize)>) {
+        let order:usize = self.order;
+
+        match &mut self.nodes[node] {
+            Node::Leaf { keys, vals, next } => {
+                let idx:usize = match keys.binary_search(&key) {
+                    Ok(idx) => return (Some(std::mem::replace(&mut vals[idx], val)), None),
+                    Err(idx) => idx
+                };
+                keys.insert(idx, key);
+                vals.insert(idx, val);
+                if keys.len() < order {
+                    return (None, None);
+                }
+
+                let mid:usize = keys.len() / 2;
+                let right:Node<K, V> = Node::Leaf { keys: keys.split_off(mid), vals: vals.split_off(mid), next: *next };
+                let sep:K = right.keys()[0].clone();
+
+                let right_idx:usize = self.alloc(right);
+                if let Node::Leaf { next, .. } = &mut self.nodes[node] {
+                    *next = Some(right_idx);
+                }
+                (None, Some((sep, right_idx)))
+            },
+            Node::Internal { keys, children } => {
+                let idx:usize = keys.partition_point(|k| k <= &key);
+                let child:usize = children[idx];
+
+                let (old, split) = self.insert_rec(child, key, val);
+                let Some((sep, right)) = split else {
+                    return (old, None);
+                };
+
+                let Node::Internal { keys, children } = &mut self.nodes[node] else {
+                    unreachable!()
+                };
+                keys.insert(idx, sep);
+                children.insert(idx + 1, right);
+                if children.len() <= order {
+                    return (old, None);
+                }
+
+                let mid:usize = keys.len() / 2;
+                let right_keys:Vec<K> = keys.split_off(mid + 1);
+                let right_children:Vec<usize> = children.split_off(mid + 1);
+                let sep:K = keys.pop().unwrap();
+
+                let right_idx:usize = self.alloc(Node::Internal { keys: right_keys, children: right_children });
+                (old, Some((sep, right_idx)))
+            }
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let val:V = self.remove_rec(self.root, key)?;
+        self.size -= 1;
+
+        // collapse a root left with a single child
+        if let Node::Internal { children, .. } = &self.nodes[self.root] && children.len() == 1 {
+            let old:usize = self.root;
+            self.root = children[0];
+            self.release(old);
+        }
+
+        Some(val)
+    }
+
+    fn remove_rec<Q>(&mut self, node:usize, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let (idx, child) = match &mut self.nodes[node] {
+            Node::Leaf { keys, vals, .. } => {
+                let idx:usize = keys.binary_search_by(|k| k.borrow().cmp(key)).ok()?;
+                keys.remove(idx);
+                return Some(vals.remove(idx));
+            },
+            Node::Internal { keys, children } => {
+                let idx:usize = keys.partition_point(|k| k.borrow() <= key);
+                (idx, children[idx])
+            }
+        };
+
+        let val:V = self.remove_rec(child, key)?;
+        self.fix_child(node, idx);
+        Some(val)
+    }
+
+    // refill the child at `idx` of `node` if it is under the minimum,
+    // borrowing from or merging with a sibling
+    fn fix_child(&mut self, node:usize, idx:usize) {
+        let Node::Internal { children, .. } = &self.nodes[node] else {
+            return;
+        };
+        let children:Vec<usize> = children.clone();
+        let child:usize = children[idx];
+
+        let is_leaf:bool = matches!(self.nodes[child], Node::Leaf { .. });
+        let min:usize = if is_leaf { self.min_entries() } else { self.min_children() };
+        if self.nodes[child].len() >= min { return; }
+
+        if idx > 0 && self.nodes[children[idx - 1]].len() > min {
+            self.borrow_left(node, idx, children[idx - 1], child);
+        } else if idx + 1 < children.len() && self.nodes[children[idx + 1]].len() > min {
+            self.borrow_right(node, idx, child, children[idx + 1]);
+        } else if idx > 0 {
+            self.merge(node, idx - 1, children[idx - 1], child);
+        } else if idx + 1 < children.len() {
+            self.merge(node, idx, child, children[idx + 1]);
+        }
+    }
+
+    // move the last entry of `left` to the front of `child`
+    fn borrow_left(&mut self, parent:usize, idx:usize, left:usize, child:usize) {
+        let sep:K = self.nodes[parent].keys()[idx - 1].clone();
+
+        let new_sep:K = match &mut self.nodes[left] {
+            Node::Leaf { keys, vals, .. } => {
+                let (key, val) = (keys.pop().unwrap(), vals.pop().unwrap());
+                if let Node::Leaf { keys, vals, .. } = &mut self.nodes[child] {
+                    keys.insert(0, key.clone());
+                    vals.insert(0, val);
+                }
+                key
+            },
+            Node::Internal { keys, children } => {
+                let (key, grandchild) = (keys.pop().unwrap(), children.pop().unwrap());
+                if let Node::Internal { keys, children } = &mut self.nodes[child] {
+                    keys.insert(0, sep);
+                    children.insert(0, grandchild);
+                }
+                key
+            }
+        };
+
+        if let Node::Internal { keys, .. } = &mut self.nodes[parent] {
+            keys[idx - 1] = new_sep;
+        }
+    }
+
+    // move the first entry of `right` to the back of `child`
+    fn borrow_right(&mut self, parent:usize, idx:usize, child:usize, right:usize) {
+        let sep:K = self.nodes[parent].keys()[idx].clone();
+
+        let new_sep:K = match &mut self.nodes[right] {
+            Node::Leaf { keys, vals, .. } => {
+                let (key, val) = (keys.remove(0), vals.remove(0));
+                let new_sep:K = keys[0].clone();
+                if let Node::Leaf { keys, vals, .. } = &mut self.nodes[child] {
+                    keys.push(key);
+                    vals.push(val);
+                }
+                new_sep
+            },
+            Node::Internal { keys, children } => {
+                let (key, grandchild) = (keys.remove(0), children.remove(0));
+                if let Node::Internal { keys, children } = &mut self.nodes[child] {
+                    keys.push(sep);
+                    children.push(grandchild);
+                }
+                key
+            }
+        };
+
+        if let Node::Internal { keys, .. } = &mut self.nodes[parent] {
+            keys[idx] = new_sep;
+        }
+    }
+
+    // append `right` (the child after separator `sep_idx`) to `left` and drop it from the parent
+    fn merge(&mut self, parent:usize, sep_idx:usize, left:usize, right:usize) {
+        let sep:K = match &mut self.nodes[parent] {
+            Node::Internal { keys, children } => {
+                children.remove(sep_idx + 1);
+                keys.remove(sep_idx)
+            },
+            Node::Leaf { .. } => unreachable!()
+        };
+
+        let right_node:Node<K, V> = std::mem::replace(&mut self.nodes[right], Node::Internal { keys: Vec::new(), children: Vec::new() });
+        match (&mut self.nodes[left], right_node) {
+            (Node::Leaf { keys, vals, next }, Node::Leaf { keys: rkeys, vals: rvals, next: rnext }) => {
+                keys.extend(rkeys);
+                vals.extend(rvals);
+                *next = rnext;
+            },
+            (Node::Internal { keys, children }, Node::Internal { keys: rkeys, children: rchildren }) => {
+                keys.push(sep);
+                keys.extend(rkeys);
+                children.extend(rchildren);
+            },
+            _ => unreachable!()
+        }
+
+        self.release(right);
+    }
+
+    /// Iterate in key order over the entries with a key inside `range`.
+    /// Finds the first leaf in O(log n) then follows the leaf links
+    pub fn range<R>(&self, range:R) -> Range<'_, K, V>
+        where R: RangeBounds<K>
+    {
+        let (leaf, idx) = match range.start_bound() {
+            Bound::Included(start) => {
+                let leaf:usize = self.find_leaf(start);
+                (leaf, self.nodes[leaf].keys().partition_point(|k| k < start))
+            },
+            Bound::Excluded(start) => {
+                let leaf:usize = self.find_leaf(start);
+                (leaf, self.nodes[leaf].keys().partition_point(|k| k <= start))
+            },
+            Bound::Unbounded => (self.first_leaf(), 0)
+        };
+
+        Range { iter: Iter { tree: self, leaf: Some(leaf), idx }, end: range.end_bound().cloned() }
+    }
+}
+
+/// Iterator over a `BPlusTree` following the leaf links
+pub struct Iter<'a, K, V> {
+    tree:&'a BPlusTree<K, V>,
+    leaf:Option<usize>,
+    idx:usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Node::Leaf { keys, vals, next } = &self.tree.nodes[self.leaf?] else {
+                return None;
+            };
+
+            if self.idx < keys.len() {
+                self.idx += 1;
+                return Some((&keys[self.idx - 1], &vals[self.idx - 1]));
+            }
+
+            self.leaf = *next;
+            self.idx = 0;
+        }
+    }
+}
+
+/// Iterator over the entries of a `BPlusTree` inside a range
+pub struct Range<'a, K, V> {
+    iter:Iter<'a, K, V>,
+    end:Bound<K>,
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V>
+    where K: Ord
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, val) = self.iter.next()?;
+
+        let inside:bool = match &self.end {
+            Bound::Included(end) => key <= end,
+            Bound::Excluded(end) => key < end,
+            Bound::Unbounded => true
+        };
+        if !inside {
+            self.iter.leaf = None;
+            return None;
+        }
+
+        Some((key, val))
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BPlusTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for BPlusTree<K, V>
+    where K: Ord + Clone
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter:I) -> Self {
+        let mut tree:BPlusTree<K, V> = BPlusTree::new();
+        for (key, val) in iter {
+            tree.insert(key, val);
+        }
+        tree
+    }
+}
+
+impl<K, V> Visualize for BPlusTree<K, V>
+    where K: Debug, V: Debug
+{
+    /// The separator keys of the internal nodes and the entries of the leaves
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("BPlusTree");
+        if self.size == 0 { return diagram; }
+
+        let label = |id:usize| match &self.nodes[id] {
+            Node::Internal { keys, .. } => format!("{:?}", keys),
+            Node::Leaf { keys, vals, .. } => {
+                let entries:Vec<String> = keys.iter().zip(vals).map(|(k, v)| format!("{:?}: {:?}", k, v)).collect();
+                format!("{{{}}}", entries.join(", "))
+            }
+        };
+        diagram.add_tree(self.root, label, |id| match &self.nodes[id] {
+            Node::Internal { children, .. } => children.iter().map(|c| Some(*c)).collect(),
+            Node::Leaf { .. } => Vec::new()
+        });
+        diagram
+    }
+}
+
+impl<K, V> fmt::Display for BPlusTree<K, V>
+    where K: Debug, V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,554 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    ops::{Bound, RangeBounds}
+};
+
+use structs::viz::{Diagram, Visualize};
+
+pub const DEFAULT_ORDER:usize = 16;
+
+// separator entry and new right sibling produced by a node split
+type Split<K, V> = Option<(K, V, Node<K, V>)>;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node<K, V> {
+    keys:Vec<K>,
+    vals:Vec<V>,
+    // empty for leaves, otherwise keys.len() + 1 children
+    children:Vec<Node<K, V>>,
+}
+
+impl<K, V> Node<K, V> {
+    fn new() -> Self {
+        Self { keys: Vec::new(), vals: Vec::new(), children: Vec::new() }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    // position of the first key not less than `key`, and whether it is equal
+    fn search<Q>(&self, key:&Q) -> (usize, bool)
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        match self.keys.binary_search_by(|k| k.borrow().cmp(key)) {
+            Ok(i) => (i, true),
+            Err(i) => (i, false)
+        }
+    }
+}
+
+/// B-tree map where every node holds up to `order - 1` keys and `order` children.
+/// High fanout keeps the tree shallow, every operation is O(order * log n)
+#[derive(Clone, Debug, PartialEq)]
+pub struct BTree<K, V> {
+    root:Node<K, V>,
+    order:usize,
+    size:usize,
+}
+
+impl<K, V> Default for BTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> BTree<K, V> {
+    pub fn new() -> Self {
+        Self::with_order(DEFAULT_ORDER)
+    }
+
+    /// Create an empty tree whose nodes have at most `order` children
+    pub fn with_order(order:usize) -> Self {
+        assert!(order >= 3, "B-tree order must be at least 3, got {}", order);
+        Self { root: Node::new(), order, size: 0 }
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Amount of entries
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Amount of levels, 0 for an empty tree
+    pub fn height(&self) -> usize {
+        if self.size == 0 { return 0; }
+
+        let mut height:usize = 1;
+        let mut cur:&Node<K, V> = &self.root;
+        while let Some(child) = cur.children.first() {
+            height += 1;
+            cur = child;
+        }
+        height
+    }
+
+    pub fn clear(&mut self) {
+        self.root = Node::new();
+        self.size = 0;
+    }
+
+    fn max_keys(&self) -> usize {
+        self.order - 1
+    }
+
+    fn min_keys(&self) -> usize {
+        self.order.div_ceil(2) - 1
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut cur:&Node<K, V> = &self.root;
+        while let Some(child) = cur.children.first() {
+            cur = child;
+        }
+        Some((cur.keys.first()?, cur.vals.first()?))
+    }
+
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut cur:&Node<K, V> = &self.root;
+        while let Some(child) = cur.children.last() {
+            cur = child;
+        }
+        Some((cur.keys.last()?, cur.vals.last()?))
+    }
+
+    /// Iterate over the entries in key order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter:Iter<'_, K, V> = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Build a tree from entries sorted by key in O(n), packing the nodes as full as possible.
+    /// Entries with equal keys keep the last value. Panics if the keys are not sorted
+    ///
+    /// #Example
+    /// ```
+    /// use trees::btree::BTree;
+    ///
+    /// let tree:BTree<u32, u32> = BTree::from_sorted(4, (0..100).map(|k| (k, k * k)));
+    ///
+    /// assert_eq!(tree.size(), 100);
+    /// assert_eq!(tree.get(&12), Some(&144));
+    /// assert_eq!(tree.height(), 4);
+    /// ```
+    pub fn from_sorted<I>(order:usize, entries:I) -> Self
+        where I: IntoIterator<Item = (K, V)>, K: Ord
+    {
+        let mut tree:Self = Self::with_order(order);
+
+        let mut keys:Vec<K> = Vec::new();
+        let mut vals:Vec<V> = Vec::new();
+        for (key, val) in entries {
+            if let Some(last) = keys.last() {
+                assert!(*last <= key, "from_sorted() needs entries sorted by key");
+                if *last == key {
+                    *vals.last_mut().unwrap() = val;
+                    continue;
+                }
+            }
+            keys.push(key);
+            vals.push(val);
+        }
+
+        tree.size = keys.len();
+        tree.root = Self::build_level(tree.order, keys, vals, Vec::new());
+        tree
+    }
+
+    // pack the keys into as few nodes as possible, recursing with the separators as the next level
+    fn build_level(order:usize, keys:Vec<K>, vals:Vec<V>, children:Vec<Node<K, V>>) -> Node<K, V> {
+        let total:usize = keys.len();
+        let nodes:usize = (total + 1).div_ceil(order);
+        if nodes <= 1 {
+            return Node { keys, vals, children };
+        }
+
+        let per_node:usize = (total + 1 - nodes) / nodes;
+        let extra:usize = (total + 1 - nodes) % nodes;
+
+        let mut keys = keys.into_iter();
+        let mut vals = vals.into_iter();
+        let mut children = children.into_iter();
+        let leaf:bool = children.len() == 0;
+
+        let mut level:Vec<Node<K, V>> = Vec::with_capacity(nodes);
+        let (mut sep_keys, mut sep_vals) = (Vec::with_capacity(nodes - 1), Vec::with_capacity(nodes - 1));
+
+        for i in 0..nodes {
+            let count:usize = per_node + usize::from(i < extra);
+            let mut node:Node<K, V> = Node {
+                keys: keys.by_ref().take(count).collect(),
+                vals: vals.by_ref().take(count).collect(),
+                children: Vec::new()
+            };
+            if !leaf {
+                node.children = children.by_ref().take(count + 1).collect();
+            }
+            level.push(node);
+
+            if i + 1 < nodes {
+                sep_keys.push(keys.next().unwrap());
+                sep_vals.push(vals.next().unwrap());
+            }
+        }
+
+        Self::build_level(order, sep_keys, sep_vals, level)
+    }
+}
+
+impl<K, V> BTree<K, V>
+    where K: Ord
+{
+    /// Insert a value, returning the previous value of the key
+    ///
+    /// #Example
+    /// ```
+    /// use trees::btree::BTree;
+    ///
+    /// let mut tree:BTree<i32, &str> = BTree::with_order(3);
+    /// for (i, word) in ["a", "b", "c", "d", "e"].into_iter().enumerate() {
+    ///     tree.insert(i as i32, word);
+    /// }
+    ///
+    /// assert_eq!(tree.insert(2, "C"), Some("c"));
+    /// assert_eq!(tree.remove(&0), Some("a"));
+    /// assert_eq!(tree.values().copied().collect::<Vec<&str>>(), vec!["b", "C", "d", "e"]);
+    /// ```
+    pub fn insert(&mut self, key:K, val:V) -> Option<V> {
+        let max:usize = self.max_keys();
+        let (old, split) = Self::insert_rec(&mut self.root, key, val, max);
+
+        if let Some((sep_key, sep_val, right)) = split {
+            let left:Node<K, V> = std::mem::replace(&mut self.root, Node::new());
+            self.root.keys.push(sep_key);
+            self.root.vals.push(sep_val);
+            self.root.children = vec![left, right];
+        }
+        if old.is_none() {
+            self.size += 1;
+        }
+
+        old
+    }
+
+    fn insert_rec(node:&mut Node<K, V>, key:K, val:V, max:usize) -> (Option<V>, Split<K, V>) {
+        let (idx, found) = node.search(&key);
+        if found {
+            return (Some(std::mem::replace(&mut node.vals[idx], val)), None);
+        }
+
+        if node.is_leaf() {
+            node.keys.insert(idx, key);
+            node.vals.insert(idx, val);
+        } else {
+            let (old, split) = Self::insert_rec(&mut node.children[idx], key, val, max);
+            let Some((sep_key, sep_val, right)) = split else {
+                return (old, None);
+            };
+
+            node.keys.insert(idx, sep_key);
+            node.vals.insert(idx, sep_val);
+            node.children.insert(idx + 1, right);
+        }
+
+        if node.keys.len() <= max {
+            return (None, None);
+        }
+
+        // overflow: move the upper half to a new node and push the median up
+        let mid:usize = node.keys.len() / 2;
+        let mut right:Node<K, V> = Node {
+            keys: node.keys.split_off(mid + 1),
+            vals: node.vals.split_off(mid + 1),
+            children: Vec::new()
+        };
+        if !node.is_leaf() {
+            right.children = node.children.split_off(mid + 1);
+        }
+        let sep_key:K = node.keys.pop().unwrap();
+        let sep_val:V = node.vals.pop().unwrap();
+
+        (None, Some((sep_key, sep_val, right)))
+    }
+
+    pub fn get<Q>(&self, key:&Q) -> Option<&V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let mut cur:&Node<K, V> = &self.root;
+        loop {
+            let (idx, found) = cur.search(key);
+            if found { return Some(&cur.vals[idx]); }
+
+            cur = cur.children.get(idx)?;
+        }
+    }
+
+    pub fn get_mut<Q>(&mut self, key:&Q) -> Option<&mut V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let mut cur:&mut Node<K, V> = &mut self.root;
+        loop {
+            let (idx, found) = cur.search(key);
+            if found { return Some(&mut cur.vals[idx]); }
+
+            cur = cur.children.get_mut(idx)?;
+        }
+    }
+
+    pub fn contains_key<Q>(&self, key:&Q) -> bool
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn remove<Q>(&mut self, key:&Q) -> Option<V>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let min:usize = self.min_keys();
+        let (_, val) = Self::remove_rec(&mut self.root, key, min)?;
+        self.size -= 1;
+
+        // the root lost its last key, its only child takes its place
+        if self.root.keys.is_empty() && !self.root.is_leaf() {
+            self.root = self.root.children.pop().unwrap();
+        }
+
+        Some(val)
+    }
+
+    fn remove_rec<Q>(node:&mut Node<K, V>, key:&Q, min:usize) -> Option<(K, V)>
+        where K: Borrow<Q>, Q: Ord + ?Sized
+    {
+        let (idx, found) = node.search(key);
+
+        if node.is_leaf() {
+            if !found { return None; }
+            return Some((node.keys.remove(idx), node.vals.remove(idx)));
+        }
+
+        let removed:(K, V) = if found {
+            // swap with the predecessor, which always sits in a leaf
+            let (pred_key, pred_val) = Self::remove_max(&mut node.children[idx], min);
+            let key:K = std::mem::replace(&mut node.keys[idx], pred_key);
+            let val:V = std::mem::replace(&mut node.vals[idx], pred_val);
+            (key, val)
+        } else {
+            Self::remove_rec(&mut node.children[idx], key, min)?
+        };
+
+        Self::fix_child(node, idx, min);
+        Some(removed)
+    }
+
+    fn remove_max(node:&mut Node<K, V>, min:usize) -> (K, V) {
+        if node.is_leaf() {
+            return (node.keys.pop().unwrap(), node.vals.pop().unwrap());
+        }
+
+        let last:usize = node.children.len() - 1;
+        let res:(K, V) = Self::remove_max(&mut node.children[last], min);
+        Self::fix_child(node, last, min);
+        res
+    }
+
+    // refill the child at `idx` if it dropped below the minimum, borrowing from or merging with a sibling
+    fn fix_child(node:&mut Node<K, V>, idx:usize, min:usize) {
+        if node.children[idx].keys.len() >= min { return; }
+
+        if idx > 0 && node.children[idx - 1].keys.len() > min {
+            let (left, right) = node.children.split_at_mut(idx);
+            let (left, child) = (&mut left[idx - 1], &mut right[0]);
+
+            let key:K = std::mem::replace(&mut node.keys[idx - 1], left.keys.pop().unwrap());
+            let val:V = std::mem::replace(&mut node.vals[idx - 1], left.vals.pop().unwrap());
+            child.keys.insert(0, key);
+            child.vals.insert(0, val);
+            if let Some(grandchild) = left.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            return;
+        }
+
+        if idx + 1 < node.children.len() && node.children[idx + 1].keys.len() > min {
+            let (left, right) = node.children.split_at_mut(idx + 1);
+            let (child, right) = (&mut left[idx], &mut right[0]);
+
+            let key:K = std::mem::replace(&mut node.keys[idx], right.keys.remove(0));
+            let val:V = std::mem::replace(&mut node.vals[idx], right.vals.remove(0));
+            child.keys.push(key);
+            child.vals.push(val);
+            if !right.is_leaf() {
+                child.children.push(right.children.remove(0));
+            }
+            return;
+        }
+
+        // merge with a sibling, pulling the separator down between them
+        let left_idx:usize = if idx > 0 { idx - 1 } else { idx };
+        let right:Node<K, V> = node.children.remove(left_idx + 1);
+        let sep_key:K = node.keys.remove(left_idx);
+        let sep_val:V = node.vals.remove(left_idx);
+
+        let left:&mut Node<K, V> = &mut node.children[left_idx];
+        left.keys.push(sep_key);
+        left.vals.push(sep_val);
+
+        let Node { keys, vals, children } = right;
+        left.keys.extend(keys);
+        left.vals.extend(vals);
+        left.children.extend(children);
+    }
+
+    /// Iterate in key order over the entries with a key inside `range`
+    ///
+    /// #Example
+    /// ```
+    /// use trees::btree::BTree;
+    ///
+    /// let tree:BTree<i32, ()> = BTree::from_sorted(5, (0..50).map(|k| (k, ())));
+    ///
+    /// let keys:Vec<i32> = tree.range(10..=14).map(|(k, _)| *k).collect();
+    /// assert_eq!(keys, vec![10, 11, 12, 13, 14]);
+    /// ```
+    pub fn range<R>(&self, range:R) -> Range<'_, K, V>
+        where R: RangeBounds<K>, K: Clone
+    {
+        let mut iter:Iter<'_, K, V> = Iter { stack: Vec::new() };
+        let mut cur:Option<&Node<K, V>> = Some(&self.root);
+
+        while let Some(node) = cur {
+            let idx:usize = match range.start_bound() {
+                Bound::Included(start) => node.keys.partition_point(|k| k < start),
+                Bound::Excluded(start) => node.keys.partition_point(|k| k <= start),
+                Bound::Unbounded => 0
+            };
+
+            iter.stack.push((node, idx));
+            cur = node.children.get(idx);
+        }
+
+        Range { iter, end: range.end_bound().cloned() }
+    }
+}
+
+/// In-order iterator over a `BTree`
+pub struct Iter<'a, K, V> {
+    // nodes on the current path with the index of their next key to visit
+    stack:Vec<(&'a Node<K, V>, usize)>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, node:&'a Node<K, V>) {
+        let mut cur:Option<&'a Node<K, V>> = Some(node);
+        while let Some(node) = cur {
+            self.stack.push((node, 0));
+            cur = node.children.first();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, idx) = self.stack.pop()?;
+            if idx >= node.keys.len() { continue; }
+
+            self.stack.push((node, idx + 1));
+            if let Some(child) = node.children.get(idx + 1) {
+                self.push_left(child);
+            }
+
+            return Some((&node.keys[idx], &node.vals[idx]));
+        }
+    }
+}
+
+/// In-order iterator over the entries of a `BTree` inside a range
+pub struct Range<'a, K, V> {
+    iter:Iter<'a, K, V>,
+    end:Bound<K>,
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V>
+    where K: Ord
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, val) = self.iter.next()?;
+
+        let inside:bool = match &self.end {
+            Bound::Included(end) => key <= end,
+            Bound::Excluded(end) => key < end,
+            Bound::Unbounded => true
+        };
+        if !inside {
+            self.iter.stack.clear();
+            return None;
+        }
+
+        Some((key, val))
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for BTree<K, V>
+    where K: Ord
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter:I) -> Self {
+        let mut tree:BTree<K, V> = BTree::new();
+        for (key, val) in iter {
+            tree.insert(key, val);
+        }
+        tree
+    }
+}
+
+impl<K, V> Visualize for BTree<K, V>
+    where K: Debug, V: Debug
+{
+    /// The keys of every node
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("BTree");
+        if self.size > 0 {
+            diagram.add_tree(&self.root, |n| format!("{:?}", n.keys), |n| n.children.iter().map(Some));
+        }
+        diagram
+    }
+}
+
+impl<K, V> fmt::Display for BTree<K, V>
+    where K: Debug, V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
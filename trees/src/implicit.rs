@@ -0,0 +1,135 @@
+use std::{fmt::Debug, mem};
+
+use structs::{range::ops::Monoid, viz::Diagram};
+
+pub(crate) type Link<T, P> = Option<Box<Node<T, P>>>;
+
+/// Node of a tree keyed by position, shared by the splay tree and the implicit treap.
+/// `P` is whatever the balancing keeps per node: a heap priority for the treap, nothing for
+/// the splay tree
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Node<T, P> {
+    pub(crate) val: T,
+    // combined values of the subtree in order and in reverse order
+    pub(crate) agg: T,
+    pub(crate) agg_rev: T,
+    pub(crate) priority: P,
+    pub(crate) size: usize,
+    // the children of every node below still have to be swapped
+    pub(crate) rev: bool,
+    pub(crate) left: Link<T, P>,
+    pub(crate) right: Link<T, P>,
+}
+
+impl<T, P> Node<T, P>
+    where T: Clone
+{
+    pub(crate) fn new(val:T, priority:P) -> Self {
+        Self { agg: val.clone(), agg_rev: val.clone(), val, priority, size: 1, rev: false, left: None, right: None }
+    }
+
+    pub(crate) fn update<Op: Monoid<T>>(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+
+        let (left, left_rev) = aggs::<T, P, Op>(&self.left);
+        let (right, right_rev) = aggs::<T, P, Op>(&self.right);
+        self.agg = Op::combine(&Op::combine(&left, &self.val), &right);
+        self.agg_rev = Op::combine(&Op::combine(&right_rev, &self.val), &left_rev);
+    }
+
+    pub(crate) fn reverse(&mut self) {
+        self.rev = !self.rev;
+        mem::swap(&mut self.agg, &mut self.agg_rev);
+    }
+
+    pub(crate) fn push(&mut self) {
+        if !self.rev { return; }
+
+        mem::swap(&mut self.left, &mut self.right);
+        for child in [&mut self.left, &mut self.right].into_iter().flatten() {
+            child.reverse();
+        }
+        self.rev = false;
+    }
+}
+
+pub(crate) fn size<T, P>(link:&Link<T, P>) -> usize {
+    link.as_ref().map_or(0, |n| n.size)
+}
+
+fn aggs<T: Clone, P, Op: Monoid<T>>(link:&Link<T, P>) -> (T, T) {
+    match link {
+        Some(node) => (node.agg.clone(), node.agg_rev.clone()),
+        None => (Op::identity(), Op::identity())
+    }
+}
+
+/// How a tree keyed by position is cut and joined, which is all that keeps it balanced
+pub(crate) trait Balance {
+    type Priority;
+
+    /// First `at` values on the left, the rest on the right
+    fn split<T: Clone, Op: Monoid<T>>(link:Link<T, Self::Priority>, at:usize) -> (Link<T, Self::Priority>, Link<T, Self::Priority>);
+
+    fn merge<T: Clone, Op: Monoid<T>>(left:Link<T, Self::Priority>, right:Link<T, Self::Priority>) -> Link<T, Self::Priority>;
+}
+
+/// Run `f` on the subtree holding exactly `from..to`
+pub(crate) fn with_range<T, Op, B, R, F>(root:&mut Link<T, B::Priority>, from:usize, to:usize, f:F) -> Option<R>
+    where T: Clone, Op: Monoid<T>, B: Balance, F: FnOnce(&mut Node<T, B::Priority>) -> R
+{
+    let to:usize = to.min(size(root));
+    if from >= to { return None; }
+
+    let (rest, right) = B::split::<T, Op>(root.take(), to);
+    let (left, mut mid) = B::split::<T, Op>(rest, from);
+    let res:R = f(mid.as_mut().unwrap());
+    *root = B::merge::<T, Op>(B::merge::<T, Op>(left, mid), right);
+
+    Some(res)
+}
+
+/// Combine the values in `from..to`
+pub(crate) fn query<T, Op, B>(root:&mut Link<T, B::Priority>, from:usize, to:usize) -> T
+    where T: Clone, Op: Monoid<T>, B: Balance
+{
+    with_range::<T, Op, B, _, _>(root, from, to, |mid| mid.agg.clone()).unwrap_or_else(Op::identity)
+}
+
+/// Reverse the order of the values in `from..to`
+pub(crate) fn reverse<T, Op, B>(root:&mut Link<T, B::Priority>, from:usize, to:usize)
+    where T: Clone, Op: Monoid<T>, B: Balance
+{
+    with_range::<T, Op, B, _, _>(root, from, to, |mid| mid.reverse());
+}
+
+/// Values in order, without pushing the pending reversals down
+pub(crate) fn iter<T, P>(root:&Link<T, P>) -> impl Iterator<Item = &T> {
+    // nodes on the stack carry whether their children are swapped
+    let mut stack:Vec<(&Node<T, P>, bool)> = Vec::new();
+    let mut cur:Option<(&Node<T, P>, bool)> = root.as_deref().map(|n| (n, false));
+
+    std::iter::from_fn(move || {
+        while let Some((node, flip)) = cur {
+            let flip:bool = flip ^ node.rev;
+            stack.push((node, flip));
+            cur = if flip { &node.right } else { &node.left }.as_deref().map(|n| (n, flip));
+        }
+
+        let (node, flip) = stack.pop()?;
+        cur = if flip { &node.left } else { &node.right }.as_deref().map(|n| (n, flip));
+        Some(&node.val)
+    })
+}
+
+/// The tree as stored, nodes whose children still have to be swapped are marked with ↺
+pub(crate) fn diagram<T, P>(name:&str, root:&Link<T, P>) -> Diagram
+    where T: Debug
+{
+    let mut diagram:Diagram = Diagram::new(name);
+    if let Some(root) = root.as_deref() {
+        let label = |n:&Node<T, P>| if n.rev { format!("{:?} ↺", n.val) } else { format!("{:?}", n.val) };
+        diagram.add_tree(root, label, |n| [n.left.as_deref(), n.right.as_deref()]);
+    }
+    diagram
+}
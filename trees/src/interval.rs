@@ -0,0 +1,356 @@
+use core::fmt;
+use std::{cmp::Ordering, fmt::Debug, ops::Range};
+
+use structs::viz::{Diagram, Visualize};
+
+use crate::avl::node::{self, Augment};
+
+// nodes are keyed by their interval
+type Node<T, V> = node::Node<Range<T>, V, MaxEnd<T>>;
+type Link<T, V> = node::Link<Range<T>, V, MaxEnd<T>>;
+
+/// Largest end in the subtree
+#[derive(Clone, Debug)]
+struct MaxEnd<T>(T);
+
+impl<T, V> Augment<Range<T>, V> for MaxEnd<T>
+    where T: Ord + Clone
+{
+    fn leaf(range:&Range<T>, _:&V) -> Self {
+        MaxEnd(range.end.clone())
+    }
+
+    fn update(node:&mut Node<T, V>) {
+        let mut max:&T = &node.key.end;
+        for child in [&node.left, &node.right].into_iter().flatten() {
+            if child.aug.0 > *max {
+                max = &child.aug.0;
+            }
+        }
+        node.aug = MaxEnd(max.clone());
+    }
+}
+
+// intervals are ordered by start, then by end
+fn compare<T: Ord>(a:&Range<T>, b:&Range<T>) -> Ordering {
+    a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end))
+}
+
+fn insert<T: Ord + Clone, V>(link:Link<T, V>, range:Range<T>, val:V) -> (Box<Node<T, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        return (Box::new(Node::new(range, val)), None);
+    };
+
+    let old:Option<V> = match compare(&range, &node.key) {
+        Ordering::Equal => {
+            let old:V = std::mem::replace(&mut node.val, val);
+            return (node, Some(old));
+        },
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), range, val);
+            node.left = Some(left);
+            old
+        },
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), range, val);
+            node.right = Some(right);
+            old
+        }
+    };
+
+    (node::rebalance(node), old)
+}
+
+fn remove<T: Ord + Clone, V>(link:Link<T, V>, range:&Range<T>) -> (Link<T, V>, Option<V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    let removed:Option<V> = match compare(range, &node.key) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), range);
+            node.left = left;
+            removed
+        },
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), range);
+            node.right = right;
+            removed
+        },
+        Ordering::Equal => {
+            let (left, right) = (node.left.take(), node.right.take());
+            let Some(right) = right else {
+                return (left, Some(node.val));
+            };
+
+            let (right, mut succ) = node::remove_min(right);
+            succ.left = left;
+            succ.right = right;
+
+            return (Some(node::rebalance(succ)), Some(node.val));
+        }
+    };
+
+    (Some(node::rebalance(node)), removed)
+}
+
+/// Map from half-open intervals `start..end` to values, backed by an AVL tree ordered
+/// by start where every node also tracks the largest end below it, so the subtrees
+/// that cannot reach a point are skipped
+#[derive(Clone, Debug)]
+pub struct IntervalTree<T, V> {
+    root:Link<T, V>,
+    size:usize,
+}
+
+impl<T, V> Default for IntervalTree<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V> IntervalTree<T, V> {
+    pub fn new() -> Self {
+        Self { root: None, size: 0 }
+    }
+
+    /// Amount of intervals
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn height(&self) -> usize {
+        node::height(&self.root)
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.size = 0;
+    }
+
+    /// Iterate over the intervals ordered by start, then by end
+    pub fn iter(&self) -> impl Iterator<Item = (&Range<T>, &V)> {
+        let mut stack:Vec<&Node<T, V>> = Vec::new();
+        let mut cur:Option<&Node<T, V>> = self.root.as_deref();
+
+        std::iter::from_fn(move || {
+            while let Some(node) = cur {
+                stack.push(node);
+                cur = node.left.as_deref();
+            }
+
+            let node:&Node<T, V> = stack.pop()?;
+            cur = node.right.as_deref();
+            Some((&node.key, &node.val))
+        })
+    }
+}
+
+impl<T, V> IntervalTree<T, V>
+    where T: Ord + Clone
+{
+    /// Insert a value for `range`, returning the previous value stored for the same bounds.
+    /// Panics if the interval is empty
+    ///
+    /// #Example
+    /// ```
+    /// use trees::interval::IntervalTree;
+    ///
+    /// let mut tree:IntervalTree<u32, &str> = IntervalTree::new();
+    /// tree.insert(1..5, "a");
+    /// tree.insert(3..8, "b");
+    /// tree.insert(10..12, "c");
+    ///
+    /// let hits:Vec<&str> = tree.stabbing(&4).map(|(_, v)| *v).collect();
+    /// assert_eq!(hits, vec!["a", "b"]);
+    ///
+    /// // intervals are half-open, 5 is past the end of "a"
+    /// let hits:Vec<&str> = tree.stabbing(&5).map(|(_, v)| *v).collect();
+    /// assert_eq!(hits, vec!["b"]);
+    ///
+    /// assert_eq!(tree.remove(&(3..8)), Some("b"));
+    /// assert!(!tree.overlaps(&(5..10)));
+    /// ```
+    pub fn insert(&mut self, range:Range<T>, val:V) -> Option<V> {
+        assert!(range.start < range.end, "interval must not be empty");
+
+        let (root, old) = insert(self.root.take(), range, val);
+        self.root = Some(root);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    /// Remove the interval with exactly these bounds
+    pub fn remove(&mut self, range:&Range<T>) -> Option<V> {
+        let (root, removed) = remove(self.root.take(), range);
+        self.root = root;
+        if removed.is_some() {
+            self.size -= 1;
+        }
+        removed
+    }
+
+    fn node(&self, range:&Range<T>) -> Option<&Node<T, V>> {
+        let mut cur:Option<&Node<T, V>> = self.root.as_deref();
+
+        while let Some(node) = cur {
+            match compare(range, &node.key) {
+                Ordering::Less => cur = node.left.as_deref(),
+                Ordering::Equal => return Some(node),
+                Ordering::Greater => cur = node.right.as_deref()
+            }
+        }
+        None
+    }
+
+    pub fn get(&self, range:&Range<T>) -> Option<&V> {
+        self.node(range).map(|node| &node.val)
+    }
+
+    pub fn get_mut(&mut self, range:&Range<T>) -> Option<&mut V> {
+        let mut cur:Option<&mut Node<T, V>> = self.root.as_deref_mut();
+
+        while let Some(node) = cur {
+            match compare(range, &node.key) {
+                Ordering::Less => cur = node.left.as_deref_mut(),
+                Ordering::Equal => return Some(&mut node.val),
+                Ordering::Greater => cur = node.right.as_deref_mut()
+            }
+        }
+        None
+    }
+
+    pub fn contains(&self, range:&Range<T>) -> bool {
+        self.node(range).is_some()
+    }
+
+    /// Smallest interval covering every stored one
+    pub fn span(&self) -> Option<Range<T>> {
+        let root:&Node<T, V> = self.root.as_deref()?;
+
+        let mut first:&Node<T, V> = root;
+        while let Some(left) = first.left.as_deref() {
+            first = left;
+        }
+        Some(first.key.start.clone()..root.aug.0.clone())
+    }
+
+    // in-order walk over the intervals ending after `after` whose start satisfies `starts_before`,
+    // cutting the subtrees whose largest end is not past `after`
+    fn search<'a, F>(&'a self, after:T, starts_before:F) -> impl Iterator<Item = (&'a Range<T>, &'a V)>
+        where F: Fn(&T) -> bool + 'a
+    {
+        let mut stack:Vec<&Node<T, V>> = Vec::new();
+        let mut cur:Option<&Node<T, V>> = self.root.as_deref();
+
+        std::iter::from_fn(move || {
+            loop {
+                while let Some(node) = cur && node.aug.0 > after {
+                    stack.push(node);
+                    cur = node.left.as_deref();
+                }
+
+                let node:&Node<T, V> = stack.pop()?;
+                if !starts_before(&node.key.start) {
+                    // every interval left to visit starts even later
+                    stack.clear();
+                    return None;
+                }
+
+                cur = node.right.as_deref();
+                if node.key.end > after {
+                    return Some((&node.key, &node.val));
+                }
+            }
+        })
+    }
+
+    /// Intervals containing `point`, ordered by start
+    pub fn stabbing(&self, point:&T) -> impl Iterator<Item = (&Range<T>, &V)> {
+        let point:T = point.clone();
+        self.search(point.clone(), move |start| *start <= point)
+    }
+
+    /// Intervals sharing at least one point with `range`, ordered by start
+    ///
+    /// #Example
+    /// ```
+    /// use chrono::{DateTime, Duration, TimeZone, Utc};
+    /// use trees::interval::IntervalTree;
+    ///
+    /// let at = |h:u32| Utc.with_ymd_and_hms(2024, 3, 1, h, 0, 0).unwrap();
+    ///
+    /// let mut jobs:IntervalTree<DateTime<Utc>, &str> = IntervalTree::new();
+    /// jobs.insert(at(8)..at(10), "backup");
+    /// jobs.insert(at(9)..at(12), "reindex");
+    /// jobs.insert(at(13)..at(14), "report");
+    ///
+    /// let window = at(11)..at(11) + Duration::minutes(150);
+    /// let clashes:Vec<&str> = jobs.overlapping(&window).map(|(_, job)| *job).collect();
+    /// assert_eq!(clashes, vec!["reindex", "report"]);
+    /// ```
+    pub fn overlapping(&self, range:&Range<T>) -> impl Iterator<Item = (&Range<T>, &V)> {
+        let end:T = range.end.clone();
+        let empty:bool = range.start >= range.end;
+        self.search(range.start.clone(), move |start| !empty && *start < end)
+    }
+
+    /// Check if any interval shares a point with `range`
+    pub fn overlaps(&self, range:&Range<T>) -> bool {
+        self.overlapping(range).next().is_some()
+    }
+}
+
+impl<T, V> FromIterator<(Range<T>, V)> for IntervalTree<T, V>
+    where T: Ord + Clone
+{
+    fn from_iter<I: IntoIterator<Item = (Range<T>, V)>>(iter:I) -> Self {
+        let mut tree:IntervalTree<T, V> = IntervalTree::new();
+        for (range, val) in iter {
+            tree.insert(range, val);
+        }
+        tree
+    }
+}
+
+impl<T, V> PartialEq for IntervalTree<T, V>
+    where T: PartialEq, V: PartialEq
+{
+    /// Same intervals with the same values, however the trees are shaped
+    fn eq(&self, other:&Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T, V> Eq for IntervalTree<T, V>
+    where T: Eq, V: Eq
+{}
+
+impl<T, V> Visualize for IntervalTree<T, V>
+    where T: Debug, V: Debug
+{
+    /// Every interval with its value and the largest end below it
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("IntervalTree");
+        if let Some(root) = self.root.as_deref() {
+            diagram.add_tree(root, |n| format!("{:?}: {:?} (max {:?})", n.key, n.val, n.aug.0), |n| {
+                [n.left.as_deref(), n.right.as_deref()]
+            });
+        }
+        diagram
+    }
+}
+
+impl<T, V> fmt::Display for IntervalTree<T, V>
+    where T: Debug, V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,287 @@
+use core::fmt;
+
+use structs::{heap::MaxHeap, viz::{Diagram, Visualize}};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Node {
+    left: Option<usize>,
+    right: Option<usize>,
+    // coordinate the node splits its subtree on
+    axis: usize,
+}
+
+fn dist2<const D: usize>(a:&[f64; D], b:&[f64; D]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// Tree over points in `D` dimensions where every node splits the space in two along one
+/// coordinate, letting nearest neighbour searches skip the regions farther than the best
+/// candidates found so far.
+///
+/// Points are identified by their insertion order, the first one being 0
+#[derive(Clone, Debug, PartialEq)]
+pub struct KdTree<const D: usize> {
+    points:Vec<[f64; D]>,
+    // same index as the point it holds
+    nodes:Vec<Node>,
+    root:Option<usize>,
+}
+
+impl<const D: usize> Default for KdTree<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize> KdTree<D> {
+    pub fn new() -> Self {
+        assert!(D > 0, "points need at least one dimension");
+        Self { points: Vec::new(), nodes: Vec::new(), root: None }
+    }
+
+    /// Build a balanced tree in O(n log n), splitting every level at the median
+    ///
+    /// #Example
+    /// ```
+    /// use trees::kdtree::KdTree;
+    ///
+    /// let tree:KdTree<2> = KdTree::build(vec![[2.0, 3.0], [5.0, 4.0], [9.0, 6.0], [4.0, 7.0], [8.0, 1.0], [7.0, 2.0]]);
+    ///
+    /// let (id, dist) = tree.nearest(&[9.0, 2.0]).unwrap();
+    /// assert_eq!(id, 4);
+    /// assert!((dist - 1.4142).abs() < 1e-4);
+    ///
+    /// let ids:Vec<usize> = tree.k_nearest(&[6.0, 3.0], 3).into_iter().map(|(id, _)| id).collect();
+    /// assert_eq!(ids, vec![1, 5, 4]);
+    /// ```
+    pub fn build(points:Vec<[f64; D]>) -> Self {
+        let mut tree:Self = Self::new();
+        tree.nodes = vec![Node { left: None, right: None, axis: 0 }; points.len()];
+        tree.points = points;
+
+        let mut ids:Vec<usize> = (0..tree.points.len()).collect();
+        tree.root = tree.build_rec(&mut ids, 0);
+        tree
+    }
+
+    fn build_rec(&mut self, ids:&mut [usize], depth:usize) -> Option<usize> {
+        if ids.is_empty() { return None; }
+
+        let axis:usize = depth % D;
+        let mid:usize = ids.len() / 2;
+        let points:&Vec<[f64; D]> = &self.points;
+        ids.select_nth_unstable_by(mid, |a, b| points[*a][axis].total_cmp(&points[*b][axis]));
+
+        let id:usize = ids[mid];
+        let (left, right) = ids.split_at_mut(mid);
+        self.nodes[id] = Node {
+            left: self.build_rec(left, depth + 1),
+            right: self.build_rec(&mut right[1..], depth + 1),
+            axis
+        };
+
+        Some(id)
+    }
+
+    /// Amount of points
+    pub fn size(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn height(&self) -> usize {
+        let mut height:usize = 0;
+        let mut stack:Vec<(usize, usize)> = self.root.map(|id| (id, 1)).into_iter().collect();
+
+        while let Some((id, depth)) = stack.pop() {
+            height = height.max(depth);
+            for child in [self.nodes[id].left, self.nodes[id].right].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+
+    /// The point inserted with the given id
+    pub fn point(&self, id:usize) -> Option<&[f64; D]> {
+        self.points.get(id)
+    }
+
+    /// Every point, indexed by id
+    pub fn points(&self) -> &[[f64; D]] {
+        &self.points
+    }
+
+    /// Add a point below the leaf it falls into and return its id.
+    /// Inserting keeps no balance, rebuild after many of them
+    pub fn insert(&mut self, point:[f64; D]) -> usize {
+        let id:usize = self.points.len();
+        self.points.push(point);
+
+        let Some(mut cur) = self.root else {
+            self.nodes.push(Node { left: None, right: None, axis: 0 });
+            self.root = Some(id);
+            return id;
+        };
+
+        loop {
+            let node:Node = self.nodes[cur];
+            let go_left:bool = point[node.axis] < self.points[cur][node.axis];
+            let next:Option<usize> = if go_left { node.left } else { node.right };
+
+            match next {
+                Some(next) => cur = next,
+                None => {
+                    if go_left {
+                        self.nodes[cur].left = Some(id);
+                    } else {
+                        self.nodes[cur].right = Some(id);
+                    }
+                    self.nodes.push(Node { left: None, right: None, axis: (node.axis + 1) % D });
+                    return id;
+                }
+            }
+        }
+    }
+
+    /// Closest point to `query` with its distance
+    pub fn nearest(&self, query:&[f64; D]) -> Option<(usize, f64)> {
+        self.k_nearest(query, 1).pop()
+    }
+
+    /// The `k` closest points to `query` with their distances, closest first.
+    /// Candidates are kept in a bounded `MaxHeap` whose root is the worst of them,
+    /// any region farther than it is skipped once the heap is full
+    pub fn k_nearest(&self, query:&[f64; D], k:usize) -> Vec<(usize, f64)> {
+        if k == 0 { return Vec::new(); }
+
+        let mut heap:MaxHeap<(f64, usize)> = MaxHeap::with_capacity(k);
+        // nodes to visit with a lower bound of the squared distance to their region
+        let mut stack:Vec<(usize, f64)> = self.root.map(|id| (id, 0.0)).into_iter().collect();
+
+        while let Some((id, bound)) = stack.pop() {
+            if heap.size() == k && bound >= heap.get_max().unwrap().0 { continue; }
+
+            let point:&[f64; D] = &self.points[id];
+            let candidate:(f64, usize) = (dist2(query, point), id);
+            if heap.size() < k {
+                heap.push(candidate);
+            } else if &candidate < heap.get_max().unwrap() {
+                heap.set_max(&candidate);
+            }
+
+            let node:Node = self.nodes[id];
+            let diff:f64 = query[node.axis] - point[node.axis];
+            let (near, far) = if diff < 0.0 { (node.left, node.right) } else { (node.right, node.left) };
+
+            // the near side goes last so it is searched first
+            if let Some(far) = far {
+                stack.push((far, bound.max(diff * diff)));
+            }
+            if let Some(near) = near {
+                stack.push((near, bound));
+            }
+        }
+
+        heap.sort();
+        heap.to_vec().into_iter().rev().map(|(d, id)| (id, d.sqrt())).collect()
+    }
+
+    /// Points at distance at most `radius` from `query`, closest first
+    pub fn within_radius(&self, query:&[f64; D], radius:f64) -> Vec<(usize, f64)> {
+        let radius2:f64 = radius * radius;
+        let mut res:Vec<(usize, f64)> = Vec::new();
+        let mut stack:Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(id) = stack.pop() {
+            let point:&[f64; D] = &self.points[id];
+            let d2:f64 = dist2(query, point);
+            if d2 <= radius2 {
+                res.push((id, d2));
+            }
+
+            let node:Node = self.nodes[id];
+            let diff:f64 = query[node.axis] - point[node.axis];
+            // coordinates equal to the split may end up on either side
+            if let Some(left) = node.left && diff <= radius {
+                stack.push(left);
+            }
+            if let Some(right) = node.right && diff >= -radius {
+                stack.push(right);
+            }
+        }
+
+        res.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        res.into_iter().map(|(id, d2)| (id, d2.sqrt())).collect()
+    }
+
+    /// Ids of the points inside the box spanning from `min` to `max`, bounds included
+    ///
+    /// #Example
+    /// ```
+    /// use trees::kdtree::KdTree;
+    ///
+    /// let mut tree:KdTree<3> = KdTree::new();
+    /// for point in [[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [2.0, 2.0, 2.0], [5.0, 1.0, 1.0]] {
+    ///     tree.insert(point);
+    /// }
+    ///
+    /// assert_eq!(tree.range(&[0.5, 0.5, 0.5], &[2.0, 3.0, 3.0]), vec![1, 2]);
+    /// assert_eq!(tree.within_radius(&[1.0, 1.0, 1.0], 2.0).len(), 2);
+    /// ```
+    pub fn range(&self, min:&[f64; D], max:&[f64; D]) -> Vec<usize> {
+        let mut res:Vec<usize> = Vec::new();
+        let mut stack:Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(id) = stack.pop() {
+            let point:&[f64; D] = &self.points[id];
+            if (0..D).all(|i| min[i] <= point[i] && point[i] <= max[i]) {
+                res.push(id);
+            }
+
+            let node:Node = self.nodes[id];
+            let split:f64 = point[node.axis];
+            if let Some(left) = node.left && min[node.axis] <= split {
+                stack.push(left);
+            }
+            if let Some(right) = node.right && max[node.axis] >= split {
+                stack.push(right);
+            }
+        }
+
+        res.sort();
+        res
+    }
+}
+
+impl<const D: usize> FromIterator<[f64; D]> for KdTree<D> {
+    fn from_iter<I: IntoIterator<Item = [f64; D]>>(iter:I) -> Self {
+        Self::build(iter.into_iter().collect())
+    }
+}
+
+impl<const D: usize> Visualize for KdTree<D> {
+    /// Every point with its id and the axis it splits on
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("KdTree");
+        if let Some(root) = self.root {
+            diagram.add_tree(root, |id| format!("#{} {:?} axis {}", id, self.points[id], self.nodes[id].axis), |id| {
+                [self.nodes[id].left, self.nodes[id].right]
+            });
+        }
+        diagram
+    }
+}
+
+impl<const D: usize> fmt::Display for KdTree<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.points.iter().enumerate()).finish()
+    }
+}
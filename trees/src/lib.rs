@@ -0,0 +1,16 @@
+pub mod avl {
+    pub mod map;
+    pub mod node;
+    pub mod set;
+}
+
+pub mod btree;
+pub mod bplus;
+pub mod ost;
+pub mod trie;
+pub mod radix;
+pub mod interval;
+mod implicit;
+pub mod splay;
+pub mod treap;
+pub mod kdtree;
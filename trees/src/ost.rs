@@ -0,0 +1,319 @@
+use core::fmt;
+use std::{cmp::Ordering, fmt::Debug};
+
+use structs::viz::{Diagram, Visualize};
+
+use crate::avl::node::{self, Augment};
+
+// the value of a node is the amount of copies of its key
+type Node<T> = node::Node<T, usize, Size>;
+type Link<T> = node::Link<T, usize, Size>;
+
+/// Elements in the subtree, copies included
+#[derive(Clone, Debug)]
+struct Size(usize);
+
+impl<T> Augment<T, usize> for Size {
+    fn leaf(_:&T, count:&usize) -> Self {
+        Size(*count)
+    }
+
+    fn update(node:&mut Node<T>) {
+        node.aug = Size(node.val + size(&node.left) + size(&node.right));
+    }
+}
+
+fn size<T>(link:&Link<T>) -> usize {
+    link.as_ref().map_or(0, |n| n.aug.0)
+}
+
+fn insert<T: Ord>(link:Link<T>, val:T) -> Box<Node<T>> {
+    let Some(mut node) = link else {
+        return Box::new(Node::new(val, 1));
+    };
+
+    match val.cmp(&node.key) {
+        Ordering::Equal => node.val += 1,
+        Ordering::Less => node.left = Some(insert(node.left.take(), val)),
+        Ordering::Greater => node.right = Some(insert(node.right.take(), val))
+    }
+
+    node::rebalance(node)
+}
+
+// remove one copy of `val`, the flag tells if it was found
+fn remove<T: Ord>(link:Link<T>, val:&T) -> (Link<T>, bool) {
+    let Some(mut node) = link else {
+        return (None, false);
+    };
+
+    let found:bool = match val.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, found) = remove(node.left.take(), val);
+            node.left = left;
+            found
+        },
+        Ordering::Greater => {
+            let (right, found) = remove(node.right.take(), val);
+            node.right = right;
+            found
+        },
+        Ordering::Equal if node.val > 1 => {
+            node.val -= 1;
+            true
+        },
+        Ordering::Equal => {
+            let (left, right) = (node.left.take(), node.right.take());
+            let Some(right) = right else {
+                return (left, true);
+            };
+
+            let (right, mut succ) = node::remove_min(right);
+            succ.left = left;
+            succ.right = right;
+
+            return (Some(node::rebalance(succ)), true);
+        }
+    };
+
+    (Some(node::rebalance(node)), found)
+}
+
+/// Sorted multiset backed by an AVL tree whose nodes also track their subtree size,
+/// so the position of any element can be found in O(log n)
+#[derive(Clone, Debug)]
+pub struct OrderStatTree<T> {
+    root:Link<T>,
+}
+
+impl<T> Default for OrderStatTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> OrderStatTree<T> {
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// Amount of elements, duplicates included
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+    }
+
+    /// The `k`-th smallest element, counting from 0
+    ///
+    /// #Example
+    /// ```
+    /// use trees::ost::OrderStatTree;
+    ///
+    /// let mut tree:OrderStatTree<i32> = vec![50, 10, 40, 10, 30].into_iter().collect();
+    ///
+    /// assert_eq!(tree.select(0), Some(&10));
+    /// assert_eq!(tree.select(1), Some(&10));
+    /// assert_eq!(tree.select(2), Some(&30));
+    /// assert_eq!(tree.rank(&40), 3);
+    ///
+    /// tree.remove(&10);
+    /// assert_eq!(tree.select(1), Some(&30));
+    /// assert_eq!(tree.rank(&40), 2);
+    /// ```
+    pub fn select(&self, mut k:usize) -> Option<&T> {
+        let mut cur:&Node<T> = self.root.as_deref()?;
+
+        loop {
+            let left:usize = size(&cur.left);
+            if k < left {
+                cur = cur.left.as_deref()?;
+            } else if k < left + cur.val {
+                return Some(&cur.key);
+            } else {
+                k -= left + cur.val;
+                cur = cur.right.as_deref()?;
+            }
+        }
+    }
+
+    pub fn min(&self) -> Option<&T> {
+        self.select(0)
+    }
+
+    pub fn max(&self) -> Option<&T> {
+        self.select(self.size().checked_sub(1)?)
+    }
+
+    /// Element at the given fraction of the sorted order, e.g. 0.5 for the median
+    pub fn quantile(&self, q:f64) -> Option<&T> {
+        if self.is_empty() { return None; }
+
+        let idx:usize = (q.clamp(0.0, 1.0) * (self.size() - 1) as f64).round() as usize;
+        self.select(idx)
+    }
+
+    /// Iterate over the elements in order, repeating duplicates
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut stack:Vec<&Node<T>> = Vec::new();
+        let mut cur:Option<&Node<T>> = self.root.as_deref();
+
+        std::iter::from_fn(move || {
+            while let Some(node) = cur {
+                stack.push(node);
+                cur = node.left.as_deref();
+            }
+
+            let node:&Node<T> = stack.pop()?;
+            cur = node.right.as_deref();
+            Some((node.val, &node.key))
+        }).flat_map(|(count, val)| std::iter::repeat_n(val, count))
+    }
+
+    /// The `k` smallest elements in order
+    pub fn smallest(&self, k:usize) -> Vec<T>
+        where T: Clone
+    {
+        self.iter().take(k).cloned().collect()
+    }
+}
+
+impl<T> OrderStatTree<T>
+    where T: Ord
+{
+    pub fn insert(&mut self, val:T) {
+        self.root = Some(insert(self.root.take(), val));
+    }
+
+    /// Remove one copy of `val`, returns false if it was not present
+    pub fn remove(&mut self, val:&T) -> bool {
+        let (root, found) = remove(self.root.take(), val);
+        self.root = root;
+        found
+    }
+
+    /// Remove one copy of the largest element
+    pub fn pop_max(&mut self) -> Option<T>
+        where T: Clone
+    {
+        let max:T = self.max()?.clone();
+        self.remove(&max);
+        Some(max)
+    }
+
+    /// Amount of elements strictly less than `val`
+    pub fn rank(&self, val:&T) -> usize {
+        let mut res:usize = 0;
+        let mut cur:Option<&Node<T>> = self.root.as_deref();
+
+        while let Some(node) = cur {
+            match val.cmp(&node.key) {
+                Ordering::Less => cur = node.left.as_deref(),
+                Ordering::Equal => return res + size(&node.left),
+                Ordering::Greater => {
+                    res += size(&node.left) + node.val;
+                    cur = node.right.as_deref();
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Copies of `val` in the tree
+    pub fn count(&self, val:&T) -> usize {
+        let mut cur:Option<&Node<T>> = self.root.as_deref();
+
+        while let Some(node) = cur {
+            match val.cmp(&node.key) {
+                Ordering::Less => cur = node.left.as_deref(),
+                Ordering::Equal => return node.val,
+                Ordering::Greater => cur = node.right.as_deref()
+            }
+        }
+
+        0
+    }
+
+    pub fn contains(&self, val:&T) -> bool {
+        self.count(val) > 0
+    }
+}
+
+impl<T> FromIterator<T> for OrderStatTree<T>
+    where T: Ord
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter:I) -> Self {
+        let mut tree:OrderStatTree<T> = OrderStatTree::new();
+        for val in iter {
+            tree.insert(val);
+        }
+        tree
+    }
+}
+
+impl<T> PartialEq for OrderStatTree<T>
+    where T: PartialEq
+{
+    /// Same elements with the same copies, however the trees are shaped
+    fn eq(&self, other:&Self) -> bool {
+        self.size() == other.size() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for OrderStatTree<T>
+    where T: Eq
+{}
+
+impl<T> Visualize for OrderStatTree<T>
+    where T: Debug
+{
+    /// Every value with its copies and the size of its subtree
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("OrderStatTree");
+        if let Some(root) = self.root.as_deref() {
+            diagram.add_tree(root, |n| format!("{:?} x{} (size {})", n.key, n.val, n.aug.0), |n| {
+                [n.left.as_deref(), n.right.as_deref()]
+            });
+        }
+        diagram
+    }
+}
+
+impl<T> fmt::Display for OrderStatTree<T>
+    where T: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub trait FindSmallest<T> {
+    fn find_smallest(&self, k:usize) -> Vec<T>;
+}
+
+impl<T> FindSmallest<T> for [T]
+    where T: Ord + Clone
+{
+    /// Keep the `k` smallest values seen so far in the tree, evicting the largest when it overflows
+    fn find_smallest(&self, k:usize) -> Vec<T> {
+        if k == 0 { return Vec::new(); }
+
+        let mut tree:OrderStatTree<T> = OrderStatTree::new();
+        for val in self.iter() {
+            if tree.size() >= k {
+                if val >= tree.max().unwrap() { continue; }
+                tree.pop_max();
+            }
+            tree.insert(val.clone());
+        }
+
+        tree.smallest(k)
+    }
+}
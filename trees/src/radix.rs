@@ -0,0 +1,312 @@
+use core::fmt;
+use std::fmt::Debug;
+
+use structs::viz::{Diagram, Visualize};
+
+use crate::{avl::map::AvlMap, trie::top_k};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node<V> {
+    // edge label leading into this node, never empty except for the root
+    label:String,
+    val:Option<V>,
+    // keyed by the first character of the child label
+    children:AvlMap<char, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new(label:String, val:Option<V>) -> Self {
+        Self { label, val, children: AvlMap::new() }
+    }
+
+    // absorb the only child of a node without value, joining both labels
+    fn merge_child(&mut self) {
+        if self.val.is_some() || self.children.size() != 1 { return; }
+
+        let (_, child) = self.children.pop_first().unwrap();
+        self.label.push_str(&child.label);
+        self.val = child.val;
+        self.children = child.children;
+    }
+}
+
+// length in bytes of the common prefix of `a` and `b`
+fn common_prefix(a:&str, b:&str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, ca), cb)| ca != cb)
+        .map_or(a.len().min(b.len()), |((i, _), _)| i)
+}
+
+/// Compressed prefix tree: chains of single-child nodes are merged into one edge,
+/// so the tree has at most 2n nodes regardless of the key lengths
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadixTree<V> {
+    root:Node<V>,
+    size:usize,
+}
+
+impl<V> Default for RadixTree<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> RadixTree<V> {
+    pub fn new() -> Self {
+        Self { root: Node::new(String::new(), None), size: 0 }
+    }
+
+    /// Amount of keys
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Insert a value, returning the previous value of the key
+    ///
+    /// #Example
+    /// ```
+    /// use trees::radix::RadixTree;
+    ///
+    /// let mut tree:RadixTree<u32> = RadixTree::new();
+    /// tree.insert("romane", 1);
+    /// tree.insert("romanus", 2);
+    /// tree.insert("romulus", 3);
+    /// tree.insert("rubens", 4);
+    ///
+    /// assert_eq!(tree.get("romanus"), Some(&2));
+    /// assert_eq!(tree.get("roman"), None);
+    /// assert_eq!(tree.remove("romane"), Some(1));
+    ///
+    /// let keys:Vec<String> = tree.iter_prefix("rom").map(|(k, _)| k).collect();
+    /// assert_eq!(keys, vec!["romanus", "romulus"]);
+    /// ```
+    pub fn insert(&mut self, key:&str, val:V) -> Option<V> {
+        let old:Option<V> = Self::insert_rec(&mut self.root, key, val);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    fn insert_rec(node:&mut Node<V>, key:&str, val:V) -> Option<V> {
+        let Some(first) = key.chars().next() else {
+            return node.val.replace(val);
+        };
+
+        let Some(child) = node.children.get_mut(&first) else {
+            node.children.insert(first, Node::new(key.to_string(), Some(val)));
+            return None;
+        };
+
+        let common:usize = common_prefix(&child.label, key);
+        if common < child.label.len() {
+            // split the edge at the point where the keys diverge
+            let rest:String = child.label.split_off(common);
+            let mut lower:Node<V> = Node::new(rest, child.val.take());
+            lower.children = std::mem::take(&mut child.children);
+
+            let lower_first:char = lower.label.chars().next().unwrap();
+            child.children.insert(lower_first, lower);
+        }
+
+        Self::insert_rec(child, &key[common..], val)
+    }
+
+    // node reached by following `key` exactly
+    fn node(&self, key:&str) -> Option<&Node<V>> {
+        let mut cur:&Node<V> = &self.root;
+        let mut rest:&str = key;
+
+        while let Some(first) = rest.chars().next() {
+            cur = cur.children.get(&first)?;
+            rest = rest.strip_prefix(cur.label.as_str())?;
+        }
+        Some(cur)
+    }
+
+    pub fn get(&self, key:&str) -> Option<&V> {
+        self.node(key)?.val.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key:&str) -> Option<&mut V> {
+        let mut cur:&mut Node<V> = &mut self.root;
+        let mut rest:&str = key;
+
+        while let Some(first) = rest.chars().next() {
+            cur = cur.children.get_mut(&first)?;
+            rest = rest.strip_prefix(cur.label.as_str())?;
+        }
+        cur.val.as_mut()
+    }
+
+    pub fn contains_key(&self, key:&str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Check if any key starts with `prefix`
+    pub fn starts_with(&self, prefix:&str) -> bool {
+        self.prefix_node(prefix).is_some()
+    }
+
+    /// Remove `key`, merging the nodes left with a single child
+    pub fn remove(&mut self, key:&str) -> Option<V> {
+        let val:V = Self::remove_rec(&mut self.root, key)?;
+        self.size -= 1;
+        Some(val)
+    }
+
+    fn remove_rec(node:&mut Node<V>, key:&str) -> Option<V> {
+        let Some(first) = key.chars().next() else {
+            return node.val.take();
+        };
+
+        let child:&mut Node<V> = node.children.get_mut(&first)?;
+        let rest:&str = key.strip_prefix(child.label.as_str())?;
+        let val:V = Self::remove_rec(child, rest)?;
+
+        if child.val.is_none() && child.children.is_empty() {
+            node.children.remove(&first);
+        } else {
+            child.merge_child();
+        }
+
+        Some(val)
+    }
+
+    /// Longest prefix of `query` that is a key, with its value
+    pub fn longest_prefix<'q>(&self, query:&'q str) -> Option<(&'q str, &V)> {
+        let mut best:Option<(&'q str, &V)> = self.root.val.as_ref().map(|v| (&query[..0], v));
+        let mut cur:&Node<V> = &self.root;
+        let mut used:usize = 0;
+
+        while let Some(first) = query[used..].chars().next() {
+            let Some(child) = cur.children.get(&first) else {
+                break;
+            };
+            if !query[used..].starts_with(child.label.as_str()) { break; }
+
+            cur = child;
+            used += child.label.len();
+            if let Some(val) = &cur.val {
+                best = Some((&query[..used], val));
+            }
+        }
+
+        best
+    }
+
+    // node whose subtree holds exactly the keys starting with `prefix`, with the key leading to it
+    fn prefix_node(&self, prefix:&str) -> Option<(String, &Node<V>)> {
+        let mut cur:&Node<V> = &self.root;
+        let mut path:String = String::new();
+        let mut rest:&str = prefix;
+
+        while let Some(first) = rest.chars().next() {
+            cur = cur.children.get(&first)?;
+            path.push_str(&cur.label);
+
+            if let Some(after) = rest.strip_prefix(cur.label.as_str()) {
+                rest = after;
+            } else if cur.label.starts_with(rest) {
+                // the prefix ends inside this edge
+                break;
+            } else {
+                return None;
+            }
+        }
+
+        Some((path, cur))
+    }
+
+    /// Iterate in lexicographic order over every key and its value
+    pub fn iter(&self) -> impl Iterator<Item = (String, &V)> {
+        self.iter_prefix("")
+    }
+
+    /// Iterate in lexicographic order over the keys starting with `prefix`
+    pub fn iter_prefix(&self, prefix:&str) -> impl Iterator<Item = (String, &V)> {
+        let mut stack:Vec<(String, &Node<V>)> = Vec::new();
+        if let Some(start) = self.prefix_node(prefix) {
+            stack.push(start);
+        }
+
+        std::iter::from_fn(move || {
+            while let Some((key, node)) = stack.pop() {
+                let children:Vec<&Node<V>> = node.children.values().collect();
+                for child in children.into_iter().rev() {
+                    stack.push((format!("{}{}", key, child.label), child));
+                }
+
+                if let Some(val) = &node.val {
+                    return Some((key, val));
+                }
+            }
+            None
+        })
+    }
+
+    /// The `k` keys starting with `prefix` with the highest values, best first.
+    /// Ties are broken alphabetically
+    pub fn top_completions(&self, prefix:&str, k:usize) -> Vec<(String, &V)>
+        where V: PartialOrd + Clone
+    {
+        top_k(self.iter_prefix(prefix), k)
+    }
+
+    /// Amount of nodes, root included
+    pub fn nodes(&self) -> usize {
+        let mut count:usize = 0;
+        let mut stack:Vec<&Node<V>> = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            count += 1;
+            stack.extend(node.children.values());
+        }
+        count
+    }
+}
+
+impl<V> FromIterator<(String, V)> for RadixTree<V> {
+    fn from_iter<I: IntoIterator<Item = (String, V)>>(iter:I) -> Self {
+        let mut tree:RadixTree<V> = RadixTree::new();
+        for (key, val) in iter {
+            tree.insert(&key, val);
+        }
+        tree
+    }
+}
+
+impl<V> Visualize for RadixTree<V>
+    where V: Debug
+{
+    /// One node per edge label, with the value of the keys ending there
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("RadixTree");
+
+        let label = |node:&Node<V>| {
+            let label:&str = if node.label.is_empty() { "·" } else { &node.label };
+            match &node.val {
+                Some(val) => format!("{} = {:?}", label, val),
+                None => label.to_string()
+            }
+        };
+        diagram.add_tree(&self.root, label, |node| node.children.values().map(Some).collect::<Vec<_>>());
+        diagram
+    }
+}
+
+impl<V> fmt::Display for RadixTree<V>
+    where V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
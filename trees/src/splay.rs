@@ -0,0 +1,311 @@
+use core::fmt;
+use std::{fmt::Debug, marker::PhantomData, mem};
+
+use structs::{range::ops::Monoid, viz::{Diagram, Visualize}};
+
+use crate::implicit::{self, size, Balance};
+
+type Link<T> = implicit::Link<T, ()>;
+type Node<T> = implicit::Node<T, ()>;
+
+/// Top-down splay bringing the `k`-th node of the subtree to its root.
+/// The nodes left of the path are gathered in `lefts` and the ones right of it in `rights`,
+/// then hung back below the new root
+fn splay<T: Clone, Op: Monoid<T>>(mut root:Box<Node<T>>, mut k:usize) -> Box<Node<T>> {
+    let mut lefts:Vec<Box<Node<T>>> = Vec::new();
+    let mut rights:Vec<Box<Node<T>>> = Vec::new();
+
+    loop {
+        root.push();
+        let left_size:usize = size(&root.left);
+
+        if k < left_size {
+            let mut child:Box<Node<T>> = root.left.take().unwrap();
+            child.push();
+
+            if k < size(&child.left) {
+                // zig-zig, rotate right before linking
+                root.left = child.right.take();
+                root.update::<Op>();
+                child.right = Some(root);
+                root = child;
+                child = root.left.take().unwrap();
+            }
+
+            rights.push(root);
+            root = child;
+        } else if k > left_size {
+            k -= left_size + 1;
+            let mut child:Box<Node<T>> = root.right.take().unwrap();
+            child.push();
+
+            if k > size(&child.left) {
+                // zig-zig, rotate left before linking
+                k -= size(&child.left) + 1;
+                root.right = child.left.take();
+                root.update::<Op>();
+                child.left = Some(root);
+                root = child;
+                child = root.right.take().unwrap();
+            }
+
+            lefts.push(root);
+            root = child;
+        } else {
+            break;
+        }
+    }
+
+    let mut acc:Link<T> = root.left.take();
+    for mut node in lefts.into_iter().rev() {
+        node.right = acc;
+        node.update::<Op>();
+        acc = Some(node);
+    }
+    root.left = acc;
+
+    let mut acc:Link<T> = root.right.take();
+    for mut node in rights.into_iter().rev() {
+        node.left = acc;
+        node.update::<Op>();
+        acc = Some(node);
+    }
+    root.right = acc;
+
+    root.update::<Op>();
+    root
+}
+
+// splits and merges splay the node at the cut to the root
+struct Splay;
+
+impl Balance for Splay {
+    type Priority = ();
+
+    fn split<T: Clone, Op: Monoid<T>>(link:Link<T>, at:usize) -> (Link<T>, Link<T>) {
+        let Some(root) = link else {
+            return (None, None);
+        };
+        if at >= root.size { return (Some(root), None); }
+
+        let mut root:Box<Node<T>> = splay::<T, Op>(root, at);
+        let left:Link<T> = root.left.take();
+        root.update::<Op>();
+
+        (left, Some(root))
+    }
+
+    fn merge<T: Clone, Op: Monoid<T>>(left:Link<T>, right:Link<T>) -> Link<T> {
+        let Some(left) = left else {
+            return right;
+        };
+
+        let last:usize = left.size - 1;
+        let mut root:Box<Node<T>> = splay::<T, Op>(left, last);
+        root.right = right;
+        root.update::<Op>();
+
+        Some(root)
+    }
+}
+
+/// Sequence stored in a splay tree keyed by position. Every operation splays the nodes it
+/// touches to the root, so runs of nearby accesses are fast and any sequence of operations
+/// costs O(log n) amortized each.
+///
+/// Values are combined with the monoid `Op`, the aggregate of a reversed range stays
+/// correct even when `Op` is not commutative
+pub struct SplayTree<T, Op>
+    where Op: Monoid<T>
+{
+    root:Link<T>,
+    op:PhantomData<Op>,
+}
+
+impl<T, Op> Default for SplayTree<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Op> SplayTree<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    pub fn new() -> Self {
+        Self { root: None, op: PhantomData }
+    }
+
+    fn from_link(root:Link<T>) -> Self {
+        Self { root, op: PhantomData }
+    }
+
+    /// Build a balanced tree holding `vals` in order, in O(n)
+    pub fn from_vec(vals:Vec<T>) -> Self {
+        let size:usize = vals.len();
+        Self::from_link(Self::build(&mut vals.into_iter(), size))
+    }
+
+    // subtree over the next `size` values of `vals`
+    fn build(vals:&mut impl Iterator<Item = T>, size:usize) -> Link<T> {
+        if size == 0 { return None; }
+
+        let left:Link<T> = Self::build(vals, size / 2);
+        let mut node:Box<Node<T>> = Box::new(Node::new(vals.next().unwrap(), ()));
+        node.left = left;
+        node.right = Self::build(vals, size - size / 2 - 1);
+        node.update::<Op>();
+
+        Some(node)
+    }
+
+    /// Amount of values
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Get the value at `idx`, splaying it to the root
+    pub fn get(&mut self, idx:usize) -> Option<&T> {
+        if idx >= self.size() { return None; }
+
+        let root:Box<Node<T>> = splay::<T, Op>(self.root.take().unwrap(), idx);
+        Some(&self.root.insert(root).val)
+    }
+
+    /// Replace the value at `idx`, returning the old one
+    pub fn set(&mut self, idx:usize, val:T) -> T {
+        assert!(idx < self.size(), "index {} out of bounds for size {}", idx, self.size());
+
+        let mut root:Box<Node<T>> = splay::<T, Op>(self.root.take().unwrap(), idx);
+        let old:T = mem::replace(&mut root.val, val);
+        root.update::<Op>();
+        self.root = Some(root);
+        old
+    }
+
+    /// Insert `val` so that it ends up at `idx`
+    pub fn insert(&mut self, idx:usize, val:T) {
+        assert!(idx <= self.size(), "index {} out of bounds for size {}", idx, self.size());
+
+        let (left, right) = Splay::split::<T, Op>(self.root.take(), idx);
+        let mut node:Box<Node<T>> = Box::new(Node::new(val, ()));
+        node.left = left;
+        node.right = right;
+        node.update::<Op>();
+        self.root = Some(node);
+    }
+
+    pub fn push(&mut self, val:T) {
+        self.insert(self.size(), val);
+    }
+
+    /// Remove the value at `idx`
+    pub fn remove(&mut self, idx:usize) -> Option<T> {
+        if idx >= self.size() { return None; }
+
+        let mut root:Box<Node<T>> = splay::<T, Op>(self.root.take().unwrap(), idx);
+        self.root = Splay::merge::<T, Op>(root.left.take(), root.right.take());
+        Some(root.val)
+    }
+
+    /// Split into the first `at` values and the rest
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::ops::Sum;
+    /// use trees::splay::SplayTree;
+    ///
+    /// let seq:SplayTree<i32, Sum> = SplayTree::from_vec(vec![1, 2, 3, 4, 5]);
+    /// let (mut left, mut right) = seq.split(2);
+    ///
+    /// assert_eq!(left.to_vec(), vec![1, 2]);
+    /// assert_eq!(right.query(0, 3), 12);
+    ///
+    /// let mut seq:SplayTree<i32, Sum> = right.merge(left);
+    /// seq.reverse(0, 3);
+    /// assert_eq!(seq.to_vec(), vec![5, 4, 3, 1, 2]);
+    /// ```
+    pub fn split(mut self, at:usize) -> (Self, Self) {
+        let (left, right) = Splay::split::<T, Op>(self.root.take(), at);
+        (Self::from_link(left), Self::from_link(right))
+    }
+
+    /// Append the values of `other` after the values of `self`
+    pub fn merge(mut self, mut other:Self) -> Self {
+        Self::from_link(Splay::merge::<T, Op>(self.root.take(), other.root.take()))
+    }
+
+    /// Combine the values in `from..to`
+    pub fn query(&mut self, from:usize, to:usize) -> T {
+        implicit::query::<T, Op, Splay>(&mut self.root, from, to)
+    }
+
+    /// Reverse the order of the values in `from..to`
+    pub fn reverse(&mut self, from:usize, to:usize) {
+        implicit::reverse::<T, Op, Splay>(&mut self.root, from, to);
+    }
+
+    /// Iterate over the values in order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        implicit::iter(&self.root)
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, Op> FromIterator<T> for SplayTree<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter:I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T, Op> Clone for SplayTree<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    fn clone(&self) -> Self {
+        Self::from_vec(self.to_vec())
+    }
+}
+
+impl<T, Op> Drop for SplayTree<T, Op>
+    where Op: Monoid<T>
+{
+    // splaying can leave paths as long as the sequence, drop without recursing down them
+    fn drop(&mut self) {
+        let mut stack:Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<T, Op> Visualize for SplayTree<T, Op>
+    where T: Clone + Debug, Op: Monoid<T>
+{
+    /// The tree as stored, nodes whose children still have to be swapped are marked with ↺
+    fn diagram(&self) -> Diagram {
+        implicit::diagram("SplayTree", &self.root)
+    }
+}
+
+impl<T, Op> fmt::Display for SplayTree<T, Op>
+    where T: Clone + Debug, Op: Monoid<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
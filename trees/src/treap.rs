@@ -0,0 +1,286 @@
+use core::fmt;
+use std::{collections::hash_map::RandomState, fmt::Debug, hash::{BuildHasher, Hasher}, marker::PhantomData, mem};
+
+use structs::{range::ops::Monoid, viz::{Diagram, Visualize}};
+
+use crate::implicit::{self, size, Balance};
+
+// every node keeps a max-heap ordered priority, which keeps the tree balanced in expectation
+type Link<T> = implicit::Link<T, u64>;
+type Node<T> = implicit::Node<T, u64>;
+
+// splits and merges follow the priorities down
+struct Treap;
+
+impl Balance for Treap {
+    type Priority = u64;
+
+    fn split<T: Clone, Op: Monoid<T>>(link:Link<T>, at:usize) -> (Link<T>, Link<T>) {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+        node.push();
+
+        let left_size:usize = size(&node.left);
+        if at <= left_size {
+            let (left, right) = Self::split::<T, Op>(node.left.take(), at);
+            node.left = right;
+            node.update::<Op>();
+            (left, Some(node))
+        } else {
+            let (left, right) = Self::split::<T, Op>(node.right.take(), at - left_size - 1);
+            node.right = left;
+            node.update::<Op>();
+            (Some(node), right)
+        }
+    }
+
+    fn merge<T: Clone, Op: Monoid<T>>(left:Link<T>, right:Link<T>) -> Link<T> {
+        match (left, right) {
+            (None, right) => right,
+            (left, None) => left,
+            (Some(mut left), Some(mut right)) => {
+                if left.priority > right.priority {
+                    left.push();
+                    left.right = Self::merge::<T, Op>(left.right.take(), Some(right));
+                    left.update::<Op>();
+                    Some(left)
+                } else {
+                    right.push();
+                    right.left = Self::merge::<T, Op>(Some(left), right.left.take());
+                    right.update::<Op>();
+                    Some(right)
+                }
+            }
+        }
+    }
+}
+
+/// Sequence stored in a treap keyed by position instead of by value: every node gets a
+/// random priority and the tree is a heap on them, so it stays O(log n) deep in expectation
+/// and splitting or joining sequences takes O(log n).
+///
+/// Values are combined with the monoid `Op`, the aggregate of a reversed range stays
+/// correct even when `Op` is not commutative
+pub struct ImplicitTreap<T, Op>
+    where Op: Monoid<T>
+{
+    root:Link<T>,
+    // xorshift state for the priorities
+    seed:u64,
+    op:PhantomData<Op>,
+}
+
+impl<T, Op> Default for ImplicitTreap<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Op> ImplicitTreap<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    pub fn new() -> Self {
+        let seed:u64 = RandomState::new().build_hasher().finish();
+        Self::with_seed(seed)
+    }
+
+    /// Create an empty treap whose shape only depends on `seed` and the operations
+    pub fn with_seed(seed:u64) -> Self {
+        // xorshift would stay stuck at zero
+        Self { root: None, seed: seed.max(1), op: PhantomData }
+    }
+
+    pub fn from_vec(vals:Vec<T>) -> Self {
+        vals.into_iter().collect()
+    }
+
+    fn priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    /// Amount of values
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn height(&self) -> usize {
+        let mut height:usize = 0;
+        let mut stack:Vec<(&Node<T>, usize)> = self.root.as_deref().map(|n| (n, 1)).into_iter().collect();
+
+        while let Some((node, depth)) = stack.pop() {
+            height = height.max(depth);
+            for child in [&node.left, &node.right].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+    }
+
+    /// Get the value at `idx`
+    pub fn get(&self, idx:usize) -> Option<&T> {
+        let mut cur:&Node<T> = self.root.as_deref()?;
+        let mut idx:usize = idx;
+        // whether the children of `cur` are swapped by the reversals above it
+        let mut flip:bool = false;
+
+        loop {
+            flip ^= cur.rev;
+            let (left, right) = if flip { (&cur.right, &cur.left) } else { (&cur.left, &cur.right) };
+
+            let left_size:usize = size(left);
+            if idx < left_size {
+                cur = left.as_deref()?;
+            } else if idx == left_size {
+                return Some(&cur.val);
+            } else {
+                idx -= left_size + 1;
+                cur = right.as_deref()?;
+            }
+        }
+    }
+
+    /// Replace the value at `idx`, returning the old one
+    pub fn set(&mut self, idx:usize, val:T) -> T {
+        assert!(idx < self.size(), "index {} out of bounds for size {}", idx, self.size());
+
+        let (left, rest) = Treap::split::<T, Op>(self.root.take(), idx);
+        let (mut mid, right) = Treap::split::<T, Op>(rest, 1);
+
+        let node:&mut Box<Node<T>> = mid.as_mut().unwrap();
+        let old:T = mem::replace(&mut node.val, val);
+        node.update::<Op>();
+
+        self.root = Treap::merge::<T, Op>(Treap::merge::<T, Op>(left, mid), right);
+        old
+    }
+
+    /// Insert `val` so that it ends up at `idx`
+    pub fn insert(&mut self, idx:usize, val:T) {
+        assert!(idx <= self.size(), "index {} out of bounds for size {}", idx, self.size());
+
+        let node:Link<T> = Some(Box::new(Node::new(val, self.priority())));
+        let (left, right) = Treap::split::<T, Op>(self.root.take(), idx);
+        self.root = Treap::merge::<T, Op>(Treap::merge::<T, Op>(left, node), right);
+    }
+
+    pub fn push(&mut self, val:T) {
+        let node:Link<T> = Some(Box::new(Node::new(val, self.priority())));
+        self.root = Treap::merge::<T, Op>(self.root.take(), node);
+    }
+
+    /// Remove the value at `idx`
+    pub fn remove(&mut self, idx:usize) -> Option<T> {
+        if idx >= self.size() { return None; }
+
+        let (left, rest) = Treap::split::<T, Op>(self.root.take(), idx);
+        let (mid, right) = Treap::split::<T, Op>(rest, 1);
+        self.root = Treap::merge::<T, Op>(left, right);
+
+        mid.map(|node| node.val)
+    }
+
+    /// Split into the first `at` values and the rest
+    ///
+    /// #Example
+    /// ```
+    /// use structs::range::ops::Max;
+    /// use trees::treap::ImplicitTreap;
+    ///
+    /// let seq:ImplicitTreap<i32, Max> = ImplicitTreap::from_vec(vec![4, 8, 1, 6, 3]);
+    /// let (left, mut right) = seq.split(2);
+    ///
+    /// assert_eq!(left.to_vec(), vec![4, 8]);
+    /// assert_eq!(right.query(0, 3), 6);
+    ///
+    /// // move the head of the sequence to its end, then flip the middle
+    /// let mut seq:ImplicitTreap<i32, Max> = right.merge(left);
+    /// seq.reverse(1, 4);
+    /// assert_eq!(seq.to_vec(), vec![1, 4, 3, 6, 8]);
+    /// assert_eq!(seq.query(0, 4), 6);
+    /// ```
+    pub fn split(mut self, at:usize) -> (Self, Self) {
+        let (left, right) = Treap::split::<T, Op>(self.root.take(), at);
+        let other_seed:u64 = self.priority();
+
+        let mut other:Self = Self::with_seed(other_seed);
+        self.root = left;
+        other.root = right;
+        (self, other)
+    }
+
+    /// Append the values of `other` after the values of `self`
+    pub fn merge(mut self, other:Self) -> Self {
+        self.root = Treap::merge::<T, Op>(self.root.take(), other.root);
+        self
+    }
+
+    /// Combine the values in `from..to`
+    pub fn query(&mut self, from:usize, to:usize) -> T {
+        implicit::query::<T, Op, Treap>(&mut self.root, from, to)
+    }
+
+    /// Reverse the order of the values in `from..to`
+    pub fn reverse(&mut self, from:usize, to:usize) {
+        implicit::reverse::<T, Op, Treap>(&mut self.root, from, to);
+    }
+
+    /// Iterate over the values in order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        implicit::iter(&self.root)
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, Op> FromIterator<T> for ImplicitTreap<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter:I) -> Self {
+        let mut treap:ImplicitTreap<T, Op> = ImplicitTreap::new();
+        for val in iter {
+            treap.push(val);
+        }
+        treap
+    }
+}
+
+impl<T, Op> Clone for ImplicitTreap<T, Op>
+    where T: Clone, Op: Monoid<T>
+{
+    fn clone(&self) -> Self {
+        Self { root: self.root.clone(), seed: self.seed, op: PhantomData }
+    }
+}
+
+impl<T, Op> Visualize for ImplicitTreap<T, Op>
+    where T: Clone + Debug, Op: Monoid<T>
+{
+    /// The tree as stored, nodes whose children still have to be swapped are marked with ↺
+    fn diagram(&self) -> Diagram {
+        implicit::diagram("ImplicitTreap", &self.root)
+    }
+}
+
+impl<T, Op> fmt::Display for ImplicitTreap<T, Op>
+    where T: Clone + Debug, Op: Monoid<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,291 @@
+use core::fmt;
+use std::{cmp::Reverse, fmt::Debug};
+
+use structs::{heap::MaxHeap, viz::{Diagram, Visualize}};
+
+use crate::avl::map::AvlMap;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node<V> {
+    val:Option<V>,
+    children:AvlMap<char, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Self { val: None, children: AvlMap::new() }
+    }
+}
+
+/// Prefix tree over string keys with one node per character
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trie<V> {
+    root:Node<V>,
+    size:usize,
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Trie<V> {
+    pub fn new() -> Self {
+        Self { root: Node::new(), size: 0 }
+    }
+
+    /// Amount of keys
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.root = Node::new();
+        self.size = 0;
+    }
+
+    /// Insert a value, returning the previous value of the key
+    ///
+    /// #Example
+    /// ```
+    /// use trees::trie::Trie;
+    ///
+    /// let mut trie:Trie<u32> = Trie::new();
+    /// trie.insert("car", 1);
+    /// trie.insert("cart", 2);
+    /// trie.insert("care", 3);
+    /// trie.insert("dog", 4);
+    ///
+    /// assert_eq!(trie.get("cart"), Some(&2));
+    /// assert_eq!(trie.get("ca"), None);
+    /// assert!(trie.starts_with("ca"));
+    ///
+    /// let keys:Vec<String> = trie.iter_prefix("car").map(|(k, _)| k).collect();
+    /// assert_eq!(keys, vec!["car", "care", "cart"]);
+    /// ```
+    pub fn insert(&mut self, key:&str, val:V) -> Option<V> {
+        let mut cur:&mut Node<V> = &mut self.root;
+        for c in key.chars() {
+            if !cur.children.contains_key(&c) {
+                cur.children.insert(c, Node::new());
+            }
+            cur = cur.children.get_mut(&c).unwrap();
+        }
+
+        let old:Option<V> = cur.val.replace(val);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    fn node(&self, key:&str) -> Option<&Node<V>> {
+        let mut cur:&Node<V> = &self.root;
+        for c in key.chars() {
+            cur = cur.children.get(&c)?;
+        }
+        Some(cur)
+    }
+
+    pub fn get(&self, key:&str) -> Option<&V> {
+        self.node(key)?.val.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key:&str) -> Option<&mut V> {
+        let mut cur:&mut Node<V> = &mut self.root;
+        for c in key.chars() {
+            cur = cur.children.get_mut(&c)?;
+        }
+        cur.val.as_mut()
+    }
+
+    pub fn contains_key(&self, key:&str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Check if any key starts with `prefix`
+    pub fn starts_with(&self, prefix:&str) -> bool {
+        self.node(prefix).is_some()
+    }
+
+    /// Remove `key`, pruning the nodes left without keys below them
+    pub fn remove(&mut self, key:&str) -> Option<V> {
+        let chars:Vec<char> = key.chars().collect();
+        let val:V = Self::remove_rec(&mut self.root, &chars)?;
+        self.size -= 1;
+        Some(val)
+    }
+
+    fn remove_rec(node:&mut Node<V>, key:&[char]) -> Option<V> {
+        let Some((c, rest)) = key.split_first() else {
+            return node.val.take();
+        };
+
+        let child:&mut Node<V> = node.children.get_mut(c)?;
+        let val:V = Self::remove_rec(child, rest)?;
+
+        if child.val.is_none() && child.children.is_empty() {
+            node.children.remove(c);
+        }
+        Some(val)
+    }
+
+    /// Longest prefix of `query` that is a key, with its value
+    ///
+    /// #Example
+    /// ```
+    /// use trees::trie::Trie;
+    ///
+    /// let mut routes:Trie<&str> = Trie::new();
+    /// routes.insert("/api", "api");
+    /// routes.insert("/api/users", "users");
+    ///
+    /// assert_eq!(routes.longest_prefix("/api/users/42"), Some(("/api/users", &"users")));
+    /// assert_eq!(routes.longest_prefix("/api/items"), Some(("/api", &"api")));
+    /// assert_eq!(routes.longest_prefix("/home"), None);
+    /// ```
+    pub fn longest_prefix<'q>(&self, query:&'q str) -> Option<(&'q str, &V)> {
+        let mut best:Option<(&'q str, &V)> = self.root.val.as_ref().map(|v| (&query[..0], v));
+        let mut cur:&Node<V> = &self.root;
+
+        for (i, c) in query.char_indices() {
+            let Some(child) = cur.children.get(&c) else {
+                break;
+            };
+            cur = child;
+
+            if let Some(val) = &cur.val {
+                best = Some((&query[..i + c.len_utf8()], val));
+            }
+        }
+
+        best
+    }
+
+    /// Iterate in lexicographic order over every key and its value
+    pub fn iter(&self) -> impl Iterator<Item = (String, &V)> {
+        self.iter_prefix("")
+    }
+
+    /// Iterate in lexicographic order over the keys starting with `prefix`
+    pub fn iter_prefix(&self, prefix:&str) -> impl Iterator<Item = (String, &V)> {
+        let mut stack:Vec<(String, &Node<V>)> = Vec::new();
+        if let Some(node) = self.node(prefix) {
+            stack.push((prefix.to_string(), node));
+        }
+
+        std::iter::from_fn(move || {
+            while let Some((key, node)) = stack.pop() {
+                let children:Vec<(&char, &Node<V>)> = node.children.iter().collect();
+                for (c, child) in children.into_iter().rev() {
+                    let mut child_key:String = key.clone();
+                    child_key.push(*c);
+                    stack.push((child_key, child));
+                }
+
+                if let Some(val) = &node.val {
+                    return Some((key, val));
+                }
+            }
+            None
+        })
+    }
+
+    /// The `k` keys starting with `prefix` with the highest values, best first.
+    /// Ties are broken alphabetically
+    ///
+    /// #Example
+    /// ```
+    /// use trees::trie::Trie;
+    ///
+    /// let mut words:Trie<u32> = Trie::new();
+    /// for (word, freq) in [("the", 500), ("then", 40), ("there", 90), ("they", 120), ("tree", 10)] {
+    ///     words.insert(word, freq);
+    /// }
+    ///
+    /// let top:Vec<String> = words.top_completions("th", 2).into_iter().map(|(k, _)| k).collect();
+    /// assert_eq!(top, vec!["the", "they"]);
+    /// ```
+    pub fn top_completions(&self, prefix:&str, k:usize) -> Vec<(String, &V)>
+        where V: PartialOrd + Clone
+    {
+        top_k(self.iter_prefix(prefix), k)
+    }
+}
+
+/// Keep the `k` best entries by value (ties by key) in a bounded `MaxHeap`
+/// where the worst kept entry sits at the root
+pub(crate) fn top_k<'a, V, I>(entries:I, k:usize) -> Vec<(String, &'a V)>
+    where I: Iterator<Item = (String, &'a V)>, V: PartialOrd + Clone + 'a
+{
+    if k == 0 { return Vec::new(); }
+
+    let mut heap:MaxHeap<(Reverse<V>, String)> = MaxHeap::with_capacity(k);
+    let mut vals:AvlMap<String, &'a V> = AvlMap::new();
+
+    for (key, val) in entries {
+        let ranked:(Reverse<V>, String) = (Reverse(val.clone()), key.clone());
+
+        if heap.size() < k {
+            heap.push(ranked);
+        } else if &ranked < heap.get_max().unwrap() {
+            let (_, evicted) = heap.get_max().unwrap().clone();
+            vals.remove(&evicted);
+            heap.set_max(&ranked);
+        } else {
+            continue;
+        }
+        vals.insert(key, val);
+    }
+
+    heap.sort();
+    heap.to_vec().into_iter().rev()
+        .map(|(_, key)| {
+            let val:&V = vals.get(&key).unwrap();
+            (key, val)
+        })
+        .collect()
+}
+
+impl<V> FromIterator<(String, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (String, V)>>(iter:I) -> Self {
+        let mut trie:Trie<V> = Trie::new();
+        for (key, val) in iter {
+            trie.insert(&key, val);
+        }
+        trie
+    }
+}
+
+impl<V> Visualize for Trie<V>
+    where V: Debug
+{
+    /// One node per character, with the value of the keys ending there
+    fn diagram(&self) -> Diagram {
+        let mut diagram:Diagram = Diagram::new("Trie");
+
+        let label = |(c, node):(Option<char>, &Node<V>)| match (c, &node.val) {
+            (None, None) => "·".to_string(),
+            (None, Some(val)) => format!("· = {:?}", val),
+            (Some(c), None) => c.to_string(),
+            (Some(c), Some(val)) => format!("{} = {:?}", c, val)
+        };
+        diagram.add_tree((None, &self.root), label, |(_, node)| {
+            node.children.iter().map(|(c, child)| Some((Some(*c), child))).collect::<Vec<_>>()
+        });
+        diagram
+    }
+}
+
+impl<V> fmt::Display for Trie<V>
+    where V: Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}